@@ -1,8 +1,10 @@
 use std::fmt;
 
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::ToTokens;
 use syn::meta::ParseNestedMeta;
 use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::Token;
 
@@ -18,6 +20,10 @@ pub(crate) const OWNED: &str = "owned";
 
 const STRIP: [&str; 6] = [COPY, NO_COPY, BORROWED_ATTR, OWNED_ATTR, BORROWME, OWNED];
 
+/// Attributes which are only kept on the borrowed variant, unless they are
+/// added to the owned variant through `#[owned_attr(..)]`.
+const BORROWED_ONLY: [&str; 1] = ["deprecated"];
+
 #[derive(Default)]
 pub(crate) struct Attributes {
     /// Attributes to only include on the owned variant.
@@ -34,6 +40,46 @@ pub(crate) struct Container {
     pub(crate) attributes: Attributes,
     /// Default field type kind.
     pub(crate) kind: Option<(Span, FieldTypeKind)>,
+    /// Inlining hint to use for generated conversion functions.
+    pub(crate) inline: Option<(Span, syn::Ident)>,
+    /// Generate a `leak` method on the owned variant.
+    pub(crate) leak: Option<(Span, ())>,
+    /// The name of a mutable view of the container to generate.
+    pub(crate) mut_view: Option<(Span, syn::Ident)>,
+    /// The name of a type alias for the borrowed variant with `'static`
+    /// lifetimes.
+    pub(crate) static_variant: Option<(Span, syn::Ident)>,
+    /// The error type used when fallibly converting to owned.
+    pub(crate) error: Option<(Span, syn::Type)>,
+    /// Generate a `Debug` implementation for both variants.
+    pub(crate) debug: Option<(Span, ())>,
+    /// Disable the heuristics used to determine how fields are converted.
+    pub(crate) no_heuristics: Option<(Span, ())>,
+    /// Require that the owned variant is `'static`.
+    pub(crate) owned_static: Option<(Span, ())>,
+    /// Generate `Hash`, `PartialEq` and `Eq` implementations for the owned
+    /// variant over a subset of its fields.
+    pub(crate) owned_hash: Option<(Span, ())>,
+    /// Implement `core::borrow::Borrow` for the owned variant of a newtype
+    /// over a reference.
+    pub(crate) std_borrow: Option<(Span, ())>,
+    /// Owned types to use for fields of a given reference type.
+    pub(crate) map_types: Vec<MapType>,
+    /// Module whose conversion functions are used by default for every field.
+    pub(crate) with: Option<(Span, syn::Path)>,
+    /// Own immutable slices such as `&'a [T]` as `Box<[T]>` by default.
+    pub(crate) boxed_slices: Option<(Span, ())>,
+    /// Add `#[serde(borrow)]` to borrowed fields which capture lifetimes.
+    pub(crate) serde: Option<(Span, ())>,
+    /// Derive `arbitrary::Arbitrary` for the owned variant.
+    pub(crate) arbitrary: Option<(Span, ())>,
+    /// Implement `From` conversions between the owned and borrowed variants.
+    pub(crate) from: Option<(Span, ())>,
+    /// Generate an inherent `borrow_ref` method on the owned variant.
+    pub(crate) borrow_ref: Option<(Span, ())>,
+    /// Where predicates replacing the ones inferred for the generated `ToOwned`
+    /// and `Borrow` implementations.
+    pub(crate) bound: Option<(Span, Vec<syn::WherePredicate>)>,
 }
 
 impl Container {
@@ -44,6 +90,123 @@ impl Container {
             quote::format_ident!("Owned{}", ident)
         }
     }
+
+    /// The inline attribute to put on generated conversion functions.
+    pub(crate) fn inline(&self) -> syn::Attribute {
+        match &self.inline {
+            Some((_, mode)) => syn::parse_quote!(#[inline(#mode)]),
+            None => syn::parse_quote!(#[inline]),
+        }
+    }
+}
+
+/// A container-level substitution of the owned type for fields of a given
+/// reference type, such as `map_type(&str => Arc<str>)`.
+pub(crate) struct MapType {
+    pub(crate) span: Span,
+    /// The type being referenced, such as `str`.
+    pub(crate) elem: syn::Type,
+    /// The owned type to use, such as `Arc<str>`.
+    pub(crate) owned: syn::Type,
+}
+
+impl MapType {
+    /// Test if the given field type is an immutable reference to the mapped
+    /// type. Lifetimes are ignored, so `&'a str` matches `&str`.
+    pub(crate) fn matches(&self, ty: &syn::Type) -> bool {
+        match ty {
+            syn::Type::Group(ty) => self.matches(&ty.elem),
+            syn::Type::Paren(ty) => self.matches(&ty.elem),
+            syn::Type::Reference(ty) if ty.mutability.is_none() => {
+                type_key(self.elem.to_token_stream()) == type_key(ty.elem.to_token_stream())
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Construct a key used to compare types with their lifetimes erased.
+fn type_key(tokens: TokenStream) -> Vec<String> {
+    let mut out = Vec::<String>::new();
+    let mut tokens = tokens.into_iter().peekable();
+
+    while let Some(tt) = tokens.next() {
+        match tt {
+            TokenTree::Punct(p) if p.as_char() == '\'' => {
+                // Skip the lifetime identifier and a trailing comma if present.
+                tokens.next();
+
+                if matches!(tokens.peek(), Some(TokenTree::Punct(p)) if p.as_char() == ',') {
+                    tokens.next();
+                }
+
+                // Erase generic arguments which only consisted of lifetimes.
+                if matches!(tokens.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '>')
+                    && out.last().map(String::as_str) == Some("<")
+                {
+                    tokens.next();
+                    out.pop();
+                }
+            }
+            TokenTree::Group(group) => {
+                out.push(format!("{:?}", group.delimiter()));
+                out.extend(type_key(group.stream()));
+            }
+            tt => out.push(tt.to_string()),
+        }
+    }
+
+    out
+}
+
+/// Find the path of the `crate = <path>` container attribute in attributes
+/// named `name`, such as `#[borrowme(crate = <path>)]`.
+///
+/// This is looked up ahead of parsing the container since it determines the
+/// paths used by the context. Malformed attributes are ignored here and
+/// reported when the container is parsed.
+pub(crate) fn crate_path(
+    name: &str,
+    attrs: &[syn::Attribute],
+    rest: &[syn::Attribute],
+) -> Option<syn::Path> {
+    for a in attrs.iter().chain(rest) {
+        let syn::Meta::List(list) = &a.meta else {
+            continue;
+        };
+
+        if !list.path.is_ident(name) {
+            continue;
+        }
+
+        let mut tokens = list.tokens.clone().into_iter();
+        let mut leading = true;
+
+        while let Some(tt) = tokens.next() {
+            match tt {
+                TokenTree::Ident(ident) if leading && ident == "crate" => {
+                    if !matches!(tokens.next(), Some(TokenTree::Punct(p)) if p.as_char() == '=') {
+                        return None;
+                    }
+
+                    let path = tokens
+                        .by_ref()
+                        .take_while(|tt| !matches!(tt, TokenTree::Punct(p) if p.as_char() == ','))
+                        .collect::<TokenStream>();
+
+                    return syn::parse2(path).ok();
+                }
+                TokenTree::Punct(p) if p.as_char() == ',' => {
+                    leading = true;
+                }
+                _ => {
+                    leading = false;
+                }
+            }
+        }
+    }
+
+    None
 }
 
 /// Parse container attributes.
@@ -56,8 +219,30 @@ pub(crate) fn container(
         owned_ident: None,
         attributes: Attributes::default(),
         kind: None,
+        inline: None,
+        leak: None,
+        mut_view: None,
+        static_variant: None,
+        error: None,
+        debug: None,
+        no_heuristics: None,
+        owned_static: None,
+        owned_hash: None,
+        std_borrow: None,
+        boxed_slices: None,
+        serde: None,
+        arbitrary: None,
+        from: None,
+        borrow_ref: None,
+        bound: None,
+        map_types: Vec::new(),
+        with: None,
     };
 
+    // NB: The crate path is resolved through `crate_path` ahead of time, so
+    // it's only validated here.
+    let mut krate = None;
+
     macro_rules! set_attr {
         ($field:ident $(. $field2:ident)*, $meta:expr, $value:expr, $message:expr $(,)?) => {
             set_attr(cx, &mut attr.$field$(.$field2)*, $meta, $value, $message)
@@ -69,6 +254,12 @@ pub(crate) fn container(
             a.parse_nested_meta(|meta| {
                 let span = meta.path.span();
 
+                if meta.path.is_ident("crate") {
+                    let (_, span) = parse_path(&meta)?;
+                    set_attr(cx, &mut krate, span, (), "Duplicate crate attribute.");
+                    return Ok(());
+                }
+
                 if meta.path.is_ident("name") {
                     meta.input.parse::<Token![=]>()?;
                     set_attr!(owned_ident, span, meta.input.parse()?, "Duplicate name.",);
@@ -81,6 +272,141 @@ pub(crate) fn container(
                     return Ok(());
                 }
 
+                if meta.path.is_ident("with") {
+                    let (path, span) = parse_path(&meta)?;
+                    set_attr!(with, span, path, "Duplicate with attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("error") {
+                    meta.input.parse::<Token![=]>()?;
+                    let ty = meta.input.parse()?;
+                    set_attr!(error, span, ty, "Duplicate error attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("mut_view") {
+                    meta.input.parse::<Token![=]>()?;
+                    let ident = meta.input.parse()?;
+                    set_attr!(mut_view, span, ident, "Duplicate mut_view attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("static_variant") {
+                    meta.input.parse::<Token![=]>()?;
+                    let ident = meta.input.parse()?;
+                    set_attr!(static_variant, span, ident, "Duplicate static_variant attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("leak") {
+                    set_attr!(leak, span, (), "Duplicate leak attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("debug") {
+                    set_attr!(debug, span, (), "Duplicate debug attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("no_heuristics") {
+                    set_attr!(
+                        no_heuristics,
+                        span,
+                        (),
+                        "Duplicate no_heuristics attribute."
+                    );
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("map_type") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+
+                    let ty: syn::Type = content.parse()?;
+                    content.parse::<Token![=>]>()?;
+                    let owned = content.parse()?;
+
+                    let elem = match ty {
+                        syn::Type::Reference(ty) if ty.mutability.is_none() => *ty.elem,
+                        ty => {
+                            return Err(syn::Error::new(
+                                ty.span(),
+                                format_args!("#[{BORROWME}]: map_type only supports immutable references such as `&str`."),
+                            ));
+                        }
+                    };
+
+                    attr.map_types.push(MapType { span, elem, owned });
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("owned_static") {
+                    set_attr!(owned_static, span, (), "Duplicate owned_static attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("boxed_slices") {
+                    set_attr!(boxed_slices, span, (), "Duplicate boxed_slices attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("owned_hash") {
+                    set_attr!(owned_hash, span, (), "Duplicate owned_hash attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("serde") {
+                    set_attr!(serde, span, (), "Duplicate serde attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("borrow_ref") {
+                    set_attr!(borrow_ref, span, (), "Duplicate borrow_ref attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("from") {
+                    set_attr!(from, span, (), "Duplicate from attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("bound") {
+                    meta.input.parse::<Token![=]>()?;
+                    let lit = meta.input.parse::<syn::LitStr>()?;
+                    let predicates = lit.parse_with(
+                        Punctuated::<syn::WherePredicate, Token![,]>::parse_terminated,
+                    )?;
+                    let predicates = predicates.into_iter().collect();
+                    set_attr!(bound, span, predicates, "Duplicate bound attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("arbitrary") {
+                    set_attr!(arbitrary, span, (), "Duplicate arbitrary attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("std_borrow") {
+                    set_attr!(std_borrow, span, (), "Duplicate std_borrow attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("inline") {
+                    meta.input.parse::<Token![=]>()?;
+                    let mode: syn::Ident = meta.input.parse()?;
+
+                    if mode != "always" && mode != "never" {
+                        return Err(syn::Error::new(
+                            mode.span(),
+                            format_args!("#[{BORROWME}]: Expected `always` or `never`."),
+                        ));
+                    }
+
+                    set_attr!(inline, span, mode, "Duplicate inline attribute.");
+                    return Ok(());
+                }
+
                 Err(syn::Error::new(
                     span,
                     format_args!("#[{BORROWME}]: Unsupported attribute."),
@@ -105,6 +431,33 @@ pub(crate) fn container(
         }
     }
 
+    // NB: This is merged with any other derives on the owned variant.
+    if let Some((span, _)) = attr.arbitrary {
+        attr.attributes
+            .own
+            .push(syn::parse_quote_spanned!(span => derive(::arbitrary::Arbitrary)));
+    }
+
+    // NB: A fallible conversion implements `TryFrom`, which conflicts with the
+    // blanket implementation for types implementing `From`.
+    if let (Some((span, _)), Some((error, _))) = (&attr.from, &attr.error) {
+        cx.span_error(
+            *span,
+            format_args!("#[{BORROWME}] from can't be combined with error."),
+        );
+        cx.span_error(
+            *error,
+            format_args!("#[{BORROWME}] Hint: the fallible conversion implements TryFrom instead."),
+        );
+    }
+
+    if let (Some((span, _)), Some((_, FieldTypeKind::Std))) = (&attr.with, &attr.kind) {
+        cx.span_error(
+            *span,
+            format_args!("#[{BORROWME}] with can't be combined with std."),
+        );
+    }
+
     Ok(attr)
 }
 
@@ -214,39 +567,130 @@ pub(crate) struct Field {
     pub(crate) ty: FieldType,
     pub(crate) borrow: Option<(Span, syn::Path)>,
     pub(crate) borrow_mut: Option<(Span, syn::Path)>,
+    /// The `borrow_mut` function of a `with` module, which is only referenced
+    /// if the field is borrowed mutably since the module might not define it.
+    pub(crate) with_borrow_mut: Option<(Span, syn::Path)>,
     pub(crate) to_owned: Option<(Span, syn::Path)>,
+    /// Fallible conversion used when implementing `TryToOwned`.
+    pub(crate) try_to_owned: Option<(Span, syn::Path)>,
+    /// Borrow the field by dereferencing the owned value.
+    pub(crate) deref_borrow: Option<(Span, ())>,
+    /// Own a reference as a `Cow<'static, T>`.
+    pub(crate) cow: Option<(Span, ())>,
+    /// Borrow a reference as a `Cow<'a, T>`.
+    pub(crate) borrow_cow: Option<(Span, ())>,
+    /// Convert a collection element-wise through `Collect`.
+    pub(crate) collect: Option<(Span, ())>,
+    /// Own a collection as the given type by collecting its elements.
+    pub(crate) owned_collect: Option<(Span, Respan<syn::Type>)>,
+    /// Own an option as its owned value, which is the default value if it's
+    /// missing.
+    pub(crate) or_default: Option<(Span, ())>,
+    /// Own the field as its own type using `Clone`, while still borrowing it
+    /// through `Borrow`.
+    pub(crate) clone_only: Option<(Span, ())>,
+    /// Skip converting the field, and construct it using `Default` instead.
+    pub(crate) skip: Option<(Span, ())>,
+    /// Whether the field is included in the implementations generated by
+    /// `owned_hash`.
+    pub(crate) hash: Option<(Span, bool)>,
+    /// Override the visibility of the field in the owned variant.
+    pub(crate) owned_vis: Option<(Span, syn::Visibility)>,
     pub(crate) attributes: Attributes,
 }
 
 impl Field {
-    /// Get borrow implementation.
-    pub(crate) fn borrow<'a>(&'a self, cx: &'a Ctxt) -> &'a syn::Path {
-        self.borrow
-            .as_ref()
-            .map(|(_, p)| p)
-            .unwrap_or(&cx.borrowme_borrow_t_borrow)
+    /// Get borrow implementation, and the span of the attribute specifying it
+    /// if any.
+    pub(crate) fn borrow<'a>(&'a self, cx: &'a Ctxt) -> (Option<Span>, &'a syn::Path) {
+        with_path(self.borrow.as_ref(), &cx.borrowme_borrow_t_borrow)
     }
 
-    /// Get borrow_mut implementation.
-    pub(crate) fn borrow_mut<'a>(&'a self, cx: &'a Ctxt) -> &'a syn::Path {
-        self.borrow_mut
-            .as_ref()
-            .map(|(_, p)| p)
-            .unwrap_or(&cx.borrowme_borrow_mut_t_borrow_mut)
+    /// Get borrow_mut implementation, and the span of the attribute specifying
+    /// it if any.
+    pub(crate) fn borrow_mut<'a>(&'a self, cx: &'a Ctxt) -> (Option<Span>, &'a syn::Path) {
+        with_path(
+            self.borrow_mut.as_ref().or(self.with_borrow_mut.as_ref()),
+            &cx.borrowme_borrow_mut_t_borrow_mut,
+        )
     }
 
-    /// Get to_owned implementation.
-    pub(crate) fn to_owned<'a>(&'a self, cx: &'a Ctxt) -> &'a syn::Path {
-        self.to_owned
-            .as_ref()
-            .map(|(_, p)| p)
-            .unwrap_or(&cx.borrowme_to_owned_t_to_owned)
+    /// Get to_owned implementation, and the span of the attribute specifying
+    /// it if any.
+    pub(crate) fn to_owned<'a>(&'a self, cx: &'a Ctxt) -> (Option<Span>, &'a syn::Path) {
+        with_path(self.to_owned.as_ref(), &cx.borrowme_to_owned_t_to_owned)
     }
 
     /// Test if field is mutable.
     pub(crate) fn is_mut(&self) -> bool {
         self.is_mut.is_some()
     }
+
+    /// Get the span of the first attribute specifying how the field is
+    /// converted, except for the ones named in `allowed`.
+    pub(crate) fn conversion_span(&self, allowed: &[&str]) -> Option<Span> {
+        let kind = self.ty.kind.as_ref().and_then(|(span, kind)| {
+            let name = match kind {
+                FieldTypeKind::Default => return None,
+                FieldTypeKind::Copy(true) => "copy",
+                FieldTypeKind::Copy(false) => "no_copy",
+                FieldTypeKind::Std => "std",
+            };
+
+            Some((name, *span))
+        });
+
+        let conversions = [
+            ("owned", self.ty.owned.as_ref().map(|(span, _)| *span)),
+            (
+                "to_owned_with",
+                self.to_owned.as_ref().map(|(span, _)| *span),
+            ),
+            (
+                "try_to_owned_with",
+                self.try_to_owned.as_ref().map(|(span, _)| *span),
+            ),
+            ("borrow_with", self.borrow.as_ref().map(|(span, _)| *span)),
+            (
+                "borrow_mut_with",
+                self.borrow_mut.as_ref().map(|(span, _)| *span),
+            ),
+            ("with", self.with_borrow_mut.as_ref().map(|(span, _)| *span)),
+            ("deref_borrow", self.deref_borrow.map(|(span, _)| span)),
+            ("cow", self.cow.map(|(span, _)| span)),
+            ("borrow_cow", self.borrow_cow.map(|(span, _)| span)),
+            ("collect", self.collect.map(|(span, _)| span)),
+            (
+                "owned_collect",
+                self.owned_collect.as_ref().map(|(span, _)| *span),
+            ),
+            ("or_default", self.or_default.map(|(span, _)| span)),
+            ("clone_only", self.clone_only.map(|(span, _)| span)),
+            ("skip", self.skip.map(|(span, _)| span)),
+            ("mut", self.is_mut.map(|(span, _)| span)),
+        ];
+
+        IntoIterator::into_iter(conversions)
+            .chain(kind.map(|(name, span)| (name, Some(span))))
+            .filter(|(name, _)| !allowed.contains(name))
+            .find_map(|(_, span)| span)
+    }
+
+    /// Test if the field specifies how it's converted, except through the
+    /// attributes named in `allowed`.
+    pub(crate) fn has_conversion(&self, allowed: &[&str]) -> bool {
+        self.conversion_span(allowed).is_some()
+    }
+}
+
+pub(crate) fn with_path<'a>(
+    path: Option<&'a (Span, syn::Path)>,
+    default: &'a syn::Path,
+) -> (Option<Span>, &'a syn::Path) {
+    match path {
+        Some((span, path)) => (Some(*span), path),
+        None => (None, default),
+    }
 }
 
 /// Parse field attributes.
@@ -259,13 +703,26 @@ pub(crate) fn field(
     spans: (Span, Span),
     attrs: &[syn::Attribute],
     default_kind: Option<(Span, FieldTypeKind)>,
+    default_with: Option<&(Span, syn::Path)>,
 ) -> Result<Field, ()> {
     let mut attr = Field {
         is_mut: None,
         ty: FieldType::default(),
         borrow: None,
         borrow_mut: None,
+        with_borrow_mut: None,
         to_owned: None,
+        try_to_owned: None,
+        deref_borrow: None,
+        cow: None,
+        borrow_cow: None,
+        collect: None,
+        owned_collect: None,
+        or_default: None,
+        clone_only: None,
+        skip: None,
+        hash: None,
+        owned_vis: None,
         attributes: Attributes::default(),
     };
 
@@ -327,6 +784,30 @@ pub(crate) fn field(
                     return Ok(());
                 }
 
+                if meta.path.is_ident("owned_collect") {
+                    meta.input.parse::<Token![=]>()?;
+                    let ty = Respan::new(meta.input.parse()?, spans);
+                    set_attr!(
+                        owned_collect,
+                        span,
+                        ty,
+                        "Duplicate owned_collect attribute."
+                    );
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("or_default") {
+                    set_attr!(or_default, span, (), "Duplicate or_default attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("owned_vis") {
+                    meta.input.parse::<Token![=]>()?;
+                    let vis = meta.input.parse()?;
+                    set_attr!(owned_vis, span, vis, "Duplicate owned_vis attribute.");
+                    return Ok(());
+                }
+
                 if meta.path.is_ident("mut") {
                     set_attr!(is_mut, span, (), "Duplicate attribute setting mutability.");
                     return Ok(());
@@ -350,6 +831,12 @@ pub(crate) fn field(
                     return Ok(());
                 }
 
+                if meta.path.is_ident("try_to_owned_with") {
+                    let (path, _) = parse_path(&meta)?;
+                    set_attr!(try_to_owned, span, path, "Duplicate try_to_owned_with.");
+                    return Ok(());
+                }
+
                 if meta.path.is_ident("to_owned_with") {
                     let (path, _) = parse_path(&meta)?;
                     set_attr!(to_owned, span, path, "Duplicate to_owned_with.");
@@ -364,31 +851,69 @@ pub(crate) fn field(
 
                 if meta.path.is_ident("borrow_mut_with") {
                     let (path, _) = parse_path(&meta)?;
-                    set_attr!(borrow_mut, span, path, "Duplicate borrow_mut_with.");
+
+                    // NB: The `borrow_mut` function of a `with` module can't
+                    // be overridden, the same as its other functions.
+                    if let Some((existing, _)) = &attr.with_borrow_mut {
+                        cx.span_error(
+                            span,
+                            format_args!("#[{BORROWME}] Duplicate borrow_mut_with."),
+                        );
+                        cx.span_error(
+                            *existing,
+                            format_args!("#[{BORROWME}] Existing one is here."),
+                        );
+                    } else {
+                        set_attr!(borrow_mut, span, path, "Duplicate borrow_mut_with.");
+                    }
+
                     set_attr!(is_mut, span, (), "Duplicate attribute setting mutability.");
                     return Ok(());
                 }
 
-                if meta.path.is_ident("with") {
-                    let (path, span) = parse_path(&meta)?;
+                if meta.path.is_ident("cow") {
+                    set_attr!(cow, span, (), "Duplicate cow attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("borrow_cow") {
+                    set_attr!(borrow_cow, span, (), "Duplicate borrow_cow attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("collect") {
+                    set_attr!(collect, span, (), "Duplicate collect attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("clone_only") {
+                    set_attr!(clone_only, span, (), "Duplicate clone_only attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("skip") {
+                    set_attr!(skip, span, (), "Duplicate skip attribute.");
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("hash") {
+                    set_attr!(hash, span, true, "Duplicate attribute setting hashing.");
+                    return Ok(());
+                }
 
-                    let mut to_owned = path.clone();
-                    to_owned
-                        .segments
-                        .push(syn::PathSegment::from(syn::Ident::new("to_owned", span)));
-                    set_attr!(to_owned, span, to_owned, "Duplicate to_owned_with.");
+                if meta.path.is_ident("no_hash") {
+                    set_attr!(hash, span, false, "Duplicate attribute setting hashing.");
+                    return Ok(());
+                }
 
-                    let mut borrow = path.clone();
-                    borrow
-                        .segments
-                        .push(syn::PathSegment::from(syn::Ident::new("borrow", span)));
-                    set_attr!(borrow, span, borrow, "Duplicate borrow_with.");
+                if meta.path.is_ident("deref_borrow") {
+                    set_attr!(deref_borrow, span, (), "Duplicate deref_borrow attribute.");
+                    return Ok(());
+                }
 
-                    let mut borrow_mut = path;
-                    borrow_mut
-                        .segments
-                        .push(syn::PathSegment::from(syn::Ident::new("borrow_mut", span)));
-                    set_attr!(borrow_mut, span, borrow_mut, "Duplicate borrow_mut_with.");
+                if meta.path.is_ident("with") {
+                    let (path, span) = parse_path(&meta)?;
+                    set_with(cx, &mut attr, span, &path);
                     return Ok(());
                 }
 
@@ -416,6 +941,93 @@ pub(crate) fn field(
         }
     }
 
+    if let Some((span, _)) = &attr.deref_borrow {
+        if attr.borrow.is_some() || attr.borrow_mut.is_some() {
+            cx.span_error(
+                *span,
+                format_args!(
+                    "#[{BORROWME}] deref_borrow can't be combined with borrow_with or borrow_mut_with."
+                ),
+            );
+        }
+    }
+
+    // Attributes which specify how the field is converted by themselves, with
+    // the other attributes they can be combined with. Only the first one which
+    // is present is checked, so that a conflicting pair produces one error and
+    // the more specific attributes come first.
+    let converted = "specifying how the field is converted.";
+
+    let exclusive = [
+        (
+            "skip",
+            attr.skip.map(|(span, _)| span),
+            &["owned"][..],
+            converted,
+        ),
+        (
+            "clone_only",
+            attr.clone_only.map(|(span, _)| span),
+            &["borrow_with", "borrow_mut_with", "deref_borrow", "mut"][..],
+            "specifying how the field is owned.",
+        ),
+        (
+            "or_default",
+            attr.or_default.map(|(span, _)| span),
+            &["owned"][..],
+            "specifying how the field is converted, except for owned.",
+        ),
+        (
+            "owned_collect",
+            attr.owned_collect.as_ref().map(|(span, _)| *span),
+            &[][..],
+            converted,
+        ),
+        (
+            "borrow_cow",
+            attr.borrow_cow.map(|(span, _)| span),
+            &[][..],
+            converted,
+        ),
+        (
+            "collect",
+            attr.collect.map(|(span, _)| span),
+            &[][..],
+            converted,
+        ),
+        ("cow", attr.cow.map(|(span, _)| span), &[][..], converted),
+    ];
+
+    if let Some((name, span, allowed, what)) = IntoIterator::into_iter(exclusive)
+        .find_map(|(name, span, allowed, what)| Some((name, span?, allowed, what)))
+    {
+        // NB: `no_copy` only disables the copy heuristic, so it only conflicts
+        // with skip which doesn't convert the field at all.
+        let no_copy = (name != "skip").then_some("no_copy");
+
+        let allowed = allowed
+            .iter()
+            .copied()
+            .chain([name])
+            .chain(no_copy)
+            .collect::<Vec<_>>();
+
+        if attr.has_conversion(&allowed) {
+            cx.span_error(
+                span,
+                format_args!("#[{BORROWME}] {name} can't be combined with other attributes {what}"),
+            );
+        }
+    }
+
+    // Fields which don't specify how they are converted use the `with` module
+    // of the container, if any.
+    if let Some((span, path)) = default_with {
+        if !attr.has_conversion(&["owned", "mut"]) {
+            set_with(cx, &mut attr, *span, path);
+        }
+    }
+
     if attr.ty.kind.is_none() {
         attr.ty.kind = default_kind;
     }
@@ -423,6 +1035,52 @@ pub(crate) fn field(
     Ok(attr)
 }
 
+/// Use the `to_owned`, `borrow` and `borrow_mut` functions of the module at
+/// `path` to convert a field.
+fn set_with(cx: &Ctxt, attr: &mut Field, span: Span, path: &syn::Path) {
+    let mut to_owned = path.clone();
+    to_owned
+        .segments
+        .push(syn::PathSegment::from(syn::Ident::new("to_owned", span)));
+    set_attr(
+        cx,
+        &mut attr.to_owned,
+        span,
+        to_owned,
+        "Duplicate to_owned_with.",
+    );
+
+    let mut borrow = path.clone();
+    borrow
+        .segments
+        .push(syn::PathSegment::from(syn::Ident::new("borrow", span)));
+    set_attr(cx, &mut attr.borrow, span, borrow, "Duplicate borrow_with.");
+
+    let mut borrow_mut = path.clone();
+    borrow_mut
+        .segments
+        .push(syn::PathSegment::from(syn::Ident::new("borrow_mut", span)));
+
+    if let Some((existing, _)) = &attr.borrow_mut {
+        cx.span_error(
+            span,
+            format_args!("#[{BORROWME}] Duplicate borrow_mut_with."),
+        );
+        cx.span_error(
+            *existing,
+            format_args!("#[{BORROWME}] Existing one is here."),
+        );
+    } else {
+        set_attr(
+            cx,
+            &mut attr.with_borrow_mut,
+            span,
+            borrow_mut,
+            "Duplicate borrow_mut_with.",
+        );
+    }
+}
+
 fn set_attr<T>(
     cx: &Ctxt,
     existing: &mut Option<(Span, T)>,
@@ -452,8 +1110,58 @@ fn parse_path(meta: &ParseNestedMeta) -> syn::Result<(syn::Path, proc_macro2::Sp
     Ok((path, last))
 }
 
+/// Parse the type parameters marked with `#[borrowme(convert)]`, which are
+/// converted through `ToOwned` and `Borrow` rather than cloned. The attributes
+/// are stripped from the generics.
+pub(crate) fn convert_params(cx: &Ctxt, generics: &mut syn::Generics) -> Vec<(Span, syn::Ident)> {
+    let mut params = Vec::new();
+
+    for param in generics.type_params_mut() {
+        let mut convert = None;
+
+        for a in &param.attrs {
+            if !a.path().is_ident(BORROWME) {
+                continue;
+            }
+
+            let result = a.parse_nested_meta(|meta| {
+                let span = meta.path.span();
+
+                if meta.path.is_ident("convert") {
+                    set_attr(cx, &mut convert, span, (), "Duplicate convert attribute.");
+                    return Ok(());
+                }
+
+                Err(syn::Error::new(
+                    span,
+                    format_args!(
+                        "#[{BORROWME}]: Unsupported type parameter attribute, expected `convert`."
+                    ),
+                ))
+            });
+
+            if let Err(error) = result {
+                cx.error(error);
+            }
+        }
+
+        strip([&mut param.attrs]);
+
+        if let Some((span, ())) = convert {
+            params.push((span, param.ident.clone()));
+        }
+    }
+
+    params
+}
+
 pub(crate) fn strip<const N: usize>(attrs: [&mut Vec<syn::Attribute>; N]) {
     for attrs in attrs {
         attrs.retain(|a| STRIP.iter().all(|name| !a.path().is_ident(name)));
     }
 }
+
+/// Strip attributes which shouldn't be forwarded to the owned variant.
+pub(crate) fn strip_owned(attrs: &mut Vec<syn::Attribute>) {
+    attrs.retain(|a| BORROWED_ONLY.iter().all(|name| !a.path().is_ident(name)));
+}
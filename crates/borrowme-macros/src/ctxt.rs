@@ -7,24 +7,110 @@ pub(crate) struct Ctxt {
     errors: RefCell<Vec<syn::Error>>,
     pub(crate) borrowme_borrow_t_borrow: syn::Path,
     pub(crate) borrowme_borrow_mut_t_borrow_mut: syn::Path,
+    pub(crate) borrowme_boxed: syn::Path,
+    pub(crate) borrowme_boxed_to_owned: syn::Path,
+    pub(crate) borrowme_collect_borrow: syn::Path,
+    pub(crate) borrowme_collect_borrow_from_iter: syn::Path,
+    pub(crate) borrowme_collect_t: syn::Path,
+    pub(crate) borrowme_collect_to_owned: syn::Path,
+    pub(crate) borrowme_collect_to_owned_from_iter: syn::Path,
+    pub(crate) borrowme_cow: syn::Path,
+    pub(crate) borrowme_cow_borrow: syn::Path,
+    pub(crate) borrowme_cow_borrowed: syn::Path,
+    pub(crate) borrowme_cow_clone_owned: syn::Path,
+    pub(crate) borrowme_cow_owned: syn::Path,
+    pub(crate) borrowme_cow_to_owned: syn::Path,
+    pub(crate) borrowme_leak: syn::Path,
+    pub(crate) borrowme_leak_mut: syn::Path,
+    pub(crate) borrowme_marker_mut: syn::Path,
+    pub(crate) borrowme_marker_ref: syn::Path,
+    pub(crate) borrowme_or_default_borrow: syn::Path,
+    pub(crate) borrowme_or_default_to_owned: syn::Path,
     pub(crate) clone_t_clone: syn::Path,
+    pub(crate) core_borrow_t: syn::Path,
+    pub(crate) core_clone_t: syn::Path,
+    pub(crate) core_cmp_eq_t: syn::Path,
+    pub(crate) core_cmp_partial_eq_t: syn::Path,
+    pub(crate) core_convert_from_t: syn::Path,
+    pub(crate) core_convert_try_from_t: syn::Path,
+    pub(crate) core_borrow_t_borrow: syn::Path,
+    pub(crate) core_borrow_mut_t: syn::Path,
+    pub(crate) core_borrow_mut_t_borrow_mut: syn::Path,
+    pub(crate) core_fmt_debug_t: syn::Path,
+    pub(crate) core_fmt_formatter: syn::Path,
+    pub(crate) core_fmt_result: syn::Path,
+    pub(crate) core_hash_hash_t: syn::Path,
+    pub(crate) core_hash_hasher_t: syn::Path,
+    pub(crate) core_mem_discriminant: syn::Path,
+    pub(crate) core_marker_phantom_data: syn::Path,
     pub(crate) borrowme_borrow_t: syn::Path,
     pub(crate) borrowme_borrow_mut_t: syn::Path,
     pub(crate) borrowme_to_owned_t: syn::Path,
     pub(crate) borrowme_to_owned_t_to_owned: syn::Path,
+    pub(crate) borrowme_try_to_owned_t: syn::Path,
 }
 
 impl Ctxt {
-    pub(crate) fn new(span: Span) -> Self {
+    /// Construct a new context, where `krate` is the path to the `borrowme`
+    /// crate if it's been relocated through `#[borrowme(crate = <path>)]`.
+    pub(crate) fn new(span: Span, krate: Option<&syn::Path>) -> Self {
+        let default_krate;
+
+        let krate = match krate {
+            Some(krate) => krate,
+            None => {
+                default_krate = path(span, ["borrowme"]);
+                &default_krate
+            }
+        };
+
+        let borrowme = |parts: &[&str]| join(span, krate, parts);
+
         Self {
             errors: RefCell::new(Vec::new()),
-            borrowme_borrow_t_borrow: path(span, ["borrowme", "Borrow", "borrow"]),
-            borrowme_borrow_mut_t_borrow_mut: path(span, ["borrowme", "BorrowMut", "borrow_mut"]),
-            borrowme_borrow_t: path(span, ["borrowme", "Borrow"]),
-            borrowme_borrow_mut_t: path(span, ["borrowme", "BorrowMut"]),
-            borrowme_to_owned_t: path(span, ["borrowme", "ToOwned"]),
+            borrowme_borrow_t_borrow: borrowme(&["Borrow", "borrow"]),
+            borrowme_borrow_mut_t_borrow_mut: borrowme(&["BorrowMut", "borrow_mut"]),
+            borrowme_boxed: borrowme(&["boxed", "Box"]),
+            borrowme_boxed_to_owned: borrowme(&["boxed", "to_owned"]),
+            borrowme_collect_borrow: borrowme(&["collect", "borrow"]),
+            borrowme_collect_borrow_from_iter: borrowme(&["collect", "borrow_from_iter"]),
+            borrowme_collect_t: borrowme(&["collect", "Collect"]),
+            borrowme_collect_to_owned: borrowme(&["collect", "to_owned"]),
+            borrowme_collect_to_owned_from_iter: borrowme(&["collect", "to_owned_from_iter"]),
+            borrowme_cow: borrowme(&["cow", "Cow"]),
+            borrowme_cow_borrow: borrowme(&["cow", "borrow"]),
+            borrowme_cow_borrowed: borrowme(&["cow", "borrowed"]),
+            borrowme_cow_clone_owned: borrowme(&["cow", "clone_owned"]),
+            borrowme_cow_owned: borrowme(&["cow", "Owned"]),
+            borrowme_cow_to_owned: borrowme(&["cow", "to_owned"]),
+            borrowme_leak: borrowme(&["leak"]),
+            borrowme_leak_mut: borrowme(&["leak_mut"]),
+            borrowme_marker_mut: borrowme(&["marker", "Mut"]),
+            borrowme_marker_ref: borrowme(&["marker", "Ref"]),
+            borrowme_or_default_borrow: borrowme(&["or_default", "borrow"]),
+            borrowme_or_default_to_owned: borrowme(&["or_default", "to_owned"]),
+            borrowme_borrow_t: borrowme(&["Borrow"]),
+            borrowme_borrow_mut_t: borrowme(&["BorrowMut"]),
+            borrowme_to_owned_t: borrowme(&["ToOwned"]),
             clone_t_clone: path(span, ["core", "clone", "Clone", "clone"]),
-            borrowme_to_owned_t_to_owned: path(span, ["borrowme", "ToOwned", "to_owned"]),
+            core_borrow_t: path(span, ["core", "borrow", "Borrow"]),
+            core_borrow_t_borrow: path(span, ["core", "borrow", "Borrow", "borrow"]),
+            core_borrow_mut_t: path(span, ["core", "borrow", "BorrowMut"]),
+            core_borrow_mut_t_borrow_mut: path(span, ["core", "borrow", "BorrowMut", "borrow_mut"]),
+            core_clone_t: path(span, ["core", "clone", "Clone"]),
+            core_cmp_eq_t: path(span, ["core", "cmp", "Eq"]),
+            core_convert_from_t: path(span, ["core", "convert", "From"]),
+            core_convert_try_from_t: path(span, ["core", "convert", "TryFrom"]),
+            core_cmp_partial_eq_t: path(span, ["core", "cmp", "PartialEq"]),
+            core_fmt_debug_t: path(span, ["core", "fmt", "Debug"]),
+            core_fmt_formatter: path(span, ["core", "fmt", "Formatter"]),
+            core_fmt_result: path(span, ["core", "fmt", "Result"]),
+            core_hash_hash_t: path(span, ["core", "hash", "Hash"]),
+            core_hash_hasher_t: path(span, ["core", "hash", "Hasher"]),
+            core_mem_discriminant: path(span, ["core", "mem", "discriminant"]),
+            core_marker_phantom_data: path(span, ["core", "marker", "PhantomData"]),
+            borrowme_to_owned_t_to_owned: borrowme(&["ToOwned", "to_owned"]),
+            borrowme_try_to_owned_t: borrowme(&["TryToOwned"]),
         }
     }
 
@@ -60,6 +146,20 @@ impl Ctxt {
     }
 }
 
+/// Helper to construct a path relative to the given base path.
+fn join(span: Span, base: &syn::Path, parts: &[&str]) -> syn::Path {
+    let mut path = base.clone();
+
+    for part in parts {
+        path.segments.push(syn::PathSegment {
+            ident: syn::Ident::new(part, span),
+            arguments: syn::PathArguments::None,
+        });
+    }
+
+    path
+}
+
 /// Helper to construct a path.
 pub(crate) fn path<I>(span: Span, parts: I) -> syn::Path
 where
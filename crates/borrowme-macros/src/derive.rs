@@ -0,0 +1,323 @@
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::Token;
+
+use crate::attr::with_path;
+use crate::ctxt::Ctxt;
+use crate::implement::{impl_attributes, Access, Binding, BoundAccess, Call};
+
+/// The trait being derived for a hand-written type.
+#[derive(Clone, Copy)]
+pub(crate) enum Kind {
+    /// `#[derive(ToOwned)]`, which is put on the borrowed type.
+    ToOwned,
+    /// `#[derive(Borrow)]`, which is put on the owned type.
+    Borrow,
+}
+
+impl Kind {
+    /// The name of the derive used in diagnostics.
+    fn name(self) -> &'static str {
+        match self {
+            Kind::ToOwned => "#[derive(ToOwned)]",
+            Kind::Borrow => "#[derive(Borrow)]",
+        }
+    }
+
+    /// The helper attribute of the derive.
+    pub(crate) fn attr(self) -> &'static str {
+        match self {
+            Kind::ToOwned => "to_owned",
+            Kind::Borrow => "borrow",
+        }
+    }
+
+    /// The field option specifying the conversion function.
+    fn with(self) -> &'static str {
+        match self {
+            Kind::ToOwned => "to_owned_with",
+            Kind::Borrow => "borrow_with",
+        }
+    }
+}
+
+/// How a single field is converted.
+#[derive(Default)]
+struct Conversion {
+    copy: Option<Span>,
+    with: Option<(Span, syn::Path)>,
+}
+
+/// Implement `ToOwned` or `Borrow` for a hand-written type, converting into the
+/// type named by the `target` container attribute.
+pub(crate) fn implement(cx: &Ctxt, kind: Kind, input: syn::DeriveInput) -> Result<TokenStream, ()> {
+    let name = kind.name();
+    let attr = kind.attr();
+
+    let mut target = None::<(Span, syn::Type)>;
+    // NB: The crate path is resolved through `crate_path` ahead of time, so
+    // it's only validated here.
+    let mut krate = None::<Span>;
+
+    for a in &input.attrs {
+        if !a.path().is_ident(attr) {
+            continue;
+        }
+
+        let result = a.parse_nested_meta(|meta| {
+            let span = meta.path.span();
+
+            if meta.path.is_ident("target") {
+                meta.input.parse::<Token![=]>()?;
+                let ty = meta.input.parse()?;
+
+                if let Some((existing, _)) = &target {
+                    cx.span_error(span, format_args!("{name}: Duplicate target."));
+                    cx.span_error(*existing, format_args!("{name}: Existing one is here."));
+                } else {
+                    target = Some((span, ty));
+                }
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("crate") {
+                meta.input.parse::<Token![=]>()?;
+                meta.input.parse::<syn::Path>()?;
+
+                if let Some(existing) = krate {
+                    cx.span_error(span, format_args!("{name}: Duplicate crate."));
+                    cx.span_error(existing, format_args!("{name}: Existing one is here."));
+                } else {
+                    krate = Some(span);
+                }
+
+                return Ok(());
+            }
+
+            Err(syn::Error::new(
+                span,
+                format_args!(
+                    "{name}: Unsupported attribute, expected `target = <type>` or `crate = <path>`."
+                ),
+            ))
+        });
+
+        if let Err(error) = result {
+            cx.error(error);
+        }
+    }
+
+    let syn::Data::Struct(st) = &input.data else {
+        cx.span_error(
+            input.ident.span(),
+            format_args!("{name}: Only supported on structs."),
+        );
+        return Err(());
+    };
+
+    let Some((_, target)) = target else {
+        cx.span_error(
+            input.ident.span(),
+            format_args!(
+                "{name}: Missing #[{attr}(target = <type>)] naming the type to convert into."
+            ),
+        );
+        return Err(());
+    };
+
+    let (default, this_lt) = match kind {
+        Kind::ToOwned => (&cx.borrowme_to_owned_t_to_owned, None),
+        Kind::Borrow => (
+            &cx.borrowme_borrow_t_borrow,
+            Some(syn::Lifetime::new("'this", Span::call_site())),
+        ),
+    };
+
+    let mut entries = Vec::new();
+
+    for (index, field) in st.fields.iter().enumerate() {
+        let conversion = conversion(cx, kind, field);
+
+        let binding = match &field.ident {
+            Some(ident) => Binding::Named(ident.clone()),
+            None => Binding::Unnamed(syn::Index::from(index)),
+        };
+
+        let call = match conversion.copy {
+            Some(..) => Call::Ref,
+            None => Call::with(with_path(conversion.with.as_ref(), default)),
+        };
+
+        let bound = BoundAccess {
+            use_reference: conversion.copy.is_none(),
+            is_mut: false,
+            access: Access::SelfAccess,
+            binding: &binding,
+        };
+
+        let member = binding.as_member();
+        let expr = call.as_expr(&bound);
+        entries.push(quote!(#member: #expr));
+    }
+
+    if cx.has_errors() {
+        return Err(());
+    }
+
+    let ident = &input.ident;
+    let constructor = constructor(cx, kind, &target)?;
+    let (impl_generics, type_generics, where_generics) = input.generics.split_for_impl();
+
+    let impl_attributes = impl_attributes();
+
+    Ok(match this_lt {
+        None => {
+            let to_owned_t = &cx.borrowme_to_owned_t;
+
+            quote! {
+                #impl_attributes
+                impl #impl_generics #to_owned_t for #ident #type_generics #where_generics {
+                    type Owned = #target;
+
+                    #[inline]
+                    fn to_owned(&self) -> Self::Owned {
+                        #constructor {
+                            #(#entries,)*
+                        }
+                    }
+                }
+            }
+        }
+        Some(this_lt) => {
+            let borrow_t = &cx.borrowme_borrow_t;
+
+            // NB: Replace all lifetimes in the target with `'this`, which
+            // borrows from `&self` in `fn borrow`.
+            let target = replace_lifetimes(quote!(#target), &this_lt);
+
+            quote! {
+                #impl_attributes
+                impl #impl_generics #borrow_t for #ident #type_generics #where_generics {
+                    type Target<#this_lt> = #target where Self: #this_lt;
+
+                    #[inline]
+                    fn borrow(&self) -> Self::Target<'_> {
+                        #constructor {
+                            #(#entries,)*
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Parse the conversion options of a field, such as `#[to_owned(copy)]` or
+/// `#[borrow(borrow_with = <path>)]`.
+fn conversion(cx: &Ctxt, kind: Kind, field: &syn::Field) -> Conversion {
+    let name = kind.name();
+    let with = kind.with();
+    let mut conversion = Conversion::default();
+
+    for a in &field.attrs {
+        if !a.path().is_ident(kind.attr()) {
+            continue;
+        }
+
+        let result = a.parse_nested_meta(|meta| {
+            let span = meta.path.span();
+
+            if meta.path.is_ident("copy") {
+                conversion.copy = Some(span);
+                return Ok(());
+            }
+
+            if meta.path.is_ident(with) {
+                meta.input.parse::<Token![=]>()?;
+                let path = meta.input.parse()?;
+
+                if let Some((existing, _)) = &conversion.with {
+                    cx.span_error(span, format_args!("{name}: Duplicate conversion."));
+                    cx.span_error(*existing, format_args!("{name}: Existing one is here."));
+                } else {
+                    conversion.with = Some((span, path));
+                }
+
+                return Ok(());
+            }
+
+            Err(syn::Error::new(
+                span,
+                format_args!(
+                    "{name}: Unsupported option, expected one of `copy` or `{with} = <path>`."
+                ),
+            ))
+        });
+
+        if let Err(error) = result {
+            cx.error(error);
+        }
+    }
+
+    if let (Some(span), true) = (conversion.copy, conversion.with.is_some()) {
+        cx.span_error(
+            span,
+            format_args!("{name}: copy can't be combined with other options."),
+        );
+    }
+
+    conversion
+}
+
+/// Get the path used to construct the target, which is the path of the target
+/// type without any generic arguments since they are inferred.
+fn constructor(cx: &Ctxt, kind: Kind, target: &syn::Type) -> Result<syn::Path, ()> {
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = target else {
+        cx.span_error(
+            target.span(),
+            format_args!(
+                "{}: The target has to be a path, such as `Word<'a>`.",
+                kind.name()
+            ),
+        );
+        return Err(());
+    };
+
+    let mut path = path.clone();
+
+    if let Some(last) = path.segments.last_mut() {
+        last.arguments = syn::PathArguments::None;
+    }
+
+    Ok(path)
+}
+
+/// Replace every non-static lifetime in the given tokens with `lt`.
+fn replace_lifetimes(tokens: TokenStream, lt: &syn::Lifetime) -> TokenStream {
+    let mut out = TokenStream::new();
+    let mut is_lifetime = false;
+
+    for tt in tokens {
+        let tt = match tt {
+            TokenTree::Ident(ident) if is_lifetime && ident != "static" => {
+                TokenTree::Ident(Ident::new(&lt.ident.to_string(), ident.span()))
+            }
+            TokenTree::Group(group) => {
+                let mut new = proc_macro2::Group::new(
+                    group.delimiter(),
+                    replace_lifetimes(group.stream(), lt),
+                );
+                new.set_span(group.span());
+                TokenTree::Group(new)
+            }
+            tt => tt,
+        };
+
+        is_lifetime = matches!(&tt, TokenTree::Punct(p) if p.as_char() == '\'');
+        out.extend([tt]);
+    }
+
+    out
+}
@@ -0,0 +1,309 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{token, Token};
+
+use crate::attr::{with_path, BORROWME};
+use crate::ctxt::Ctxt;
+use crate::implement::{impl_attributes, strip_lifetimes, Access, Binding, BoundAccess, Call};
+
+const NAME: &str = "impl_borrow!";
+
+/// The input to `impl_borrow!`, such as `Word<'a> => OwnedWord { text }`.
+pub(crate) struct Input {
+    /// Leading attributes, such as `#[borrowme(crate = <path>)]`.
+    pub(crate) attrs: Vec<syn::Attribute>,
+    borrow_ident: syn::Ident,
+    generics: syn::Generics,
+    owned_ident: syn::Ident,
+    fields: Punctuated<Field, Token![,]>,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let attrs = input.call(syn::Attribute::parse_outer)?;
+        let borrow_ident = input.parse()?;
+        let mut generics: syn::Generics = input.parse()?;
+        generics.where_clause = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let owned_ident = input.parse()?;
+
+        let content;
+        syn::braced!(content in input);
+        let fields = content.parse_terminated(Field::parse, Token![,])?;
+
+        Ok(Self {
+            attrs,
+            borrow_ident,
+            generics,
+            owned_ident,
+            fields,
+        })
+    }
+}
+
+/// A field mapping, such as `text` or `text: to_owned_with = <path>`.
+struct Field {
+    member: syn::Member,
+    options: Vec<FieldOption>,
+}
+
+impl Parse for Field {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let member = input.parse()?;
+        let mut options = Vec::new();
+
+        if input.parse::<Option<Token![:]>>()?.is_some() {
+            if input.peek(token::Paren) {
+                let content;
+                syn::parenthesized!(content in input);
+                options.extend(content.parse_terminated(FieldOption::parse, Token![,])?);
+            } else {
+                options.push(input.parse()?);
+            }
+        }
+
+        Ok(Self { member, options })
+    }
+}
+
+/// A single conversion option of a field.
+struct FieldOption {
+    ident: syn::Ident,
+    path: Option<syn::Path>,
+}
+
+impl Parse for FieldOption {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let ident = input.parse()?;
+
+        let path = if input.parse::<Option<Token![=]>>()?.is_some() {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        Ok(Self { ident, path })
+    }
+}
+
+/// How a single field is converted.
+#[derive(Default)]
+struct Conversion {
+    copy: Option<Span>,
+    to_owned: Option<(Span, syn::Path)>,
+    borrow: Option<(Span, syn::Path)>,
+}
+
+impl Conversion {
+    fn new(cx: &Ctxt, field: &Field) -> Self {
+        let mut conversion = Conversion::default();
+
+        for option in &field.options {
+            let span = option.ident.span();
+
+            match (option.ident.to_string().as_str(), &option.path) {
+                ("copy", None) => {
+                    conversion.copy = Some(span);
+                }
+                ("to_owned_with", Some(path)) => {
+                    set_path(cx, &mut conversion.to_owned, span, path.clone());
+                }
+                ("borrow_with", Some(path)) => {
+                    set_path(cx, &mut conversion.borrow, span, path.clone());
+                }
+                ("with", Some(path)) => {
+                    let mut to_owned = path.clone();
+                    to_owned
+                        .segments
+                        .push(syn::Ident::new("to_owned", span).into());
+                    set_path(cx, &mut conversion.to_owned, span, to_owned);
+
+                    let mut borrow = path.clone();
+                    borrow.segments.push(syn::Ident::new("borrow", span).into());
+                    set_path(cx, &mut conversion.borrow, span, borrow);
+                }
+                _ => {
+                    cx.span_error(
+                        span,
+                        format_args!(
+                            "{NAME}: Unsupported option, expected one of `copy`, `to_owned_with = <path>`, `borrow_with = <path>` or `with = <path>`."
+                        ),
+                    );
+                }
+            }
+        }
+
+        if let (Some(span), true) = (
+            conversion.copy,
+            conversion.to_owned.is_some() || conversion.borrow.is_some(),
+        ) {
+            cx.span_error(
+                span,
+                format_args!("{NAME}: copy can't be combined with other options."),
+            );
+        }
+
+        conversion
+    }
+}
+
+fn set_path(cx: &Ctxt, existing: &mut Option<(Span, syn::Path)>, span: Span, path: syn::Path) {
+    if let Some((existing, _)) = existing {
+        cx.span_error(span, format_args!("{NAME}: Duplicate conversion."));
+        cx.span_error(*existing, format_args!("{NAME}: Existing one is here."));
+    } else {
+        *existing = Some((span, path));
+    }
+}
+
+/// Implement `ToOwned` and `Borrow` for an existing pair of types.
+pub(crate) fn implement(cx: &Ctxt, input: Input) -> Result<TokenStream, ()> {
+    let Input {
+        attrs,
+        borrow_ident,
+        generics,
+        owned_ident,
+        fields,
+    } = input;
+
+    // NB: The crate path is resolved through `crate_path` ahead of time, so
+    // it's only validated here.
+    let mut krate = None::<Span>;
+
+    for a in &attrs {
+        if !a.path().is_ident(BORROWME) {
+            cx.span_error(
+                a.path().span(),
+                format_args!(
+                    "{NAME}: Unsupported attribute, expected #[{BORROWME}(crate = <path>)]."
+                ),
+            );
+            continue;
+        }
+
+        let result = a.parse_nested_meta(|meta| {
+            let span = meta.path.span();
+
+            if meta.path.is_ident("crate") {
+                meta.input.parse::<Token![=]>()?;
+                meta.input.parse::<syn::Path>()?;
+
+                if let Some(existing) = krate {
+                    cx.span_error(span, format_args!("{NAME}: Duplicate crate."));
+                    cx.span_error(existing, format_args!("{NAME}: Existing one is here."));
+                } else {
+                    krate = Some(span);
+                }
+
+                return Ok(());
+            }
+
+            Err(syn::Error::new(
+                span,
+                format_args!("{NAME}: Unsupported attribute, expected `crate = <path>`."),
+            ))
+        });
+
+        if let Err(error) = result {
+            cx.error(error);
+        }
+    }
+
+    let mut to_owned_entries = Vec::new();
+    let mut borrow_entries = Vec::new();
+
+    for field in &fields {
+        let conversion = Conversion::new(cx, field);
+
+        let binding = match &field.member {
+            syn::Member::Named(ident) => Binding::Named(ident.clone()),
+            syn::Member::Unnamed(index) => Binding::Unnamed(index.clone()),
+        };
+
+        let (to_owned, borrow) = match conversion.copy {
+            Some(..) => (Call::Ref, Call::Ref),
+            None => (
+                Call::with(with_path(
+                    conversion.to_owned.as_ref(),
+                    &cx.borrowme_to_owned_t_to_owned,
+                )),
+                Call::with(with_path(
+                    conversion.borrow.as_ref(),
+                    &cx.borrowme_borrow_t_borrow,
+                )),
+            ),
+        };
+
+        let bound = BoundAccess {
+            use_reference: conversion.copy.is_none(),
+            is_mut: false,
+            access: Access::SelfAccess,
+            binding: &binding,
+        };
+
+        let member = &field.member;
+        let to_owned = to_owned.as_expr(&bound);
+        let borrow = borrow.as_expr(&bound);
+        to_owned_entries.push(quote!(#member: #to_owned));
+        borrow_entries.push(quote!(#member: #borrow));
+    }
+
+    if cx.has_errors() {
+        return Err(());
+    }
+
+    // NB: The owned type receives the same generics as the borrowed type,
+    // except for its lifetimes.
+    let mut owned_generics = generics.clone();
+    strip_lifetimes(&mut owned_generics);
+
+    // NB: Replace all borrowed lifetimes with `'this`, which borrows from
+    // `&self` in `fn borrow`.
+    let this_lt = syn::Lifetime::new("'this", Span::call_site());
+    let mut borrow_generics = generics.clone();
+
+    for g in &mut borrow_generics.params {
+        if let syn::GenericParam::Lifetime(l) = g {
+            l.lifetime = this_lt.clone();
+        }
+    }
+
+    let (impl_generics, type_generics, where_generics) = generics.split_for_impl();
+    let (owned_impl_generics, owned_type_generics, owned_where_generics) =
+        owned_generics.split_for_impl();
+    let (_, borrow_return_type_generics, _) = borrow_generics.split_for_impl();
+
+    let to_owned_t = &cx.borrowme_to_owned_t;
+    let borrow_t = &cx.borrowme_borrow_t;
+
+    let impl_attributes = impl_attributes();
+
+    Ok(quote! {
+        #impl_attributes
+        impl #impl_generics #to_owned_t for #borrow_ident #type_generics #where_generics {
+            type Owned = #owned_ident #owned_type_generics;
+
+            #[inline]
+            fn to_owned(&self) -> Self::Owned {
+                #owned_ident {
+                    #(#to_owned_entries,)*
+                }
+            }
+        }
+
+        #impl_attributes
+        impl #owned_impl_generics #borrow_t for #owned_ident #owned_type_generics #owned_where_generics {
+            type Target<#this_lt> = #borrow_ident #borrow_return_type_generics where Self: #this_lt;
+
+            #[inline]
+            fn borrow(&self) -> Self::Target<'_> {
+                #borrow_ident {
+                    #(#borrow_entries,)*
+                }
+            }
+        }
+    })
+}
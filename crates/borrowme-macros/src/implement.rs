@@ -1,33 +1,33 @@
 use std::collections::HashSet;
 use std::mem;
 
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Span, TokenStream, TokenTree};
 use quote::{quote, ToTokens};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{token, Token};
 
 use crate::attr;
-use crate::ctxt::Ctxt;
-use crate::respan::Respan;
+use crate::ctxt::{path, Ctxt};
+use crate::respan::{respan_stream, Respan};
 
 const NAME: &str = "#[borrowme]";
 const STATIC: &str = "static";
 const STATIC_LT: &str = "'static";
 
 #[derive(Debug, Clone, Copy)]
-enum Access {
+pub(crate) enum Access {
     SelfAccess,
     BindingAccess,
 }
 
-enum Binding {
+pub(crate) enum Binding {
     Named(syn::Ident),
     Unnamed(syn::Index),
 }
 
 impl Binding {
-    fn as_member(&self) -> syn::Member {
+    pub(crate) fn as_member(&self) -> syn::Member {
         match self {
             Binding::Named(ident) => syn::Member::Named(ident.clone()),
             Binding::Unnamed(index) => syn::Member::Unnamed(index.clone()),
@@ -88,11 +88,11 @@ impl ToTokens for Binding {
     }
 }
 
-struct BoundAccess<'a> {
-    use_reference: bool,
-    is_mut: bool,
-    access: Access,
-    binding: &'a Binding,
+pub(crate) struct BoundAccess<'a> {
+    pub(crate) use_reference: bool,
+    pub(crate) is_mut: bool,
+    pub(crate) access: Access,
+    pub(crate) binding: &'a Binding,
 }
 
 impl BoundAccess<'_> {
@@ -131,13 +131,28 @@ impl BoundAccess<'_> {
 }
 
 #[derive(Clone, Copy)]
-enum Call<'a> {
+pub(crate) enum Call<'a> {
     Path(&'a syn::Path),
+    /// Call a path specified through an attribute. The argument is spanned to
+    /// the attribute, so that a function with the wrong signature is reported
+    /// there instead of in generated code.
+    With(Span, &'a syn::Path),
     Ref,
+    /// Construct a new marker value, such as `PhantomData`.
+    Marker(&'a syn::Path),
+    /// Borrow through `Deref`, such as `&*self.field`.
+    Deref,
 }
 
-impl Call<'_> {
-    fn as_expr(self, access: &BoundAccess<'_>) -> syn::Expr {
+impl<'a> Call<'a> {
+    pub(crate) fn with((span, path): (Option<Span>, &'a syn::Path)) -> Self {
+        match span {
+            Some(span) => Call::With(span, path),
+            None => Call::Path(path),
+        }
+    }
+
+    pub(crate) fn as_expr(self, access: &BoundAccess<'_>) -> syn::Expr {
         match self {
             Call::Path(path) => {
                 let mut call = syn::ExprCall {
@@ -154,24 +169,121 @@ impl Call<'_> {
                 call.args.push(access.as_expr());
                 syn::Expr::Call(call)
             }
-            Call::Ref => access.as_expr(),
+            Call::With(span, path) => {
+                let arg = respan_stream(access.as_expr().into_token_stream(), span);
+
+                let mut call = syn::ExprCall {
+                    attrs: Vec::new(),
+                    func: Box::new(syn::Expr::Path(syn::ExprPath {
+                        attrs: Vec::new(),
+                        qself: None,
+                        path: path.clone(),
+                    })),
+                    paren_token: token::Paren(span),
+                    args: Punctuated::new(),
+                };
+
+                call.args.push(syn::Expr::Verbatim(arg));
+                syn::Expr::Call(call)
+            }
+            // NB: Match bindings are references, so copy fields have to be
+            // dereferenced when they're passed by value.
+            Call::Ref => match access.access {
+                Access::BindingAccess if !access.use_reference => {
+                    let expr = access.as_expr();
+                    syn::parse_quote!(*#expr)
+                }
+                _ => access.as_expr(),
+            },
+            Call::Marker(path) => syn::Expr::Path(syn::ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: path.clone(),
+            }),
+            Call::Deref => {
+                let expr = access.as_expr();
+
+                if access.is_mut {
+                    syn::parse_quote!(&mut **#expr)
+                } else {
+                    syn::parse_quote!(&**#expr)
+                }
+            }
         }
     }
 }
 
+/// A field in the mutable view of a struct.
+struct MutViewField {
+    /// Whether the field is a reference which is borrowed mutably.
+    is_mut: bool,
+    /// The expression used to construct the field.
+    value: syn::FieldValue,
+}
+
+/// Additional where predicates needed by the generated items.
+#[derive(Default)]
+struct Bounds {
+    /// Predicates added to the owned type and every generated impl.
+    owned: Vec<syn::WherePredicate>,
+    /// Predicates added to the generated `ToOwned` and `TryToOwned` impls.
+    to_owned: Vec<syn::WherePredicate>,
+    /// Predicates added to the generated `Borrow` or `BorrowMut` impl.
+    borrow: Vec<syn::WherePredicate>,
+    /// Type parameters which are referenced and owned as `T::Owned`.
+    generic_references: HashSet<syn::Ident>,
+}
+
 pub(crate) fn implement(
     cx: &Ctxt,
     attrs: &[syn::Attribute],
     mut item: syn::Item,
 ) -> Result<TokenStream, ()> {
+    // NB: Parsed before the owned variant is copied, so that the attributes are
+    // stripped from both.
+    let convert = match &mut item {
+        syn::Item::Struct(st) => convert_params(cx, &mut st.generics),
+        syn::Item::Enum(en) => convert_params(cx, &mut en.generics),
+        syn::Item::Union(un) => convert_params(cx, &mut un.generics),
+        _ => Vec::new(),
+    };
+
+    let converted = convert
+        .iter()
+        .map(|(_, param)| param.clone())
+        .collect::<HashSet<_>>();
+
     let mut output = item.clone();
 
     let mut needs_mut = false;
+    let mut bounds = Bounds::default();
+
+    let params = match &item {
+        syn::Item::Struct(st) => type_params(&st.generics),
+        syn::Item::Enum(en) => type_params(&en.generics),
+        syn::Item::Union(un) => type_params(&un.generics),
+        _ => HashSet::new(),
+    };
 
-    let (to_owned_fn, borrow_fn) = match (&mut output, &mut item) {
+    let mut mut_view = None;
+    let mut static_variant = None;
+    let mut std_borrow = None;
+    let mut try_to_owned = None;
+    let mut needs_try = false;
+    let debug;
+    let owned_static;
+    let owned_hash;
+    let from;
+    let borrow_ref;
+    let bound;
+    // Hashing attributes of each field, grouped by variant.
+    let mut hash_fields = Vec::new();
+
+    let (to_owned_fn, borrow_fn, inline, leak) = match (&mut output, &mut item) {
         (syn::Item::Struct(o_st), syn::Item::Struct(b_st)) => {
             let attr = attr::container(cx, attrs, &o_st.attrs)?;
             attr::strip([&mut o_st.attrs, &mut b_st.attrs]);
+            attr::strip_owned(&mut o_st.attrs);
 
             apply_attributes(&attr.attributes, &mut o_st.attrs, &mut b_st.attrs);
             process_generics(
@@ -181,25 +293,87 @@ pub(crate) fn implement(
                 o_st.fields.is_empty(),
             );
             o_st.ident = attr.owned_ident(&o_st.ident);
+            let inline = attr.inline();
+            static_variant = attr.static_variant.clone();
+            let leak = attr.leak.is_some();
+            debug = attr.debug.is_some();
+            owned_static = attr.owned_static.is_some();
+            owned_hash = attr.owned_hash.is_some();
+            from = attr.from.is_some();
+            borrow_ref = attr.borrow_ref.is_some();
+            bound = attr.bound.clone();
+
+            // Dynamically sized types can't be constructed by value, so there
+            // is no way to convert to and from them.
+            if let Some(field) = b_st.fields.iter().last() {
+                if is_unsized(&field.ty) {
+                    let mut error = syn::Error::new(
+                        field.ty.span(),
+                        format_args!("{NAME}: unsized fields are not supported."),
+                    );
+
+                    error.combine(syn::Error::new(
+                        field.span(),
+                        "Hint: store the field behind a reference or a container such as `&'a [T]` or `Box<[T]>`",
+                    ));
+                    cx.error(error);
+                    return Err(());
+                }
+            }
 
             let mut to_owned_entries = Vec::new();
             let mut borrow_entries = Vec::new();
+            let mut try_to_owned_entries = Vec::new();
+            let mut mut_view_fields = Vec::new();
+            let mut hashed = Vec::new();
 
             process_fields(
                 cx,
                 Access::SelfAccess,
                 attr.kind,
+                attr.with.as_ref(),
+                attr.no_heuristics.is_none(),
+                &attr.map_types,
+                attr.boxed_slices.map(|(span, _)| span),
+                attr.serde.map(|(span, _)| span),
                 &mut o_st.fields,
                 &mut b_st.fields,
+                &params,
+                &converted,
                 &mut to_owned_entries,
                 &mut borrow_entries,
+                &mut try_to_owned_entries,
+                &mut mut_view_fields,
+                &mut hashed,
+                &mut needs_try,
                 &mut needs_mut,
+                &mut bounds,
             )?;
 
+            hash_fields.push(hashed);
+
+            if let Some((span, ident)) = &attr.mut_view {
+                if needs_mut {
+                    cx.span_error(
+                        *span,
+                        format_args!(
+                            "{NAME}: mut_view can't be used when a field requires mutable access."
+                        ),
+                    );
+                    return Err(());
+                }
+
+                mut_view = Some((ident.clone(), mut_view_fields));
+            }
+
+            if let Some((span, _)) = &attr.std_borrow {
+                std_borrow = Some(std_borrow_field(cx, *span, o_st, b_st)?);
+            }
+
             let owned_ident = &o_st.ident;
 
             let to_owned_fn = quote! {
-                #[inline]
+                #inline
                 fn to_owned(&self) -> Self::Owned {
                     #owned_ident {
                         #(#to_owned_entries,)*
@@ -207,11 +381,24 @@ pub(crate) fn implement(
                 }
             };
 
+            if let Some(error) = try_error(cx, &attr, needs_try, o_st.ident.span())? {
+                let try_to_owned_fn = quote! {
+                    #inline
+                    fn try_to_owned(&self) -> ::core::result::Result<Self::Owned, Self::Error> {
+                        ::core::result::Result::Ok(#owned_ident {
+                            #(#try_to_owned_entries,)*
+                        })
+                    }
+                };
+
+                try_to_owned = Some((error, try_to_owned_fn));
+            }
+
             let borrow_ident = &b_st.ident;
 
             let borrow_fn = if needs_mut {
                 quote! {
-                    #[inline]
+                    #inline
                     fn borrow_mut(&mut self) -> Self::TargetMut<'_> {
                         #borrow_ident {
                             #(#borrow_entries,)*
@@ -220,7 +407,7 @@ pub(crate) fn implement(
                 }
             } else {
                 quote! {
-                    #[inline]
+                    #inline
                     fn borrow(&self) -> Self::Target<'_> {
                         #borrow_ident {
                             #(#borrow_entries,)*
@@ -229,11 +416,12 @@ pub(crate) fn implement(
                 }
             };
 
-            (to_owned_fn, borrow_fn)
+            (to_owned_fn, borrow_fn, inline, leak)
         }
         (syn::Item::Enum(o_en), syn::Item::Enum(b_en)) => {
             let attr = attr::container(cx, attrs, &o_en.attrs)?;
             attr::strip([&mut o_en.attrs, &mut b_en.attrs]);
+            attr::strip_owned(&mut o_en.attrs);
 
             apply_attributes(&attr.attributes, &mut o_en.attrs, &mut b_en.attrs);
             process_generics(
@@ -243,33 +431,81 @@ pub(crate) fn implement(
                 o_en.variants.iter().all(|v| v.fields.is_empty()),
             );
             o_en.ident = attr.owned_ident(&o_en.ident);
+            let inline = attr.inline();
+            static_variant = attr.static_variant.clone();
+
+            if let Some((span, _)) = &attr.mut_view {
+                cx.span_error(
+                    *span,
+                    format_args!("{NAME}: mut_view is only supported on structs."),
+                );
+                return Err(());
+            }
+
+            if let Some((span, _)) = &attr.std_borrow {
+                cx.span_error(
+                    *span,
+                    format_args!("{NAME}: std_borrow is only supported on structs."),
+                );
+                return Err(());
+            }
+            let leak = attr.leak.is_some();
+            debug = attr.debug.is_some();
+            owned_static = attr.owned_static.is_some();
+            owned_hash = attr.owned_hash.is_some();
+            from = attr.from.is_some();
+            borrow_ref = attr.borrow_ref.is_some();
+            bound = attr.bound.clone();
 
             let mut to_owned_variants = Vec::new();
             let mut borrow_variants = Vec::new();
+            let mut try_to_owned_variants = Vec::new();
 
             let owned_ident = o_en.ident.clone();
             let borrow_ident = b_en.ident.clone();
+            let heuristics = attr.no_heuristics.is_none();
+            let map_types = &attr.map_types;
+            let boxed_slices = attr.boxed_slices.map(|(span, _)| span);
+            let serde = attr.serde.map(|(span, _)| span);
+            let with = attr.with.as_ref();
 
             for (o_variant, b_variant) in o_en.variants.iter_mut().zip(b_en.variants.iter_mut()) {
                 let attr = attr::variant(cx, &o_variant.attrs, &attr)?;
                 attr::strip([&mut o_variant.attrs, &mut b_variant.attrs]);
+                attr::strip_owned(&mut o_variant.attrs);
 
                 apply_attributes(&attr.attributes, &mut o_variant.attrs, &mut b_variant.attrs);
 
                 let mut to_owned_entries = Vec::new();
                 let mut borrow_entries = Vec::new();
+                let mut try_to_owned_entries = Vec::new();
+                let mut hashed = Vec::new();
 
                 process_fields(
                     cx,
                     Access::BindingAccess,
                     attr.kind,
+                    with,
+                    heuristics,
+                    map_types,
+                    boxed_slices,
+                    serde,
                     &mut o_variant.fields,
                     &mut b_variant.fields,
+                    &params,
+                    &converted,
                     &mut to_owned_entries,
                     &mut borrow_entries,
+                    &mut try_to_owned_entries,
+                    &mut Vec::new(),
+                    &mut hashed,
+                    &mut needs_try,
                     &mut needs_mut,
+                    &mut bounds,
                 )?;
 
+                hash_fields.push(hashed);
+
                 let fields = o_variant
                     .fields
                     .iter()
@@ -292,6 +528,16 @@ pub(crate) fn implement(
 
                 let patterns = fields.clone().map(|b| b.as_field_value());
 
+                try_to_owned_variants.push(quote! {
+                    #borrow_ident::#variant_ident { #(#patterns,)* } => {
+                        #owned_ident::#variant_ident {
+                            #(#try_to_owned_entries,)*
+                        }
+                    }
+                });
+
+                let patterns = fields.clone().map(|b| b.as_field_value());
+
                 borrow_variants.push(quote! {
                     #owned_ident::#variant_ident { #(#patterns,)* } => {
                         #borrow_ident::#variant_ident {
@@ -302,7 +548,7 @@ pub(crate) fn implement(
             }
 
             let to_owned_fn = quote! {
-                #[inline]
+                #inline
                 fn to_owned(&self) -> Self::Owned {
                     match self {
                         #(#to_owned_variants,)*
@@ -310,9 +556,22 @@ pub(crate) fn implement(
                 }
             };
 
+            if let Some(error) = try_error(cx, &attr, needs_try, owned_ident.span())? {
+                let try_to_owned_fn = quote! {
+                    #inline
+                    fn try_to_owned(&self) -> ::core::result::Result<Self::Owned, Self::Error> {
+                        ::core::result::Result::Ok(match self {
+                            #(#try_to_owned_variants,)*
+                        })
+                    }
+                };
+
+                try_to_owned = Some((error, try_to_owned_fn));
+            }
+
             let borrow_fn = if needs_mut {
                 quote! {
-                    #[inline]
+                    #inline
                     fn borrow_mut(&mut self) -> Self::TargetMut<'_> {
                         match self {
                             #(#borrow_variants,)*
@@ -321,7 +580,7 @@ pub(crate) fn implement(
                 }
             } else {
                 quote! {
-                    #[inline]
+                    #inline
                     fn borrow(&self) -> Self::Target<'_> {
                         match self {
                             #(#borrow_variants,)*
@@ -330,43 +589,318 @@ pub(crate) fn implement(
                 }
             };
 
-            (to_owned_fn, borrow_fn)
+            (to_owned_fn, borrow_fn, inline, leak)
+        }
+        (syn::Item::Union(o_un), syn::Item::Union(b_un)) => {
+            let attr = attr::container(cx, attrs, &o_un.attrs)?;
+            attr::strip([&mut o_un.attrs, &mut b_un.attrs]);
+            attr::strip_owned(&mut o_un.attrs);
+
+            apply_attributes(&attr.attributes, &mut o_un.attrs, &mut b_un.attrs);
+            // NB: Unions can't capture lifetimes since every field is copied.
+            let lifetimes = b_un
+                .generics
+                .lifetimes()
+                .map(|lt| lt.lifetime.ident.clone())
+                .collect::<HashSet<_>>();
+            process_generics(cx, o_un.ident.span(), &mut o_un.generics, true);
+            o_un.ident = attr.owned_ident(&o_un.ident);
+            let inline = attr.inline();
+
+            let unsupported = [
+                ("mut_view", attr.mut_view.as_ref().map(|(span, _)| *span)),
+                (
+                    "static_variant",
+                    attr.static_variant.as_ref().map(|(span, _)| *span),
+                ),
+                ("std_borrow", attr.std_borrow.map(|(span, _)| span)),
+                ("debug", attr.debug.map(|(span, _)| span)),
+                ("owned_hash", attr.owned_hash.map(|(span, _)| span)),
+                ("with", attr.with.as_ref().map(|(span, _)| *span)),
+                ("map_type", attr.map_types.first().map(|m| m.span)),
+                ("boxed_slices", attr.boxed_slices.map(|(span, _)| span)),
+                ("serde", attr.serde.map(|(span, _)| span)),
+            ];
+
+            for (name, span) in unsupported {
+                if let Some(span) = span {
+                    cx.span_error(
+                        span,
+                        format_args!("{NAME}: {name} is not supported on unions."),
+                    );
+                }
+            }
+
+            let leak = attr.leak.is_some();
+            debug = false;
+            owned_static = attr.owned_static.is_some();
+            owned_hash = false;
+            from = attr.from.is_some();
+            borrow_ref = attr.borrow_ref.is_some();
+            bound = attr.bound.clone();
+
+            if let Some((span, _)) = convert.first() {
+                cx.span_error(
+                    *span,
+                    format_args!("{NAME}: convert is not supported on unions."),
+                );
+            }
+
+            if !is_repr_c(&b_un.attrs) {
+                let mut error = syn::Error::new(
+                    b_un.ident.span(),
+                    format_args!("{NAME}: unions have to be #[repr(C)]."),
+                );
+
+                error.combine(syn::Error::new(
+                    b_un.union_token.span(),
+                    "Hint: the owned union is copied from the borrowed one, so they need to have the same layout",
+                ));
+                cx.error(error);
+            }
+
+            let variant_attrs = [
+                (attr::OWNED_ATTR, &attr.attributes.own),
+                (attr::BORROWED_ATTR, &attr.attributes.borrow),
+            ];
+
+            for (name, metas) in variant_attrs {
+                for meta in metas {
+                    if meta.path().is_ident("repr") {
+                        let mut error = syn::Error::new(
+                            meta.span(),
+                            format_args!("{NAME}: repr can't be used in {name} on unions."),
+                        );
+
+                        error.combine(syn::Error::new(
+                            b_un.union_token.span(),
+                            "Hint: the owned union is copied from the borrowed one, so put the repr on the union to apply it to both",
+                        ));
+                        cx.error(error);
+                    }
+                }
+            }
+
+            process_union_fields(
+                cx,
+                attr.kind,
+                attr.no_heuristics.is_none(),
+                &lifetimes,
+                &mut o_un.fields,
+                &mut b_un.fields,
+            );
+
+            if cx.has_errors() {
+                return Err(());
+            }
+
+            let owned_ident = &o_un.ident;
+
+            // SAFETY: Every field has the same type in both unions, which is
+            // asserted to be `Copy`. Both unions are `#[repr(C)]` with the same
+            // repr, since it can't be changed for only one of them, and their
+            // layouts are asserted to be equal. So they can be copied bitwise
+            // into one another.
+            let to_owned = union_copy(&o_un.fields, quote!(Self::Owned));
+
+            let to_owned_fn = quote! {
+                #inline
+                fn to_owned(&self) -> Self::Owned {
+                    #to_owned
+                }
+            };
+
+            if let Some(error) = try_error(cx, &attr, false, owned_ident.span())? {
+                let try_to_owned_fn = quote! {
+                    #inline
+                    fn try_to_owned(&self) -> ::core::result::Result<Self::Owned, Self::Error> {
+                        ::core::result::Result::Ok({ #to_owned })
+                    }
+                };
+
+                try_to_owned = Some((error, try_to_owned_fn));
+            }
+
+            let borrow = union_copy(&o_un.fields, quote!(Self::Target<'_>));
+
+            let borrow_fn = quote! {
+                #inline
+                fn borrow(&self) -> Self::Target<'_> {
+                    #borrow
+                }
+            };
+
+            (to_owned_fn, borrow_fn, inline, leak)
         }
         (_, item) => {
             cx.span_error(
                 item.span(),
-                format_args!("{NAME}: is only supported on structs."),
+                format_args!("{NAME}: is only supported on structs, enums, and unions."),
             );
             return Err(());
         }
     };
 
-    let (owned_ident, owned_generics) = match &output {
-        syn::Item::Struct(st) => (&st.ident, &st.generics),
-        syn::Item::Enum(en) => (&en.ident, &en.generics),
+    if let (Some((span, _)), true) = (convert.first(), needs_mut) {
+        cx.span_error(
+            *span,
+            format_args!("{NAME}: convert can't be used when a field requires mutable access."),
+        );
+        return Err(());
+    }
+
+    // Type parameters marked with `convert` stand in for their owned type in
+    // the owned variant, so they're converted through `ToOwned` and `Borrow`.
+    for (_, param) in &convert {
+        let to_owned_t = &cx.borrowme_to_owned_t;
+        let borrow_t = &cx.borrowme_borrow_t;
+        bounds.to_owned.push(syn::parse_quote!(#param: #to_owned_t));
+        bounds.borrow.push(syn::parse_quote!(#param: #borrow_t));
+    }
+
+    // NB: Explicitly specified bounds replace the ones which are inferred for
+    // the conversions, but not the ones needed by the owned type itself.
+    if let Some((_, predicates)) = bound {
+        bounds.to_owned = predicates.clone();
+        bounds.borrow = predicates;
+    }
+
+    // Require that the owned variant is `'static`, which in turn requires that
+    // every type parameter or its owned variant is.
+    if owned_static {
+        let generics = match &item {
+            syn::Item::Struct(st) => &st.generics,
+            syn::Item::Enum(en) => &en.generics,
+            syn::Item::Union(un) => &un.generics,
+            _ => return Err(()),
+        };
+
+        let to_owned_t = &cx.borrowme_to_owned_t;
+
+        for param in generics.type_params() {
+            let param = &param.ident;
+
+            if bounds.generic_references.contains(param) {
+                bounds
+                    .owned
+                    .push(syn::parse_quote!(<#param as #to_owned_t>::Owned: 'static));
+            } else {
+                bounds.owned.push(syn::parse_quote!(#param: 'static));
+            }
+        }
+    }
+
+    let (owned_ident, owned_vis, owned_generics) = match &mut output {
+        syn::Item::Struct(st) => (&st.ident, &st.vis, &mut st.generics),
+        syn::Item::Enum(en) => (&en.ident, &en.vis, &mut en.generics),
+        syn::Item::Union(un) => (&un.ident, &un.vis, &mut un.generics),
         _ => return Err(()),
     };
 
+    owned_generics
+        .make_where_clause()
+        .predicates
+        .extend(bounds.owned.iter().cloned());
+
+    let owned_generics = &*owned_generics;
+
     let (borrow_ident, borrow_generics) = match &item {
         syn::Item::Struct(st) => (&st.ident, &st.generics),
         syn::Item::Enum(en) => (&en.ident, &en.generics),
+        syn::Item::Union(un) => (&un.ident, &un.generics),
         _ => {
             return Err(());
         }
     };
 
-    let (_, to_owned_type_generics, _) = owned_generics.split_for_impl();
+    // A type alias for the borrowed variant where every lifetime is `'static`,
+    // which is what constant data is stored as.
+    let static_variant = match static_variant {
+        Some((span, ident)) => {
+            let Some(static_variant) = static_variant_alias(&item, ident) else {
+                cx.span_error(
+                    span,
+                    format_args!("{NAME}: static_variant requires the borrowed variant to have lifetime parameters."),
+                );
+                return Err(());
+            };
+
+            Some(static_variant)
+        }
+        None => None,
+    };
+
+    let impl_attributes = impl_attributes();
+
+    let to_owned_type_generics = {
+        let to_owned_t = &cx.borrowme_to_owned_t;
+        converted_type_generics(
+            owned_generics,
+            &converted,
+            |param| quote!(<#param as #to_owned_t>::Owned),
+        )
+    };
 
     let to_owned = {
-        let (impl_generics, type_generics, where_generics) = borrow_generics.split_for_impl();
+        let mut to_owned_generics = borrow_generics.clone();
+
+        to_owned_generics
+            .make_where_clause()
+            .predicates
+            .extend(bounds.owned.iter().chain(&bounds.to_owned).cloned());
+
+        let (impl_generics, type_generics, where_generics) = to_owned_generics.split_for_impl();
         let to_owned = &cx.borrowme_to_owned_t;
 
+        // NB: A fallible conversion also implements `TryFrom` taking the
+        // borrowed variant by value, which shares its error type.
+        let try_to_owned = try_to_owned.map(|(error, try_to_owned_fn)| {
+            let try_to_owned_t = &cx.borrowme_try_to_owned_t;
+            let try_from_t = &cx.core_convert_try_from_t;
+
+            quote! {
+                #impl_attributes
+                impl #impl_generics #try_to_owned_t for #borrow_ident #type_generics #where_generics {
+                    type Owned = #owned_ident #to_owned_type_generics;
+                    type Error = #error;
+                    #try_to_owned_fn
+                }
+
+                #impl_attributes
+                impl #impl_generics #try_from_t<#borrow_ident #type_generics> for #owned_ident #to_owned_type_generics #where_generics {
+                    type Error = #error;
+
+                    #inline
+                    fn try_from(value: #borrow_ident #type_generics) -> ::core::result::Result<Self, Self::Error> {
+                        <#borrow_ident #type_generics as #try_to_owned_t>::try_to_owned(&value)
+                    }
+                }
+            }
+        });
+
+        let from = from.then(|| {
+            let from_t = &cx.core_convert_from_t;
+
+            quote! {
+                #impl_attributes
+                impl #impl_generics #from_t<#borrow_ident #type_generics> for #owned_ident #to_owned_type_generics #where_generics {
+                    #inline
+                    fn from(value: #borrow_ident #type_generics) -> Self {
+                        <#borrow_ident #type_generics as #to_owned>::to_owned(&value)
+                    }
+                }
+            }
+        });
+
         quote! {
-            #[automatically_derived]
+            #impl_attributes
             impl #impl_generics #to_owned for #borrow_ident #type_generics #where_generics {
                 type Owned = #owned_ident #to_owned_type_generics;
                 #to_owned_fn
             }
+
+            #try_to_owned
+            #from
         }
     };
 
@@ -383,17 +917,104 @@ pub(crate) fn implement(
             }
         }
 
-        let (_, borrow_return_type_generics, _) = borrow_generics.split_for_impl();
+        let borrow_t = &cx.borrowme_borrow_t;
+
+        let borrow_return_type_generics = converted_type_generics(
+            &borrow_generics,
+            &converted,
+            |param| quote!(<#param as #borrow_t>::Target<#this_lt>),
+        );
+
+        let mut leak_generics = borrow_generics.clone();
+
+        for g in &mut leak_generics.params {
+            if let syn::GenericParam::Lifetime(l) = g {
+                l.lifetime = syn::Lifetime::new(STATIC_LT, Span::call_site());
+            }
+        }
+
+        let leak_return_type_generics = converted_type_generics(
+            &leak_generics,
+            &converted,
+            |param| quote!(<#param as #borrow_t>::Target<'static>),
+        );
+
+        let mut owned_generics = owned_generics.clone();
+
+        owned_generics
+            .make_where_clause()
+            .predicates
+            .extend(bounds.borrow.iter().cloned());
 
         let (impl_generics, type_generics, where_generics) = owned_generics.split_for_impl();
 
-        if needs_mut {
+        let leak = leak.then(|| {
+            let leak = if needs_mut {
+                &cx.borrowme_leak_mut
+            } else {
+                &cx.borrowme_leak
+            };
+
+            quote! {
+                #impl_attributes
+                impl #impl_generics #owned_ident #type_generics #where_generics {
+                    /// Borrow this value for the `'static` lifetime by leaking
+                    /// it, which means that its memory is never reclaimed.
+                    #inline
+                    #owned_vis fn leak(self) -> #borrow_ident #leak_return_type_generics where Self: 'static {
+                        #leak(self)
+                    }
+                }
+            }
+        });
+
+        let mut_view = mut_view.map(|(view_ident, fields)| {
+            let mut view = match &item {
+                syn::Item::Struct(st) => st.clone(),
+                _ => unreachable!(),
+            };
+
+            view.ident = view_ident;
+            view.attrs.clear();
+
+            for (field, view_field) in view.fields.iter_mut().zip(&fields) {
+                field.attrs.clear();
+
+                if let (true, syn::Type::Reference(ty)) = (view_field.is_mut, &mut field.ty) {
+                    ty.mutability = Some(<Token![mut]>::default());
+                }
+            }
+
+            let view_ident = &view.ident;
+            let entries = fields.iter().map(|f| &f.value);
+            let borrow_mut_t = &cx.borrowme_borrow_mut_t;
+
+            quote! {
+                #view
+
+                #impl_attributes
+                impl #impl_generics #borrow_mut_t for #owned_ident #type_generics #where_generics {
+                    type TargetMut<#this_lt> = #view_ident #borrow_return_type_generics where Self: #this_lt;
+
+                    #inline
+                    fn borrow_mut(&mut self) -> Self::TargetMut<'_> {
+                        #view_ident {
+                            #(#entries,)*
+                        }
+                    }
+                }
+            }
+        });
+
+        // NB: `Self: 'this` implies that every type parameter outlives `'this`,
+        // which is needed when they are borrowed by the target.
+        let borrow = if needs_mut {
             let borrow_mut_t = &cx.borrowme_borrow_mut_t;
 
             quote! {
-                #[automatically_derived]
+                #impl_attributes
                 impl #impl_generics #borrow_mut_t for #owned_ident #type_generics #where_generics {
-                    type TargetMut<#this_lt> = #borrow_ident #borrow_return_type_generics;
+                    type TargetMut<#this_lt> = #borrow_ident #borrow_return_type_generics where Self: #this_lt;
                     #borrow_fn
                 }
             }
@@ -401,46 +1022,982 @@ pub(crate) fn implement(
             let borrow_t = &cx.borrowme_borrow_t;
 
             quote! {
-                #[automatically_derived]
+                #impl_attributes
                 impl #impl_generics #borrow_t for #owned_ident #type_generics #where_generics {
-                    type Target<#this_lt> = #borrow_ident #borrow_return_type_generics;
+                    type Target<#this_lt> = #borrow_ident #borrow_return_type_generics where Self: #this_lt;
                     #borrow_fn
                 }
             }
-        }
+        };
+
+        // NB: A field which needs mutable access means that the borrowed
+        // variant can only be constructed from `&mut self`.
+        let borrow_ref = borrow_ref.then(|| {
+            let (receiver, borrow) = if needs_mut {
+                let borrow_mut_t = &cx.borrowme_borrow_mut_t;
+                (quote!(&#this_lt mut self), quote!(#borrow_mut_t::borrow_mut(self)))
+            } else {
+                let borrow_t = &cx.borrowme_borrow_t;
+                (quote!(&#this_lt self), quote!(#borrow_t::borrow(self)))
+            };
+
+            quote! {
+                #impl_attributes
+                impl #impl_generics #owned_ident #type_generics #where_generics {
+                    /// Borrow this value as its *borrowed* variant.
+                    ///
+                    /// There is no way to borrow into an existing borrowed
+                    /// variant to reuse it, since its lifetime is tied to the
+                    /// borrow of `self` through a generic associated type. So
+                    /// a new value is constructed every time.
+                    #inline
+                    #owned_vis fn borrow_ref<#this_lt>(#receiver) -> #borrow_ident #borrow_return_type_generics {
+                        #borrow
+                    }
+                }
+            }
+        });
+
+        // NB: The borrowed variant borrows from the reference for `'this`,
+        // which matches the lifetime of the `Target<'this>` associated type.
+        let from = from.then(|| {
+            let from_t = &cx.core_convert_from_t;
+
+            let mut from_generics = owned_generics.clone();
+            from_generics.params.insert(
+                0,
+                syn::GenericParam::Lifetime(syn::LifetimeParam::new(this_lt.clone())),
+            );
+            from_generics
+                .make_where_clause()
+                .predicates
+                .push(syn::parse_quote!(#owned_ident #type_generics: #this_lt));
+
+            let (impl_generics, _, where_generics) = from_generics.split_for_impl();
+
+            let (reference, borrow) = if needs_mut {
+                let borrow_mut_t = &cx.borrowme_borrow_mut_t;
+                (quote!(&#this_lt mut), quote!(#borrow_mut_t::borrow_mut(value)))
+            } else {
+                let borrow_t = &cx.borrowme_borrow_t;
+                (quote!(&#this_lt), quote!(#borrow_t::borrow(value)))
+            };
+
+            quote! {
+                #impl_attributes
+                impl #impl_generics #from_t<#reference #owned_ident #type_generics> for #borrow_ident #borrow_return_type_generics #where_generics {
+                    #inline
+                    fn from(value: #reference #owned_ident #type_generics) -> Self {
+                        #borrow
+                    }
+                }
+            }
+        });
+
+        let std_borrow = std_borrow.map(|(member, owned_ty, elem)| {
+            let core_borrow_t = &cx.core_borrow_t;
+
+            // NB: The call is spanned to the owned type of the field, so that
+            // it's reported there if it doesn't implement `Borrow`.
+            let borrow = quote::quote_spanned! {
+                owned_ty.span() => <#owned_ty as #core_borrow_t<#elem>>::borrow(&self.#member)
+            };
+
+            quote! {
+                #impl_attributes
+                impl #impl_generics #core_borrow_t<#elem> for #owned_ident #type_generics #where_generics {
+                    #inline
+                    fn borrow(&self) -> &#elem {
+                        #borrow
+                    }
+                }
+            }
+        });
+
+        quote!(#borrow #borrow_ref #leak #mut_view #std_borrow #from)
     };
 
+    if !owned_hash {
+        let mut has_errors = false;
+
+        for (span, _) in hash_fields.iter().flatten().flatten() {
+            cx.span_error(
+                *span,
+                format_args!(
+                    "{NAME}: hash and no_hash require #[borrowme(owned_hash)] on the container."
+                ),
+            );
+            has_errors = true;
+        }
+
+        if has_errors {
+            return Err(());
+        }
+    }
+
     let mut stream = TokenStream::new();
     item.to_tokens(&mut stream);
     output.to_tokens(&mut stream);
+    static_variant.to_tokens(&mut stream);
     to_owned.to_tokens(&mut stream);
     borrow.to_tokens(&mut stream);
+
+    if debug {
+        debug_impl(cx, &item, &params).to_tokens(&mut stream);
+        debug_impl(cx, &output, &params).to_tokens(&mut stream);
+    }
+
+    if owned_hash {
+        hash_impl(cx, &output, &params, &hash_fields).to_tokens(&mut stream);
+    }
+
     Ok(stream)
 }
 
-fn process_fields(
+/// Process the fields of a union, which have to be copied since a union can't
+/// be converted field by field.
+fn process_union_fields(
     cx: &Ctxt,
-    access: Access,
     default_kind: Option<(Span, attr::FieldTypeKind)>,
-    o_fields: &mut syn::Fields,
-    b_fields: &mut syn::Fields,
-    to_owned_entries: &mut Vec<syn::FieldValue>,
-    borrow_entries: &mut Vec<syn::FieldValue>,
-    parent_needs_mut: &mut bool,
-) -> Result<(), ()> {
-    for (index, (o_field, b_field)) in o_fields.iter_mut().zip(b_fields.iter_mut()).enumerate() {
-        let field_ty_spans = field_ty_spans(o_field);
+    heuristics: bool,
+    lifetimes: &HashSet<syn::Ident>,
+    o_fields: &mut syn::FieldsNamed,
+    b_fields: &mut syn::FieldsNamed,
+) {
+    for (o_field, b_field) in o_fields.named.iter_mut().zip(b_fields.named.iter_mut()) {
+        let Ok(attr) = attr::field(
+            cx,
+            field_ty_spans(o_field),
+            &o_field.attrs,
+            default_kind,
+            None,
+        ) else {
+            continue;
+        };
 
-        let mut attr = attr::field(cx, field_ty_spans, &o_field.attrs, default_kind)?;
         attr::strip([&mut o_field.attrs, &mut b_field.attrs]);
+        attr::strip_owned(&mut o_field.attrs);
         apply_attributes(&attr.attributes, &mut o_field.attrs, &mut b_field.attrs);
 
-        // Ensure that the field does not make use of any lifetimes.
+        if let Some((_, vis)) = &attr.owned_vis {
+            o_field.vis = vis.clone();
+        }
+
+        if let Some(span) = attr.conversion_span(&[attr::COPY, attr::NO_COPY]) {
+            cx.span_error(
+                span,
+                format_args!("{NAME}: union fields are always copied, so they can't specify how they are converted."),
+            );
+        }
+
+        if let Some(span) = find_lifetime(o_field.ty.to_token_stream(), lifetimes) {
+            let mut error = syn::Error::new(
+                span,
+                format_args!("{NAME}: union fields can't capture lifetimes."),
+            );
+
+            error.combine(syn::Error::new(
+                o_field.span(),
+                "Hint: the owned union is copied from the borrowed one, so it would capture the same lifetimes",
+            ));
+            cx.error(error);
+            continue;
+        }
+
+        let mut ty = o_field.ty.clone();
+        let (type_hint, _) = process_type(&mut ty, &HashSet::new(), &mut Vec::new());
+
+        let is_copy = match attr.ty.kind() {
+            attr::FieldTypeKind::Copy(is_copy) => is_copy,
+            _ => heuristics && matches!(type_hint, TypeHint::Copy),
+        };
+
+        if !is_copy {
+            let mut error = syn::Error::new(
+                o_field.ty.span(),
+                format_args!("{NAME}: union fields have to be copied, since they can't be converted field by field."),
+            );
+
+            error.combine(syn::Error::new(
+                o_field.span(),
+                "Hint: add #[copy] to this field",
+            ));
+            cx.error(error);
+        }
+    }
+}
+
+/// Find the span of the first use of any of the given lifetimes.
+fn find_lifetime(tokens: TokenStream, lifetimes: &HashSet<syn::Ident>) -> Option<Span> {
+    let mut tokens = tokens.into_iter().peekable();
+
+    while let Some(tt) = tokens.next() {
+        match tt {
+            TokenTree::Punct(p) if p.as_char() == '\'' => {
+                if let Some(TokenTree::Ident(ident)) = tokens.peek() {
+                    if lifetimes.contains(ident) {
+                        return Some(ident.span());
+                    }
+                }
+            }
+            TokenTree::Group(group) => {
+                if let Some(span) = find_lifetime(group.stream(), lifetimes) {
+                    return Some(span);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Generate an expression bitwise copying `self` into the union `target`.
+///
+/// Since unions with generics can't be checked up front, this asserts that
+/// every field is `Copy` and that both unions have the same layout when the
+/// conversion is instantiated.
+fn union_copy(fields: &syn::FieldsNamed, target: TokenStream) -> TokenStream {
+    let tys = fields.named.iter().map(|f| &f.ty);
+
+    quote! {
+        fn assert_copy<T: ::core::marker::Copy>() {}
+
+        struct SameLayout<A, B>(::core::marker::PhantomData<(A, B)>);
+
+        impl<A, B> SameLayout<A, B> {
+            const ASSERT: () = ::core::assert!(
+                ::core::mem::size_of::<A>() == ::core::mem::size_of::<B>()
+                    && ::core::mem::align_of::<A>() == ::core::mem::align_of::<B>(),
+                "the owned and borrowed unions have to have the same layout"
+            );
+        }
+
+        #(assert_copy::<#tys>();)*
+        let () = SameLayout::<Self, #target>::ASSERT;
+        unsafe { ::core::mem::transmute_copy::<Self, #target>(self) }
+    }
+}
+
+/// Parse the type parameters marked with `#[borrowme(convert)]`, which can't
+/// have any bounds since they would apply to both the borrowed and the owned
+/// type.
+fn convert_params(cx: &Ctxt, generics: &mut syn::Generics) -> Vec<(Span, syn::Ident)> {
+    let convert = attr::convert_params(cx, generics);
+
+    let converted = convert
+        .iter()
+        .map(|(_, param)| param.clone())
+        .collect::<HashSet<_>>();
+
+    for param in generics.type_params() {
+        if converted.contains(&param.ident) && !param.bounds.is_empty() {
+            cx.span_error(
+                param.bounds.span(),
+                format_args!("{NAME}: type parameters marked with convert can't have bounds."),
+            );
+        }
+    }
+
+    if let Some(where_clause) = &generics.where_clause {
+        for predicate in &where_clause.predicates {
+            if mentions_param(predicate.to_token_stream(), &converted) {
+                cx.span_error(
+                    predicate.span(),
+                    format_args!("{NAME}: type parameters marked with convert can't have bounds."),
+                );
+            }
+        }
+    }
+
+    convert
+}
+
+/// Construct the type generics of a generic item, where each type parameter in
+/// `converted` is replaced with the type constructed by `f`.
+fn converted_type_generics(
+    generics: &syn::Generics,
+    converted: &HashSet<syn::Ident>,
+    f: impl Fn(&syn::Ident) -> TokenStream,
+) -> TokenStream {
+    if converted.is_empty() {
+        let (_, type_generics, _) = generics.split_for_impl();
+        return type_generics.to_token_stream();
+    }
+
+    let params = generics.params.iter().map(|param| match param {
+        syn::GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+        syn::GenericParam::Type(param) if converted.contains(&param.ident) => f(&param.ident),
+        syn::GenericParam::Type(param) => param.ident.to_token_stream(),
+        syn::GenericParam::Const(param) => param.ident.to_token_stream(),
+    });
+
+    quote!(<#(#params),*>)
+}
+
+/// Construct a type alias for the borrowed variant of the given item where
+/// every lifetime is `'static`, or `None` if it doesn't have any lifetimes.
+fn static_variant_alias(item: &syn::Item, ident: syn::Ident) -> Option<TokenStream> {
+    let (vis, borrow_ident, generics) = match item {
+        syn::Item::Struct(st) => (&st.vis, &st.ident, &st.generics),
+        syn::Item::Enum(en) => (&en.vis, &en.ident, &en.generics),
+        _ => return None,
+    };
+
+    generics.lifetimes().next()?;
+
+    let mut params = Vec::new();
+    let mut args = Vec::new();
+
+    for param in &generics.params {
+        match param {
+            syn::GenericParam::Lifetime(..) => {
+                args.push(quote!('static));
+            }
+            syn::GenericParam::Type(ty) => {
+                // NB: Bounds on type aliases aren't enforced, so they're
+                // stripped to avoid the `type_alias_bounds` lint.
+                let ident = &ty.ident;
+                params.push(quote!(#ident));
+                args.push(quote!(#ident));
+            }
+            syn::GenericParam::Const(c) => {
+                let (ident, ty) = (&c.ident, &c.ty);
+                params.push(quote!(const #ident: #ty));
+                args.push(quote!(#ident));
+            }
+        }
+    }
+
+    let params = (!params.is_empty()).then(|| quote!(<#(#params),*>));
+    let doc = format!(" The *borrowed* variant of [`{borrow_ident}`] with every lifetime set to `'static`, such as for constant data.");
+
+    Some(quote! {
+        #[doc = #doc]
+        #vis type #ident #params = #borrow_ident<#(#args),*>;
+    })
+}
+
+/// Get the argument of an option such as `&'a str` in `Option<&'a str>`.
+fn option_argument(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+
+    let last = path.segments.last()?;
+
+    if last.ident != "Option" {
+        return None;
+    }
+
+    let syn::PathArguments::AngleBracketed(generics) = &last.arguments else {
+        return None;
+    };
+
+    match (generics.args.len(), generics.args.first()) {
+        (1, Some(syn::GenericArgument::Type(ty))) => Some(ty),
+        _ => None,
+    }
+}
+
+/// Test if the given attributes contain `#[repr(C)]`.
+fn is_repr_c(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|a| {
+        let mut is_c = false;
+
+        if a.path().is_ident("repr") {
+            let _ = a.parse_nested_meta(|meta| {
+                is_c |= meta.path.is_ident("C");
+
+                // Skip over arguments such as in `align(8)`.
+                if meta.input.peek(syn::token::Paren) {
+                    let _content;
+                    syn::parenthesized!(_content in meta.input);
+                }
+
+                Ok(())
+            });
+        }
+
+        is_c
+    })
+}
+
+/// Find the member, owned type, and referenced type of the single field of a
+/// struct using `std_borrow`, such as `str` in `struct Word<'a>(&'a str)`.
+fn std_borrow_field(
+    cx: &Ctxt,
+    span: Span,
+    o_st: &syn::ItemStruct,
+    b_st: &syn::ItemStruct,
+) -> Result<(syn::Member, syn::Type, syn::Type), ()> {
+    let (o_field, b_field) = match (o_st.fields.iter().next(), b_st.fields.iter().next()) {
+        (Some(o_field), Some(b_field)) if b_st.fields.len() == 1 => (o_field, b_field),
+        _ => {
+            let mut error = syn::Error::new(
+                span,
+                format_args!("{NAME}: std_borrow requires a struct with a single field."),
+            );
+
+            error.combine(syn::Error::new(
+                b_st.ident.span(),
+                "Hint: this struct has to be a newtype such as `struct Word<'a>(&'a str)`",
+            ));
+            cx.error(error);
+            return Err(());
+        }
+    };
+
+    let elem = match &b_field.ty {
+        syn::Type::Reference(ty) if ty.mutability.is_none() => &ty.elem,
+        ty => {
+            let mut error = syn::Error::new(
+                ty.span(),
+                format_args!(
+                    "{NAME}: std_borrow requires the field to be an immutable reference such as `&'a str`."
+                ),
+            );
+
+            error.combine(syn::Error::new(span, "Hint: std_borrow is specified here"));
+            cx.error(error);
+            return Err(());
+        }
+    };
+
+    // NB: The borrowed type can't capture any lifetimes, since it's borrowed
+    // from the owned variant which doesn't have them.
+    let lifetimes = b_st
+        .generics
+        .lifetimes()
+        .map(|lt| lt.lifetime.ident.clone())
+        .collect::<HashSet<_>>();
+
+    if mentions_lifetime(elem.to_token_stream(), &lifetimes) {
+        let mut error = syn::Error::new(
+            elem.span(),
+            format_args!(
+                "{NAME}: std_borrow requires the referenced type to not capture any lifetimes."
+            ),
+        );
+
+        error.combine(syn::Error::new(span, "Hint: std_borrow is specified here"));
+        cx.error(error);
+        return Err(());
+    }
+
+    let member = match &o_field.ident {
+        Some(ident) => syn::Member::Named(ident.clone()),
+        None => syn::Member::Unnamed(syn::Index::from(0)),
+    };
+
+    Ok((member, o_field.ty.clone(), (**elem).clone()))
+}
+
+fn process_fields(
+    cx: &Ctxt,
+    access: Access,
+    default_kind: Option<(Span, attr::FieldTypeKind)>,
+    default_with: Option<&(Span, syn::Path)>,
+    heuristics: bool,
+    map_types: &[attr::MapType],
+    boxed_slices: Option<Span>,
+    serde: Option<Span>,
+    o_fields: &mut syn::Fields,
+    b_fields: &mut syn::Fields,
+    params: &HashSet<syn::Ident>,
+    converted: &HashSet<syn::Ident>,
+    to_owned_entries: &mut Vec<syn::FieldValue>,
+    borrow_entries: &mut Vec<syn::FieldValue>,
+    try_to_owned_entries: &mut Vec<syn::FieldValue>,
+    mut_view_fields: &mut Vec<MutViewField>,
+    hashed: &mut Vec<Option<(Span, bool)>>,
+    parent_needs_try: &mut bool,
+    parent_needs_mut: &mut bool,
+    bounds: &mut Bounds,
+) -> Result<(), ()> {
+    for (index, (o_field, b_field)) in o_fields.iter_mut().zip(b_fields.iter_mut()).enumerate() {
+        let field_ty_spans = field_ty_spans(o_field);
+
+        let mut attr = attr::field(
+            cx,
+            field_ty_spans,
+            &o_field.attrs,
+            default_kind,
+            default_with,
+        )?;
+        attr::strip([&mut o_field.attrs, &mut b_field.attrs]);
+        attr::strip_owned(&mut o_field.attrs);
+        hashed.push(attr.hash);
+        apply_attributes(&attr.attributes, &mut o_field.attrs, &mut b_field.attrs);
+
+        if let Some((_, vis)) = &attr.owned_vis {
+            o_field.vis = vis.clone();
+        }
+
+        // Ensure that the field does not make use of any lifetimes.
         let ignore = HashSet::new();
         let mut lifetimes = Vec::new();
         let mut as_ty = o_field.ty.clone();
 
-        let (type_hint, immediate_reference) = process_type(&mut as_ty, &ignore, &mut lifetimes);
+        let (type_hint, mut immediate_reference) =
+            process_type(&mut as_ty, &ignore, &mut lifetimes);
+
+        // Borrowed fields which capture lifetimes need `#[serde(borrow)]` to
+        // be deserialized by borrowing, unless it has already been added.
+        if let (Some(span), false) = (serde, lifetimes.is_empty()) {
+            if !has_serde_borrow(&b_field.attrs) {
+                b_field
+                    .attrs
+                    .push(syn::parse_quote_spanned!(span => #[serde(borrow)]));
+            }
+        }
+
+        // Substitute the owned type of a field matching a container-level
+        // `map_type`, unless the field specifies how it's converted itself.
+        let explicit = attr.has_conversion(&["no_copy"]);
+
+        if let (false, Some(map_type)) =
+            (explicit, map_types.iter().find(|m| m.matches(&o_field.ty)))
+        {
+            let mut from = path(map_type.span, ["core", "convert", "From", "from"]);
+            let elem = &map_type.elem;
+
+            // NB: The argument is specified so that the reference passed in
+            // is coerced, since enum bindings are references to the field.
+            from.segments[2].arguments =
+                syn::PathArguments::AngleBracketed(syn::parse_quote!(::<&#elem>));
+
+            attr.ty
+                .set_owned(Respan::new(map_type.owned.clone(), field_ty_spans));
+            attr.ty.set_kind(attr::FieldTypeKind::Default);
+            attr.to_owned = Some((map_type.span, from));
+            attr.deref_borrow = Some((map_type.span, ()));
+        }
+
+        // Own an immutable slice such as `&'a [u8]` as a `Box<[u8]>` if the
+        // container uses `boxed_slices`. Slices of references can't be
+        // borrowed back, so they are left to report an error below.
+        if let (false, Some(span)) = (explicit || attr.ty.owned.is_some(), boxed_slices) {
+            if let syn::Type::Reference(syn::TypeReference {
+                mutability: None,
+                elem,
+                ..
+            }) = &o_field.ty
+            {
+                if let syn::Type::Slice(slice) = &**elem {
+                    if slice_element_lifetime(&o_field.ty, &ignore).is_none() {
+                        let elem = &slice.elem;
+
+                        if mentions_param(elem.to_token_stream(), params) {
+                            let clone_t = &cx.core_clone_t;
+                            bounds.to_owned.push(syn::parse_quote!(#elem: #clone_t));
+                        }
+
+                        let boxed = &cx.borrowme_boxed;
+                        let owned = syn::parse_quote!(#boxed<[#elem]>);
+                        attr.ty.set_owned(Respan::new(owned, field_ty_spans));
+                        attr.ty.set_kind(attr::FieldTypeKind::Default);
+                        attr.to_owned = Some((span, cx.borrowme_boxed_to_owned.clone()));
+                        attr.deref_borrow = Some((span, ()));
+                    }
+                }
+            }
+        }
+
+        // A reference to an array such as `&'a [u8; N]` is owned as the array
+        // itself and borrowed back as a reference to it through
+        // `core::borrow`, since borrowing the array produces a new array of
+        // borrowed elements. The same goes for references to owned containers
+        // such as `&'a Vec<f32>` or `&'a String`, which would otherwise be
+        // borrowed as a `Vec<&'a f32>` or a `&'a str`. Mutable slices such as
+        // `&'a mut [u8]` are owned as a `Vec<u8>` and borrowed back mutably
+        // in the same way.
+        if !explicit && attr.ty.owned.is_none() {
+            if let syn::Type::Reference(syn::TypeReference {
+                mutability: None,
+                elem,
+                ..
+            }) = &o_field.ty
+            {
+                if matches!(&**elem, syn::Type::Array(..)) || is_owned_container(elem, params) {
+                    attr.borrow = Some((o_field.ty.span(), cx.core_borrow_t_borrow.clone()));
+                }
+            }
+
+            if let syn::Type::Reference(syn::TypeReference {
+                mutability: Some(..),
+                elem,
+                ..
+            }) = &o_field.ty
+            {
+                if let syn::Type::Slice(slice) = &**elem {
+                    if slice_element_lifetime(&o_field.ty, &ignore).is_none()
+                        && !mentions_param(slice.elem.to_token_stream(), params)
+                    {
+                        attr.borrow_mut =
+                            Some((o_field.ty.span(), cx.core_borrow_mut_t_borrow_mut.clone()));
+                    }
+                }
+            }
+        }
+
+        // Skipped fields are constructed using `Default` in both directions,
+        // and are owned with their lifetimes replaced by `'static` unless the
+        // owned type is specified.
+        if attr.skip.is_some() {
+            if let Some(ty) = attr.ty.owned() {
+                o_field.ty = ty.as_type();
+            } else {
+                o_field.ty = as_ty;
+            }
+
+            // NB: The owned type is spelled out and spanned to the field so
+            // that a missing `Default` implementation is reported there. The
+            // borrowed type can't be named since its lifetimes aren't in scope
+            // in the `Borrow` implementation.
+            let span = b_field.ty.span();
+            let default_t = path(span, ["core", "default", "Default"]);
+            let owned_ty = &o_field.ty;
+
+            let member = match &o_field.ident {
+                Some(ident) => syn::Member::Named(ident.clone()),
+                None => syn::Member::Unnamed(syn::Index::from(index)),
+            };
+
+            let owned_entry = syn::FieldValue {
+                attrs: Vec::new(),
+                member: member.clone(),
+                colon_token: Some(<Token![:]>::default()),
+                expr: syn::parse_quote_spanned!(span => <#owned_ty as #default_t>::default()),
+            };
+
+            let borrow_entry = syn::FieldValue {
+                attrs: Vec::new(),
+                member,
+                colon_token: Some(<Token![:]>::default()),
+                expr: syn::parse_quote_spanned!(span => #default_t::default()),
+            };
+
+            to_owned_entries.push(owned_entry.clone());
+            try_to_owned_entries.push(owned_entry);
+            borrow_entries.push(borrow_entry.clone());
+            mut_view_fields.push(MutViewField {
+                is_mut: false,
+                value: borrow_entry,
+            });
+            continue;
+        }
+
+        // Fields mentioning a type parameter marked with `convert` are owned
+        // as the same type, where the parameter stands in for its owned type.
+        if !explicit
+            && attr.ty.owned.is_none()
+            && mentions_param(o_field.ty.to_token_stream(), converted)
+        {
+            if let Some((span, ..)) = lifetimes.first() {
+                let mut error = syn::Error::new(
+                    *span,
+                    format_args!("{NAME}: fields capturing lifetimes can't mention type parameters marked with convert."),
+                );
+
+                error.combine(syn::Error::new(
+                    o_field.span(),
+                    "Hint: add #[owned(<type>)] to specify the owned type instead",
+                ));
+                cx.error(error);
+                continue;
+            }
+
+            attr.ty
+                .set_owned(Respan::new(o_field.ty.clone(), field_ty_spans));
+            attr.ty.set_kind(attr::FieldTypeKind::Default);
+        }
+
+        // Without heuristics, every field has to specify how it's converted
+        // instead of having it guessed from its type.
+        let type_hint = if heuristics {
+            type_hint
+        } else {
+            let explicit = attr.ty.owned.is_some()
+                || attr.cow.is_some()
+                || attr.borrow_cow.is_some()
+                || attr.collect.is_some()
+                || attr.owned_collect.is_some()
+                || attr.or_default.is_some()
+                || attr.clone_only.is_some()
+                || match attr.ty.kind() {
+                    attr::FieldTypeKind::Copy(true) => true,
+                    attr::FieldTypeKind::Std => {
+                        immediate_reference.is_some() || lifetimes.is_empty()
+                    }
+                    _ => false,
+                };
+
+            if !explicit {
+                let mut error = syn::Error::new(
+                    o_field.ty.span(),
+                    format_args!("{NAME}: no_heuristics requires every field to specify how it is converted."),
+                );
+
+                error.combine(syn::Error::new(
+                    o_field.span(),
+                    "Hint: add #[copy], #[owned(<type>)] or #[borrowme(std)] to this field",
+                ));
+                cx.error(error);
+                continue;
+            }
+
+            TypeHint::None
+        };
+
+        // Markers are constructed in both variants with their lifetimes
+        // rewritten, unless the owned type is explicitly specified.
+        let marker = match type_hint {
+            TypeHint::Marker if attr.ty.owned.is_none() => {
+                let mut ty = as_ty.clone();
+                process_marker(cx, &mut ty);
+                Some(ty)
+            }
+            _ => None,
+        };
+
+        // Trait objects such as `Box<dyn Fn(&'a str) -> bool + 'a>` capture
+        // lifetimes in a way that can't be rewritten, so the owned type has to
+        // be specified explicitly.
+        if attr.ty.owned.is_none() {
+            if let Some(span) = trait_object_lifetime(&o_field.ty, &ignore) {
+                let mut error = syn::Error::new(
+                    span,
+                    format_args!("{NAME}: trait objects capturing lifetimes are not supported."),
+                );
+
+                error.combine(syn::Error::new(
+                    o_field.span(),
+                    "Hint: add #[borrowme(owned = <type>, to_owned_with = <path>, borrow_with = <path>)] to specify how this field is converted",
+                ));
+                cx.error(error);
+                continue;
+            }
+
+            // Function pointers such as `fn(&'a str)` can't be owned, since
+            // their arguments can't be widened to `'static` and then borrowed
+            // back.
+            if marker.is_none() {
+                if let Some(span) = fn_pointer_lifetime(&o_field.ty, &ignore) {
+                    let mut error = syn::Error::new(
+                        span,
+                        format_args!(
+                            "{NAME}: function pointers mentioning lifetimes are not supported."
+                        ),
+                    );
+
+                    error.combine(syn::Error::new(
+                        o_field.span(),
+                        "Hint: make the function pointer generic over the lifetime such as `for<'x> fn(&'x str)`, or add #[borrowme(owned = <type>, to_owned_with = <path>, borrow_with = <path>)] to specify how this field is converted",
+                    ));
+                    cx.error(error);
+                    continue;
+                }
+            }
+
+            // A slice of references such as `&'a [&'a str]` would be owned as
+            // something like `Vec<String>`, which can't be borrowed back into a
+            // slice without allocating.
+            if !matches!(attr.ty.kind(), attr::FieldTypeKind::Copy(true)) {
+                if let Some(span) = slice_element_lifetime(&o_field.ty, &ignore) {
+                    let mut error = syn::Error::new(
+                        span,
+                        format_args!("{NAME}: slices of references can't be borrowed back from an owned value."),
+                    );
+
+                    error.combine(syn::Error::new(
+                        o_field.span(),
+                        "Hint: use a `Vec` instead such as `Vec<&'a str>`, or add #[borrowme(owned = <type>, to_owned_with = <path>, borrow_with = <path>)] to specify how this field is converted",
+                    ));
+                    cx.error(error);
+                    continue;
+                }
+
+                // A reference to a type with lifetimes such as `&'a Word<'a>`
+                // would be owned as an `OwnedWord`, which can only be borrowed
+                // back as a `Word<'_>` by value and not as a reference.
+                if let Some(span) = reference_element_lifetime(&o_field.ty, &ignore) {
+                    let mut error = syn::Error::new(
+                        span,
+                        format_args!("{NAME}: references to types with lifetimes can't be borrowed back from an owned value."),
+                    );
+
+                    error.combine(syn::Error::new(
+                        o_field.span(),
+                        "Hint: store the value instead such as `Word<'a>` rather than `&'a Word<'a>`, or add #[borrowme(owned = <type>, to_owned_with = <path>, borrow_with = <path>)] to specify how this field is converted",
+                    ));
+                    cx.error(error);
+                    continue;
+                }
+            }
+        }
+
+        // Own a reference such as `&'a str` as a `Cow<'static, str>`, which is
+        // the same as specifying the owned type and conversion functions.
+        if let Some((span, _)) = attr.cow {
+            let elem = match &o_field.ty {
+                syn::Type::Reference(ty) if ty.mutability.is_none() => &ty.elem,
+                ty => {
+                    cx.span_error(
+                        ty.span(),
+                        format_args!(
+                            "{NAME}: cow is only supported on immutable references such as `&'a str`."
+                        ),
+                    );
+                    continue;
+                }
+            };
+
+            let cow = &cx.borrowme_cow;
+            let owned = syn::parse_quote!(#cow<'static, #elem>);
+            attr.ty.set_owned(Respan::new(owned, field_ty_spans));
+            attr.ty.set_kind(attr::FieldTypeKind::Default);
+            attr.to_owned = Some((span, with_type_argument(&cx.borrowme_cow_to_owned, elem)));
+            attr.borrow = Some((span, with_type_argument(&cx.borrowme_cow_borrow, elem)));
+        }
+
+        // Borrow a reference such as `&'a str` as a `Cow<'a, str>`, which is
+        // owned as a `String`. This changes the type of the borrowed field.
+        if let Some((span, _)) = attr.borrow_cow {
+            let (lifetime, elem) = match &b_field.ty {
+                syn::Type::Reference(ty) if ty.mutability.is_none() => {
+                    (ty.lifetime.clone(), (*ty.elem).clone())
+                }
+                ty => {
+                    cx.span_error(
+                        ty.span(),
+                        format_args!(
+                            "{NAME}: borrow_cow is only supported on immutable references such as `&'a str`."
+                        ),
+                    );
+                    continue;
+                }
+            };
+
+            let cow = &cx.borrowme_cow;
+            let owned = &cx.borrowme_cow_owned;
+            let lifetime = lifetime.iter();
+            b_field.ty = syn::parse_quote!(#cow<#(#lifetime,)* #elem>);
+            // NB: The borrowed field is no longer a reference.
+            immediate_reference = None;
+
+            let owned = syn::parse_quote!(#owned<#elem>);
+            attr.ty.set_owned(Respan::new(owned, field_ty_spans));
+            attr.ty.set_kind(attr::FieldTypeKind::Default);
+            attr.to_owned = Some((
+                span,
+                with_type_argument(&cx.borrowme_cow_clone_owned, &elem),
+            ));
+            attr.borrow = Some((span, with_type_argument(&cx.borrowme_cow_borrowed, &elem)));
+        }
+
+        // Convert a collection element-wise through `Collect`, which owns a
+        // field such as `Stack<&'a str>` as a `Stack<String>`.
+        if let Some((span, _)) = attr.collect {
+            let collect_t = &cx.borrowme_collect_t;
+            let to_owned_t = &cx.borrowme_to_owned_t;
+            let owned = syn::parse_quote! {
+                <#as_ty as #collect_t>::Output<<<#as_ty as #collect_t>::Item as #to_owned_t>::Owned>
+            };
+            attr.ty.set_owned(Respan::new(owned, field_ty_spans));
+            attr.ty.set_kind(attr::FieldTypeKind::Default);
+            attr.to_owned = Some((span, cx.borrowme_collect_to_owned.clone()));
+            attr.borrow = Some((span, cx.borrowme_collect_borrow.clone()));
+        }
+
+        // Own a collection as the specified type by collecting its converted
+        // elements, such as a `Vec<Word<'a>>` as a `SmallVec<[OwnedWord; 4]>`.
+        // Slices such as `&'a [u32]` are borrowed back by dereferencing the
+        // owned collection instead, since they can't be collected into.
+        if let Some((span, owned)) = attr.owned_collect.take() {
+            attr.ty.set_owned(owned);
+            attr.ty.set_kind(attr::FieldTypeKind::Default);
+            attr.to_owned = Some((span, cx.borrowme_collect_to_owned_from_iter.clone()));
+
+            match &o_field.ty {
+                syn::Type::Reference(syn::TypeReference {
+                    mutability: None,
+                    elem,
+                    ..
+                }) if matches!(&**elem, syn::Type::Slice(..)) => {
+                    attr.deref_borrow = Some((span, ()));
+                }
+                syn::Type::Reference(ty) => {
+                    let mut error = syn::Error::new(
+                        ty.span(),
+                        format_args!("{NAME}: owned_collect requires a collection or a slice such as `&'a [u32]`."),
+                    );
+
+                    error.combine(syn::Error::new(
+                        o_field.span(),
+                        "Hint: use a collection instead such as `Vec<Word<'a>>`",
+                    ));
+                    cx.error(error);
+                    continue;
+                }
+                _ => {
+                    attr.borrow = Some((span, cx.borrowme_collect_borrow_from_iter.clone()));
+                }
+            }
+        }
+
+        // Own an option such as `Option<&'a str>` as a `String`, which is the
+        // default value if it's missing. It's borrowed back as `None` if it's
+        // equal to the default value.
+        if let Some((span, _)) = attr.or_default {
+            let Some(elem) = option_argument(&as_ty) else {
+                cx.span_error(
+                    o_field.ty.span(),
+                    format_args!("{NAME}: or_default is only supported on options such as `Option<&'a str>`."),
+                );
+                continue;
+            };
+
+            if attr.ty.owned.is_none() {
+                let to_owned_t = &cx.borrowme_to_owned_t;
+                let owned = syn::parse_quote!(<#elem as #to_owned_t>::Owned);
+                attr.ty.set_owned(Respan::new(owned, field_ty_spans));
+            }
+
+            attr.ty.set_kind(attr::FieldTypeKind::Default);
+            attr.to_owned = Some((span, cx.borrowme_or_default_to_owned.clone()));
+            attr.borrow = Some((span, cx.borrowme_or_default_borrow.clone()));
+        }
+
+        // Own the field as its own type by cloning it, which requires that it
+        // doesn't capture any lifetimes. It's still borrowed through `Borrow`,
+        // unlike fields which are cloned in both directions.
+        if let Some((span, _)) = attr.clone_only {
+            if let Some((lt_span, ..)) = lifetimes.first() {
+                let mut error = syn::Error::new(
+                    *lt_span,
+                    format_args!("{NAME}: clone_only requires a field type without lifetimes, since it's used as the owned type."),
+                );
+
+                error.combine(syn::Error::new(
+                    o_field.span(),
+                    "Hint: add #[owned(<type>)] to specify the owned type instead",
+                ));
+                cx.error(error);
+                continue;
+            }
+
+            if mentions_param(o_field.ty.to_token_stream(), params) {
+                let ty = &o_field.ty;
+                let clone_t = &cx.core_clone_t;
+                bounds.to_owned.push(syn::parse_quote!(#ty: #clone_t));
+            }
+
+            attr.ty
+                .set_owned(Respan::new(o_field.ty.clone(), field_ty_spans));
+            attr.ty.set_kind(attr::FieldTypeKind::Default);
+            attr.to_owned = Some((span, cx.clone_t_clone.clone()));
+        }
 
         let needs_mut = lifetimes
             .iter()
@@ -477,104 +2034,603 @@ fn process_fields(
                 // `ToOwned` implementation to figure out which type to use.
                 match type_hint {
                     TypeHint::None
-                        if attr.ty.owned.is_none() && !is_std_ref && !lifetimes.is_empty() =>
+                        if heuristics
+                            && attr.ty.owned.is_none()
+                            && !is_std_ref
+                            && !lifetimes.is_empty() =>
                     {
                         let mut path = cx.borrowme_to_owned_t.clone();
+                        let position = path.segments.len();
 
                         path.segments.push(syn::PathSegment::from(syn::Ident::new(
                             "Owned",
                             Span::call_site(),
                         )));
 
+                        // A reference to a type parameter such as `&'a T` is
+                        // owned as `T::Owned` and borrowed back through
+                        // `core::borrow`, since `&'static T` would require
+                        // that `T: 'static`.
+                        let as_ty = match type_param(immediate_reference.as_ref(), params) {
+                            Some(param) => {
+                                generic_reference_bounds(cx, param, needs_mut, &mut attr, bounds);
+                                immediate_reference.clone().unwrap_or(as_ty)
+                            }
+                            None => as_ty,
+                        };
+
                         let ty = syn::Type::Path(syn::TypePath {
                             qself: Some(syn::QSelf {
                                 lt_token: <Token![<]>::default(),
                                 ty: Box::new(as_ty),
-                                position: 2,
+                                position,
                                 as_token: Some(<Token![as]>::default()),
                                 gt_token: <Token![>]>::default(),
                             }),
                             path,
                         });
 
-                        attr.ty.set_owned(Respan::new(ty, field_ty_spans));
-                    }
-                    TypeHint::Copy => {
-                        if !matches!(attr.ty.kind(), attr::FieldTypeKind::Copy(false)) {
-                            attr.ty.set_kind(attr::FieldTypeKind::Copy(true));
-                        }
-                    }
-                    _ => {}
-                }
-            }
-        };
+                        attr.ty.set_owned(Respan::new(ty, field_ty_spans));
+                    }
+                    TypeHint::Copy
+                        if !matches!(attr.ty.kind(), attr::FieldTypeKind::Copy(false))
+                            && attr.clone_only.is_none()
+                            && attr.or_default.is_none() =>
+                    {
+                        attr.ty.set_kind(attr::FieldTypeKind::Copy(true));
+                    }
+                    _ => {}
+                }
+            }
+        };
+
+        let (to_owned, borrow) = if let Some(marker) = marker {
+            o_field.ty = marker;
+            let marker = &cx.core_marker_phantom_data;
+            (Call::Marker(marker), Call::Marker(marker))
+        } else {
+            match (attr.ty.kind(), &immediate_reference, attr.ty.owned()) {
+                (attr::FieldTypeKind::Copy(true), _, _) => (Call::Ref, Call::Ref),
+                (attr::FieldTypeKind::Std, _, Some(ty)) => {
+                    o_field.ty = ty.as_type();
+                    (Call::Path(&cx.clone_t_clone), Call::Ref)
+                }
+                (attr::FieldTypeKind::Std, Some(ty), None) => {
+                    o_field.ty = ty.clone();
+                    (Call::Path(&cx.clone_t_clone), Call::Ref)
+                }
+                (_, _, Some(ty)) => {
+                    o_field.ty = ty.as_type();
+
+                    let borrow = if needs_mut {
+                        attr.borrow_mut(cx)
+                    } else {
+                        attr.borrow(cx)
+                    };
+
+                    (Call::with(attr.to_owned(cx)), Call::with(borrow))
+                }
+                _ => {
+                    // Fields which mention a type parameter such as `T` or
+                    // `Option<T>` are cloned in both directions, so they have to
+                    // implement `Clone`.
+                    if mentions_param(o_field.ty.to_token_stream(), params) {
+                        let ty = &o_field.ty;
+                        let clone_t = &cx.core_clone_t;
+                        let bound: syn::WherePredicate = syn::parse_quote!(#ty: #clone_t);
+                        bounds.to_owned.push(bound.clone());
+                        bounds.borrow.push(bound);
+                    }
+
+                    let clone = &cx.clone_t_clone;
+                    (Call::Path(clone), Call::Path(clone))
+                }
+            }
+        };
+
+        let borrow = match borrow {
+            Call::Marker(..) => borrow,
+            _ if attr.deref_borrow.is_some() => Call::Deref,
+            _ => borrow,
+        };
+
+        let binding = match &o_field.ident {
+            Some(ident) => Binding::Named(ident.clone()),
+            None => Binding::Unnamed(syn::Index::from(index)),
+        };
+
+        let member = binding.as_member();
+
+        let is_copy = matches!(attr.ty.kind(), attr::FieldTypeKind::Copy(true));
+
+        let bound = BoundAccess {
+            use_reference: !is_copy && immediate_reference.is_none(),
+            is_mut: false,
+            access,
+            binding: &binding,
+        };
+
+        let to_owned_entry = syn::FieldValue {
+            attrs: Vec::new(),
+            member: member.clone(),
+            colon_token: Some(<Token![:]>::default()),
+            expr: to_owned.as_expr(&bound),
+        };
+
+        // Fields without a fallible conversion are converted the same way as
+        // in `ToOwned`.
+        let try_to_owned_entry = match &attr.try_to_owned {
+            Some((span, path)) => {
+                *parent_needs_try = true;
+
+                syn::FieldValue {
+                    attrs: Vec::new(),
+                    member: member.clone(),
+                    colon_token: Some(<Token![:]>::default()),
+                    expr: syn::Expr::Try(syn::ExprTry {
+                        attrs: Vec::new(),
+                        expr: Box::new(Call::With(*span, path).as_expr(&bound)),
+                        question_token: <Token![?]>::default(),
+                    }),
+                }
+            }
+            None => to_owned_entry.clone(),
+        };
+
+        to_owned_entries.push(to_owned_entry);
+        try_to_owned_entries.push(try_to_owned_entry);
+
+        let bound = BoundAccess {
+            use_reference: !is_copy || matches!(borrow, Call::Deref),
+            is_mut: needs_mut,
+            access,
+            binding: &binding,
+        };
+
+        let borrow_entry = syn::FieldValue {
+            attrs: Vec::new(),
+            member: member.clone(),
+            colon_token: Some(<Token![:]>::default()),
+            expr: borrow.as_expr(&bound),
+        };
+
+        // In a mutable view, references are borrowed mutably while every other
+        // field is borrowed the same way as in the borrowed variant.
+        let is_mut_view_ref = immediate_reference.is_some()
+            && !is_copy
+            && !needs_mut
+            && !matches!(borrow, Call::Marker(..))
+            && attr.cow.is_none()
+            && type_param(immediate_reference.as_ref(), params).is_none();
+
+        let mut_view_field = if is_mut_view_ref {
+            let bound = BoundAccess {
+                use_reference: true,
+                is_mut: true,
+                access,
+                binding: &binding,
+            };
+
+            let borrow_mut = match borrow {
+                Call::Deref => Call::Deref,
+                _ => Call::with(attr.borrow_mut(cx)),
+            };
+
+            MutViewField {
+                is_mut: true,
+                value: syn::FieldValue {
+                    attrs: Vec::new(),
+                    member,
+                    colon_token: Some(<Token![:]>::default()),
+                    expr: borrow_mut.as_expr(&bound),
+                },
+            }
+        } else {
+            MutViewField {
+                is_mut: false,
+                value: borrow_entry.clone(),
+            }
+        };
+
+        mut_view_fields.push(mut_view_field);
+        borrow_entries.push(borrow_entry);
+    }
+
+    Ok(())
+}
+
+/// Attributes put on every generated implementation.
+///
+/// NB: Deprecation warnings are suppressed since a deprecated container or
+/// field is still used by the implementations generated for it.
+pub(crate) fn impl_attributes() -> TokenStream {
+    quote! {
+        #[automatically_derived]
+        #[allow(deprecated)]
+    }
+}
+
+/// Generate a minimal field-listing `Debug` implementation for the given item.
+///
+/// Fields whose types mention a type parameter are bounded by `Debug`.
+fn debug_impl(cx: &Ctxt, item: &syn::Item, params: &HashSet<syn::Ident>) -> TokenStream {
+    let debug_t = &cx.core_fmt_debug_t;
+
+    let (ident, generics, variants) = match item {
+        syn::Item::Struct(st) => (&st.ident, &st.generics, vec![(None, &st.fields)]),
+        syn::Item::Enum(en) => (
+            &en.ident,
+            &en.generics,
+            en.variants
+                .iter()
+                .map(|v| (Some(&v.ident), &v.fields))
+                .collect(),
+        ),
+        _ => return TokenStream::new(),
+    };
+
+    let mut generics = generics.clone();
+    let mut arms = Vec::new();
+
+    for (variant, fields) in variants {
+        let bindings = fields
+            .iter()
+            .enumerate()
+            .map(|(n, f)| match &f.ident {
+                Some(ident) => Binding::Named(ident.clone()),
+                None => Binding::Unnamed(syn::Index::from(n)),
+            })
+            .collect::<Vec<_>>();
+
+        for field in fields {
+            if mentions_param(field.ty.to_token_stream(), params) {
+                let ty = &field.ty;
+
+                generics
+                    .make_where_clause()
+                    .predicates
+                    .push(syn::parse_quote!(#ty: #debug_t));
+            }
+        }
+
+        let (path, name) = match variant {
+            Some(variant) => (quote!(#ident::#variant), variant.to_string()),
+            None => (quote!(#ident), ident.to_string()),
+        };
+
+        let patterns = bindings.iter().map(|b| b.as_field_value());
+        let variables = bindings.iter().map(|b| b.as_variable());
+
+        let body = match fields {
+            syn::Fields::Named(..) => {
+                let names = bindings.iter().map(|b| b.as_variable().to_string());
+                quote!(__f.debug_struct(#name) #(.field(#names, #variables))* .finish())
+            }
+            syn::Fields::Unnamed(..) => {
+                quote!(__f.debug_tuple(#name) #(.field(#variables))* .finish())
+            }
+            syn::Fields::Unit => quote!(__f.write_str(#name)),
+        };
+
+        arms.push(quote!(#path { #(#patterns,)* } => #body));
+    }
+
+    let formatter = &cx.core_fmt_formatter;
+    let result = &cx.core_fmt_result;
+    let (impl_generics, type_generics, where_generics) = generics.split_for_impl();
+    let impl_attributes = impl_attributes();
+
+    quote! {
+        #impl_attributes
+        impl #impl_generics #debug_t for #ident #type_generics #where_generics {
+            fn fmt(&self, __f: &mut #formatter<'_>) -> #result {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+    }
+}
+
+/// Generate `Hash`, `PartialEq` and `Eq` implementations for the owned
+/// variant. If any field is marked with `hash` only marked fields are used,
+/// otherwise every field which isn't marked with `no_hash` is.
+fn hash_impl(
+    cx: &Ctxt,
+    item: &syn::Item,
+    params: &HashSet<syn::Ident>,
+    hash_fields: &[Vec<Option<(Span, bool)>>],
+) -> TokenStream {
+    let hash_t = &cx.core_hash_hash_t;
+    let hasher_t = &cx.core_hash_hasher_t;
+    let partial_eq_t = &cx.core_cmp_partial_eq_t;
+    let eq_t = &cx.core_cmp_eq_t;
+
+    let (ident, generics, variants) = match item {
+        syn::Item::Struct(st) => (&st.ident, &st.generics, vec![(None, &st.fields)]),
+        syn::Item::Enum(en) => (
+            &en.ident,
+            &en.generics,
+            en.variants
+                .iter()
+                .map(|v| (Some(&v.ident), &v.fields))
+                .collect(),
+        ),
+        _ => return TokenStream::new(),
+    };
+
+    let only_marked = hash_fields
+        .iter()
+        .flatten()
+        .any(|hash| matches!(hash, Some((_, true))));
+
+    let mut predicates = Vec::new();
+    let mut hash_arms = Vec::new();
+    let mut eq_arms = Vec::new();
+
+    for ((variant, fields), hashed) in variants.into_iter().zip(hash_fields) {
+        let mut members = Vec::new();
+        let mut a = Vec::new();
+        let mut b = Vec::new();
+
+        for (n, (field, hash)) in fields.iter().zip(hashed).enumerate() {
+            let included = match hash {
+                Some((_, hash)) => *hash,
+                None => !only_marked,
+            };
+
+            if !included {
+                continue;
+            }
+
+            if mentions_param(field.ty.to_token_stream(), params) {
+                predicates.push(field.ty.clone());
+            }
+
+            members.push(match &field.ident {
+                Some(ident) => syn::Member::Named(ident.clone()),
+                None => syn::Member::Unnamed(syn::Index::from(n)),
+            });
+
+            a.push(syn::Ident::new(&format!("__a{n}"), Span::call_site()));
+            b.push(syn::Ident::new(&format!("__b{n}"), Span::call_site()));
+        }
+
+        let path = match variant {
+            Some(variant) => quote!(#ident::#variant),
+            None => quote!(#ident),
+        };
+
+        hash_arms.push(quote! {
+            #path { #(#members: #a,)* .. } => {
+                #(#hash_t::hash(#a, __state);)*
+            }
+        });
+
+        eq_arms.push(quote! {
+            (#path { #(#members: #a,)* .. }, #path { #(#members: #b,)* .. }) => {
+                true #(&& #partial_eq_t::eq(#a, #b))*
+            }
+        });
+    }
+
+    // Values of different variants never compare equal, but the fallback arm
+    // is unreachable for containers with only a single variant.
+    let (discriminant, fallback) = match item {
+        syn::Item::Enum(..) => {
+            let discriminant = &cx.core_mem_discriminant;
+
+            (
+                Some(quote!(#hash_t::hash(&#discriminant(self), __state);)),
+                Some(quote!(#[allow(unreachable_patterns)] _ => false,)),
+            )
+        }
+        _ => (None, None),
+    };
+
+    let mut hash_generics = generics.clone();
+    let mut eq_generics = generics.clone();
+
+    for ty in &predicates {
+        hash_generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(#ty: #hash_t));
+
+        eq_generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(#ty: #eq_t));
+    }
+
+    let (impl_generics, type_generics, where_generics) = hash_generics.split_for_impl();
+    let impl_attributes = impl_attributes();
+
+    let hash = quote! {
+        #impl_attributes
+        impl #impl_generics #hash_t for #ident #type_generics #where_generics {
+            fn hash<__H: #hasher_t>(&self, __state: &mut __H) {
+                #discriminant
+
+                match self {
+                    #(#hash_arms)*
+                }
+            }
+        }
+    };
+
+    let (impl_generics, type_generics, where_generics) = eq_generics.split_for_impl();
+
+    quote! {
+        #hash
+
+        #impl_attributes
+        impl #impl_generics #partial_eq_t for #ident #type_generics #where_generics {
+            fn eq(&self, __other: &Self) -> bool {
+                match (self, __other) {
+                    #(#eq_arms)*
+                    #fallback
+                }
+            }
+        }
+
+        #impl_attributes
+        impl #impl_generics #eq_t for #ident #type_generics #where_generics {}
+    }
+}
+
+/// Test if the type is an owned container such as `Vec<f32>` or `String` which
+/// is owned as itself, since it doesn't capture any lifetimes or type
+/// parameters.
+fn is_owned_container(ty: &syn::Type, params: &HashSet<syn::Ident>) -> bool {
+    let syn::Type::Path(ty) = ty else {
+        return false;
+    };
+
+    let Some(last) = ty.path.segments.last() else {
+        return false;
+    };
+
+    if last.ident != "Vec" && last.ident != "String" {
+        return false;
+    }
+
+    let mut lifetimes = Vec::new();
+    process_type(
+        &mut syn::Type::Path(ty.clone()),
+        &HashSet::new(),
+        &mut lifetimes,
+    );
+    lifetimes.is_empty() && !mentions_param(ty.to_token_stream(), params)
+}
 
-        let (to_owned, borrow) = match (attr.ty.kind(), &immediate_reference, attr.ty.owned()) {
-            (attr::FieldTypeKind::Copy(true), _, _) => (Call::Ref, Call::Ref),
-            (attr::FieldTypeKind::Std, _, Some(ty)) => {
-                o_field.ty = ty.as_type();
-                (Call::Path(&cx.clone_t_clone), Call::Ref)
-            }
-            (attr::FieldTypeKind::Std, Some(ty), None) => {
-                o_field.ty = ty.clone();
-                (Call::Path(&cx.clone_t_clone), Call::Ref)
-            }
-            (_, _, Some(ty)) => {
-                o_field.ty = ty.as_type();
+/// Test if the given tokens mention any of the given type parameters.
+fn mentions_param(tokens: TokenStream, params: &HashSet<syn::Ident>) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        proc_macro2::TokenTree::Ident(ident) => params.contains(&ident),
+        proc_macro2::TokenTree::Group(group) => mentions_param(group.stream(), params),
+        _ => false,
+    })
+}
 
-                let borrow = if needs_mut {
-                    attr.borrow_mut(cx)
-                } else {
-                    attr.borrow(cx)
-                };
+/// Test if the given tokens mention any of the given lifetimes, such as `'a` in
+/// `Trait<'a>`.
+fn mentions_lifetime(tokens: TokenStream, lifetimes: &HashSet<syn::Ident>) -> bool {
+    let mut is_lifetime = false;
 
-                (Call::Path(attr.to_owned(cx)), Call::Path(borrow))
-            }
-            _ => {
-                let clone = &cx.clone_t_clone;
-                (Call::Path(clone), Call::Path(clone))
-            }
+    tokens.into_iter().any(|tt| {
+        let found = match &tt {
+            proc_macro2::TokenTree::Ident(ident) => is_lifetime && lifetimes.contains(ident),
+            proc_macro2::TokenTree::Group(group) => mentions_lifetime(group.stream(), lifetimes),
+            _ => false,
         };
 
-        let binding = match &o_field.ident {
-            Some(ident) => Binding::Named(ident.clone()),
-            None => Binding::Unnamed(syn::Index::from(index)),
-        };
+        is_lifetime = matches!(&tt, proc_macro2::TokenTree::Punct(p) if p.as_char() == '\'');
+        found
+    })
+}
 
-        let member = binding.as_member();
+/// Get the error type used by the generated `TryToOwned` implementation, if one
+/// should be generated.
+fn try_error(
+    cx: &Ctxt,
+    attr: &attr::Container,
+    needs_try: bool,
+    span: Span,
+) -> Result<Option<syn::Type>, ()> {
+    match &attr.error {
+        Some((_, error)) => Ok(Some(error.clone())),
+        None if needs_try => {
+            cx.span_error(
+                span,
+                format_args!(
+                    "{NAME}: try_to_owned_with requires an error type, add #[borrowme(error = <type>)] to the container."
+                ),
+            );
+            Err(())
+        }
+        None => Ok(None),
+    }
+}
 
-        let is_copy = matches!(attr.ty.kind(), attr::FieldTypeKind::Copy(true));
+/// Test if the given type is dynamically sized, such as `[T]`, `str` or
+/// `dyn Trait`.
+fn is_unsized(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Group(ty) => is_unsized(&ty.elem),
+        syn::Type::Paren(ty) => is_unsized(&ty.elem),
+        syn::Type::Slice(..) | syn::Type::TraitObject(..) => true,
+        syn::Type::Path(ty) => ty.qself.is_none() && ty.path.is_ident("str"),
+        _ => false,
+    }
+}
 
-        let bound = BoundAccess {
-            use_reference: !is_copy && immediate_reference.is_none(),
-            is_mut: false,
-            access,
-            binding: &binding,
-        };
+/// Add an explicit type argument to the last segment of a function path, such
+/// as `to_owned::<str>`.
+fn with_type_argument(path: &syn::Path, ty: &syn::Type) -> syn::Path {
+    let mut path = path.clone();
 
-        to_owned_entries.push(syn::FieldValue {
-            attrs: Vec::new(),
-            member: member.clone(),
-            colon_token: Some(<Token![:]>::default()),
-            expr: to_owned.as_expr(&bound),
-        });
+    if let Some(segment) = path.segments.last_mut() {
+        segment.arguments = syn::PathArguments::AngleBracketed(syn::parse_quote!(::<#ty>));
+    }
 
-        let bound = BoundAccess {
-            use_reference: !is_copy,
-            is_mut: needs_mut,
-            access,
-            binding: &binding,
-        };
+    path
+}
 
-        borrow_entries.push(syn::FieldValue {
-            attrs: Vec::new(),
-            member,
-            colon_token: Some(<Token![:]>::default()),
-            expr: borrow.as_expr(&bound),
-        });
+/// Collect the identifiers of all type parameters.
+fn type_params(generics: &syn::Generics) -> HashSet<syn::Ident> {
+    generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect()
+}
+
+/// Test if the given type is a bare type parameter.
+fn type_param<'a>(
+    ty: Option<&'a syn::Type>,
+    params: &HashSet<syn::Ident>,
+) -> Option<&'a syn::Ident> {
+    let syn::Type::Path(ty) = ty? else {
+        return None;
+    };
+
+    if ty.qself.is_some() {
+        return None;
     }
 
-    Ok(())
+    let ident = ty.path.get_ident()?;
+    params.contains(ident).then_some(ident)
+}
+
+/// Add the bounds needed to convert a reference to the type parameter `param`,
+/// and default to borrowing it through `core::borrow`.
+fn generic_reference_bounds(
+    cx: &Ctxt,
+    param: &syn::Ident,
+    needs_mut: bool,
+    attr: &mut attr::Field,
+    bounds: &mut Bounds,
+) {
+    let to_owned_t = &cx.borrowme_to_owned_t;
+
+    bounds.owned.push(syn::parse_quote!(#param: #to_owned_t));
+    bounds.generic_references.insert(param.clone());
+
+    if needs_mut {
+        if attr.borrow_mut.is_none() && attr.with_borrow_mut.is_none() {
+            let borrow_mut_t = &cx.core_borrow_mut_t;
+            bounds
+                .borrow
+                .push(syn::parse_quote!(<#param as #to_owned_t>::Owned: #borrow_mut_t<#param>));
+            attr.borrow_mut = Some((Span::call_site(), cx.core_borrow_mut_t_borrow_mut.clone()));
+        }
+    } else if attr.borrow.is_none() {
+        let borrow_t = &cx.core_borrow_t;
+        bounds
+            .borrow
+            .push(syn::parse_quote!(<#param as #to_owned_t>::Owned: #borrow_t<#param>));
+        attr.borrow = Some((Span::call_site(), cx.core_borrow_t_borrow.clone()));
+    }
 }
 
 /// Calculate the field type span to use for diagnostics such as when there is a
@@ -599,22 +2655,108 @@ fn apply_attributes(
     owned_attrs: &mut Vec<syn::Attribute>,
     borrowed_attrs: &mut Vec<syn::Attribute>,
 ) {
-    for meta in &attributes.own {
-        owned_attrs.push(syn::Attribute {
-            pound_token: <Token![#]>::default(),
-            style: syn::AttrStyle::Outer,
-            bracket_token: token::Bracket::default(),
-            meta: meta.clone(),
-        });
+    push_attributes(&attributes.own, owned_attrs);
+    push_attributes(&attributes.borrow, borrowed_attrs);
+}
+
+/// Push the given metas as outer attributes, merging every `derive(..)` into
+/// the first one since some derives dislike being split up. Derives listed in
+/// `no_derive(..)` are removed from every derive of the variant.
+fn push_attributes(metas: &[syn::Meta], attrs: &mut Vec<syn::Attribute>) {
+    let mut derive = None::<(usize, syn::MetaList, Punctuated<syn::Path, Token![,]>)>;
+    let mut excluded = Vec::new();
+
+    for meta in metas {
+        if let Some((list, paths)) = list_paths(meta, "derive") {
+            match &mut derive {
+                Some((_, _, existing)) => existing.extend(paths),
+                None => derive = Some((attrs.len(), list.clone(), paths)),
+            }
+
+            continue;
+        }
+
+        if let Some((_, paths)) = list_paths(meta, "no_derive") {
+            excluded.extend(paths.iter().map(|p| p.to_token_stream().to_string()));
+            continue;
+        }
+
+        attrs.push(outer_attribute(meta.clone()));
     }
 
-    for meta in &attributes.borrow {
-        borrowed_attrs.push(syn::Attribute {
-            pound_token: <Token![#]>::default(),
-            style: syn::AttrStyle::Outer,
-            bracket_token: token::Bracket::default(),
-            meta: meta.clone(),
-        });
+    if let Some((index, mut list, paths)) = derive {
+        list.tokens = paths.into_token_stream();
+        attrs.insert(index, outer_attribute(syn::Meta::List(list)));
+    }
+
+    if excluded.is_empty() {
+        return;
+    }
+
+    attrs.retain_mut(|attr| {
+        let Some((list, paths)) = list_paths(&attr.meta, "derive") else {
+            return true;
+        };
+
+        let mut list = list.clone();
+
+        let paths = paths
+            .into_iter()
+            .filter(|p| !excluded.contains(&p.to_token_stream().to_string()))
+            .collect::<Punctuated<syn::Path, Token![,]>>();
+
+        if paths.is_empty() {
+            return false;
+        }
+
+        list.tokens = paths.into_token_stream();
+        attr.meta = syn::Meta::List(list);
+        true
+    });
+}
+
+/// Test if the attributes contain a `#[serde(borrow)]` attribute, possibly
+/// alongside other serde options such as `#[serde(flatten, borrow)]`.
+fn has_serde_borrow(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        let syn::Meta::List(list) = &attr.meta else {
+            return false;
+        };
+
+        list.path.is_ident("serde")
+            && list
+                .tokens
+                .clone()
+                .into_iter()
+                .any(|tt| matches!(tt, proc_macro2::TokenTree::Ident(ident) if ident == "borrow"))
+    })
+}
+
+/// Parse the paths of a list meta with the given name, such as `derive(..)`.
+fn list_paths<'a>(
+    meta: &'a syn::Meta,
+    name: &str,
+) -> Option<(&'a syn::MetaList, Punctuated<syn::Path, Token![,]>)> {
+    let syn::Meta::List(list) = meta else {
+        return None;
+    };
+
+    if !list.path.is_ident(name) {
+        return None;
+    }
+
+    let paths = list
+        .parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+        .ok()?;
+    Some((list, paths))
+}
+
+fn outer_attribute(meta: syn::Meta) -> syn::Attribute {
+    syn::Attribute {
+        pound_token: <Token![#]>::default(),
+        style: syn::AttrStyle::Outer,
+        bracket_token: token::Bracket::default(),
+        meta,
     }
 }
 
@@ -624,6 +2766,8 @@ enum TypeHint {
     None,
     /// Type looks like it could be copy, such as `'static T`.
     Copy,
+    /// Type is a zero-sized marker such as `PhantomData<T>`.
+    Marker,
 }
 
 impl TypeHint {
@@ -720,11 +2864,54 @@ fn process_type(
                         return (TypeHint::Copy, None)
                     }
                     "f32" | "f64" => return (TypeHint::Copy, None),
-                    "bool" => return (TypeHint::Copy, None),
+                    "bool" | "char" => return (TypeHint::Copy, None),
                     _ => {}
                 }
             }
 
+            // NB: Non-zero integers such as `NonZeroU32` or
+            // `core::num::NonZeroU32`, which can also be shadowed in which case
+            // `#[no_copy]` has to be specified.
+            if is_non_zero(&ty.path) {
+                return (TypeHint::Copy, None);
+            }
+
+            if let Some(last) = ty.path.segments.last_mut() {
+                // NB: The arguments of markers are rewritten separately by
+                // `process_marker`.
+                if last.ident == "PhantomData" {
+                    return (TypeHint::Marker, None);
+                }
+
+                // NB: Other zero-sized markers are copied as-is.
+                if last.ident == "PhantomPinned" && last.arguments.is_none() {
+                    return (TypeHint::Copy, None);
+                }
+
+                // NB: An option of a copy type such as `Option<u32>` is copy
+                // as well. This is checked on a copy of the argument, since
+                // processing it replaces any lifetimes.
+                if last.ident == "Option" {
+                    if let syn::PathArguments::AngleBracketed(generics) = &last.arguments {
+                        if let (1, Some(syn::GenericArgument::Type(ty))) =
+                            (generics.args.len(), generics.args.first())
+                        {
+                            if let (TypeHint::Copy, _) =
+                                process_type(&mut ty.clone(), ignore, &mut Vec::new())
+                            {
+                                return (TypeHint::Copy, None);
+                            }
+                        }
+                    }
+                }
+            }
+
+            // NB: The self type of an associated type such as
+            // `<Lines<'a> as Iterator>::Item` can capture lifetimes as well.
+            if let Some(qself) = &mut ty.qself {
+                process_type(&mut qself.ty, ignore, out);
+            }
+
             for s in &mut ty.path.segments {
                 match &mut s.arguments {
                     syn::PathArguments::AngleBracketed(generics) => {
@@ -749,6 +2936,292 @@ fn process_type(
     }
 }
 
+/// Test if the path refers to a non-zero integer, either by its bare name such
+/// as `NonZeroU32` or through `core::num` or `std::num`.
+fn is_non_zero(path: &syn::Path) -> bool {
+    const NON_ZERO: [&str; 12] = [
+        "NonZeroU8",
+        "NonZeroU16",
+        "NonZeroU32",
+        "NonZeroU64",
+        "NonZeroU128",
+        "NonZeroUsize",
+        "NonZeroI8",
+        "NonZeroI16",
+        "NonZeroI32",
+        "NonZeroI64",
+        "NonZeroI128",
+        "NonZeroIsize",
+    ];
+
+    if path.segments.iter().any(|s| !s.arguments.is_none()) {
+        return false;
+    }
+
+    let is_non_zero = |ident: &syn::Ident| NON_ZERO.iter().any(|name| ident == name);
+
+    match path.segments.len() {
+        1 => path.leading_colon.is_none() && is_non_zero(&path.segments[0].ident),
+        3 => {
+            let root = &path.segments[0].ident;
+
+            (root == "core" || root == "std")
+                && path.segments[1].ident == "num"
+                && is_non_zero(&path.segments[2].ident)
+        }
+        _ => false,
+    }
+}
+
+/// Find the first non-static lifetime captured by a trait object, such as
+/// `'a` in `Box<dyn Fn(&'a str) -> bool + 'a>`.
+fn trait_object_lifetime(ty: &syn::Type, ignore: &HashSet<syn::Ident>) -> Option<Span> {
+    match ty {
+        syn::Type::Array(ty) => trait_object_lifetime(&ty.elem, ignore),
+        syn::Type::Group(ty) => trait_object_lifetime(&ty.elem, ignore),
+        syn::Type::Paren(ty) => trait_object_lifetime(&ty.elem, ignore),
+        syn::Type::Reference(ty) => trait_object_lifetime(&ty.elem, ignore),
+        syn::Type::Slice(ty) => trait_object_lifetime(&ty.elem, ignore),
+        syn::Type::Tuple(ty) => ty
+            .elems
+            .iter()
+            .find_map(|ty| trait_object_lifetime(ty, ignore)),
+        syn::Type::Path(ty) => path_trait_object_lifetime(&ty.path, ignore),
+        syn::Type::TraitObject(ty) => {
+            for bound in &ty.bounds {
+                match bound {
+                    syn::TypeParamBound::Lifetime(lt)
+                        if lt.ident != STATIC && !ignore.contains(&lt.ident) =>
+                    {
+                        return Some(lt.span());
+                    }
+                    syn::TypeParamBound::Trait(bound) => {
+                        let mut ignore = ignore.clone();
+
+                        // ignore for<'a, 'b, 'c> lifetimes
+                        if let Some(bound) = &bound.lifetimes {
+                            for param in &bound.lifetimes {
+                                if let syn::GenericParam::Lifetime(lt) = param {
+                                    ignore.insert(lt.lifetime.ident.clone());
+                                }
+                            }
+                        }
+
+                        if let Some(span) = lifetime_in_path(&bound.path, &ignore) {
+                            return Some(span);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            None
+        }
+        _ => None,
+    }
+}
+
+/// Find the first non-static lifetime mentioned by a function pointer, such as
+/// `'a` in `Option<fn(&'a str) -> bool>`. Lifetimes bound by the function
+/// pointer itself, such as in `for<'x> fn(&'x str)`, are ignored.
+fn fn_pointer_lifetime(ty: &syn::Type, ignore: &HashSet<syn::Ident>) -> Option<Span> {
+    match ty {
+        syn::Type::Array(ty) => fn_pointer_lifetime(&ty.elem, ignore),
+        syn::Type::Group(ty) => fn_pointer_lifetime(&ty.elem, ignore),
+        syn::Type::Paren(ty) => fn_pointer_lifetime(&ty.elem, ignore),
+        syn::Type::Reference(ty) => fn_pointer_lifetime(&ty.elem, ignore),
+        syn::Type::Slice(ty) => fn_pointer_lifetime(&ty.elem, ignore),
+        syn::Type::Tuple(ty) => ty
+            .elems
+            .iter()
+            .find_map(|ty| fn_pointer_lifetime(ty, ignore)),
+        syn::Type::Path(ty) => {
+            for s in &ty.path.segments {
+                if let syn::PathArguments::AngleBracketed(generics) = &s.arguments {
+                    for argument in &generics.args {
+                        if let syn::GenericArgument::Type(ty) = argument {
+                            if let Some(span) = fn_pointer_lifetime(ty, ignore) {
+                                return Some(span);
+                            }
+                        }
+                    }
+                }
+            }
+
+            None
+        }
+        syn::Type::BareFn(ty) => {
+            let mut ignore = ignore.clone();
+
+            // ignore for<'a, 'b, 'c> lifetimes
+            if let Some(bound) = &ty.lifetimes {
+                for param in &bound.lifetimes {
+                    if let syn::GenericParam::Lifetime(lt) = param {
+                        ignore.insert(lt.lifetime.ident.clone());
+                    }
+                }
+            }
+
+            let mut out = Vec::new();
+
+            for arg in &ty.inputs {
+                process_type(&mut arg.ty.clone(), &ignore, &mut out);
+            }
+
+            if let syn::ReturnType::Type(_, ty) = &ty.output {
+                process_type(&mut (**ty).clone(), &ignore, &mut out);
+            }
+
+            out.into_iter()
+                .find(|(_, lt, _)| lt.is_some())
+                .map(|(span, _, _)| span)
+        }
+        _ => None,
+    }
+}
+
+/// Find the first lifetime captured by the element of a reference, such as the
+/// second `'a` in `&'a Word<'a>`.
+fn reference_element_lifetime(ty: &syn::Type, ignore: &HashSet<syn::Ident>) -> Option<Span> {
+    let syn::Type::Reference(ty) = ty else {
+        return None;
+    };
+
+    let mut out = Vec::new();
+    process_type(&mut (*ty.elem).clone(), ignore, &mut out);
+    out.into_iter()
+        .find(|(_, lt, _)| lt.is_some())
+        .map(|(span, _, _)| span)
+}
+
+/// Find the first lifetime captured by the elements of a slice reference, such
+/// as `'a` in `&'a [&'a str]`.
+fn slice_element_lifetime(ty: &syn::Type, ignore: &HashSet<syn::Ident>) -> Option<Span> {
+    let syn::Type::Reference(ty) = ty else {
+        return None;
+    };
+
+    let syn::Type::Slice(ty) = &*ty.elem else {
+        return None;
+    };
+
+    let mut out = Vec::new();
+    process_type(&mut (*ty.elem).clone(), ignore, &mut out);
+    out.into_iter().next().map(|(span, _, _)| span)
+}
+
+/// Find trait objects capturing lifetimes in the generic arguments of a path.
+fn path_trait_object_lifetime(path: &syn::Path, ignore: &HashSet<syn::Ident>) -> Option<Span> {
+    for s in &path.segments {
+        if let syn::PathArguments::AngleBracketed(generics) = &s.arguments {
+            for argument in &generics.args {
+                if let syn::GenericArgument::Type(ty) = argument {
+                    if let Some(span) = trait_object_lifetime(ty, ignore) {
+                        return Some(span);
+                    }
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Find any non-static lifetime used in a trait bound path, such as `'a` in
+/// `Fn(&'a str) -> bool`.
+fn lifetime_in_path(path: &syn::Path, ignore: &HashSet<syn::Ident>) -> Option<Span> {
+    let mut out = Vec::new();
+
+    for s in &path.segments {
+        match &s.arguments {
+            syn::PathArguments::AngleBracketed(generics) => {
+                let mut args = generics.args.clone();
+                process_generic_type(&mut args, ignore, &mut out);
+            }
+            syn::PathArguments::Parenthesized(generics) => {
+                for ty in &generics.inputs {
+                    process_type(&mut ty.clone(), ignore, &mut out);
+                }
+
+                if let syn::ReturnType::Type(_, ty) = &generics.output {
+                    process_type(&mut (**ty).clone(), ignore, &mut out);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    out.into_iter()
+        .find(|(_, lt, _)| lt.is_some())
+        .map(|(span, _, _)| span)
+}
+
+/// Rewrite the type parameter of a marker so that it doesn't capture any
+/// lifetimes.
+///
+/// References are replaced with `borrowme::marker::Ref<T>` (or `Mut<T>`),
+/// which preserve variance, unsized parameters and the auto traits of the
+/// reference, and any other lifetimes are made `'static`.
+fn process_marker(cx: &Ctxt, ty: &mut syn::Type) {
+    match ty {
+        syn::Type::Reference(reference) => {
+            let mut elem = (*reference.elem).clone();
+            process_marker(cx, &mut elem);
+
+            let marker = match reference.mutability {
+                Some(_) => &cx.borrowme_marker_mut,
+                None => &cx.borrowme_marker_ref,
+            };
+
+            *ty = syn::parse_quote!(#marker<#elem>);
+        }
+        syn::Type::Array(ty) => process_marker(cx, &mut ty.elem),
+        syn::Type::Group(ty) => process_marker(cx, &mut ty.elem),
+        syn::Type::Paren(ty) => process_marker(cx, &mut ty.elem),
+        syn::Type::Ptr(ty) => process_marker(cx, &mut ty.elem),
+        syn::Type::Slice(ty) => process_marker(cx, &mut ty.elem),
+        syn::Type::Tuple(ty) => {
+            for ty in &mut ty.elems {
+                process_marker(cx, ty);
+            }
+        }
+        syn::Type::BareFn(ty) => {
+            for input in &mut ty.inputs {
+                process_marker(cx, &mut input.ty);
+            }
+
+            if let syn::ReturnType::Type(_, ty) = &mut ty.output {
+                process_marker(cx, ty);
+            }
+        }
+        syn::Type::TraitObject(ty) => {
+            for bound in &mut ty.bounds {
+                if let syn::TypeParamBound::Lifetime(lt) = bound {
+                    *lt = syn::Lifetime::new(STATIC_LT, lt.span());
+                }
+            }
+        }
+        syn::Type::Path(ty) => {
+            for s in &mut ty.path.segments {
+                if let syn::PathArguments::AngleBracketed(generics) = &mut s.arguments {
+                    for argument in &mut generics.args {
+                        match argument {
+                            syn::GenericArgument::Lifetime(lt) => {
+                                *lt = syn::Lifetime::new(STATIC_LT, lt.span());
+                            }
+                            syn::GenericArgument::Type(ty) => {
+                                process_marker(cx, ty);
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
 fn process_generic_type<P>(
     generics: &mut Punctuated<syn::GenericArgument, P>,
     ignore: &HashSet<syn::Ident>,
@@ -781,31 +3254,84 @@ fn process_generic_type<P>(
 
 /// Strip lifetime parameters from the given generics.
 fn process_generics(cx: &Ctxt, span: Span, generics: &mut syn::Generics, empty_type: bool) {
-    let mut any = false;
+    let span = if !generics.params.is_empty() {
+        generics.params.span()
+    } else {
+        span
+    };
+
+    if !strip_lifetimes(generics) && !empty_type {
+        cx.span_error(
+            span,
+            format_args!("{NAME}: Can only be used on types which receive lifetimes or are empty"),
+        );
+    }
+}
+
+/// Remove the lifetime parameters of the given generics, returning `true` if
+/// there were any.
+pub(crate) fn strip_lifetimes(generics: &mut syn::Generics) -> bool {
+    let mut lifetimes = HashSet::new();
 
     let mut params = generics.params.clone();
     params.clear();
 
     for p in &generics.params {
-        if matches!(p, syn::GenericParam::Lifetime(..)) {
-            any = true;
+        if let syn::GenericParam::Lifetime(lt) = p {
+            lifetimes.insert(lt.lifetime.ident.clone());
         } else {
             params.push(p.clone());
         }
     }
 
-    if !any && !empty_type {
-        let span = if !generics.params.is_empty() {
-            generics.params.span()
-        } else {
-            span
-        };
+    // Bounds such as `T: 'a` only make sense on the borrowed variant, so they
+    // are removed along with the lifetimes they mention.
+    for p in &mut params {
+        if let syn::GenericParam::Type(param) = p {
+            param.bounds = mem::take(&mut param.bounds)
+                .into_iter()
+                .filter(|bound| !mentions_lifetime(bound.to_token_stream(), &lifetimes))
+                .collect();
+
+            if param.bounds.is_empty() {
+                param.colon_token = None;
+            }
+        }
+    }
 
-        cx.span_error(
-            span,
-            format_args!("{NAME}: Can only be used on types which receive lifetimes or are empty"),
-        );
+    if let Some(where_clause) = &mut generics.where_clause {
+        let mut predicates = Punctuated::new();
+
+        for mut predicate in mem::take(&mut where_clause.predicates) {
+            match &mut predicate {
+                syn::WherePredicate::Type(ty) => {
+                    if mentions_lifetime(ty.bounded_ty.to_token_stream(), &lifetimes) {
+                        continue;
+                    }
+
+                    ty.bounds = mem::take(&mut ty.bounds)
+                        .into_iter()
+                        .filter(|bound| !mentions_lifetime(bound.to_token_stream(), &lifetimes))
+                        .collect();
+
+                    if ty.bounds.is_empty() {
+                        continue;
+                    }
+                }
+                syn::WherePredicate::Lifetime(..) => continue,
+                _ => {}
+            }
+
+            predicates.push(predicate);
+        }
+
+        if predicates.is_empty() {
+            generics.where_clause = None;
+        } else {
+            where_clause.predicates = predicates;
+        }
     }
 
     generics.params = params;
+    !lifetimes.is_empty()
 }
@@ -6,6 +6,8 @@
 
 mod attr;
 mod ctxt;
+mod derive;
+mod impl_borrow;
 mod implement;
 mod respan;
 
@@ -41,7 +43,14 @@ pub fn borrowme(
         &[]
     };
 
-    let cx = Ctxt::new(item.span());
+    let krate = match &item {
+        syn::Item::Struct(st) => attr::crate_path(attr::BORROWME, attr, &st.attrs),
+        syn::Item::Enum(en) => attr::crate_path(attr::BORROWME, attr, &en.attrs),
+        syn::Item::Union(un) => attr::crate_path(attr::BORROWME, attr, &un.attrs),
+        _ => attr::crate_path(attr::BORROWME, attr, &[]),
+    };
+
+    let cx = Ctxt::new(item.span(), krate.as_ref());
 
     if let Ok(stream) = implement::implement(&cx, attr, item) {
         if !cx.has_errors() {
@@ -51,3 +60,44 @@ pub fn borrowme(
 
     cx.into_errors().into()
 }
+
+#[proc_macro]
+pub fn impl_borrow(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as impl_borrow::Input);
+
+    let krate = attr::crate_path(attr::BORROWME, &input.attrs, &[]);
+    let cx = Ctxt::new(Span::call_site(), krate.as_ref());
+
+    if let Ok(stream) = impl_borrow::implement(&cx, input) {
+        if !cx.has_errors() {
+            return stream.into();
+        }
+    }
+
+    cx.into_errors().into()
+}
+
+#[proc_macro_derive(ToOwned, attributes(to_owned))]
+pub fn derive_to_owned(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive(input, derive::Kind::ToOwned)
+}
+
+#[proc_macro_derive(Borrow, attributes(borrow))]
+pub fn derive_borrow(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive(input, derive::Kind::Borrow)
+}
+
+fn derive(input: proc_macro::TokenStream, kind: derive::Kind) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    let krate = attr::crate_path(kind.attr(), &input.attrs, &[]);
+    let cx = Ctxt::new(input.ident.span(), krate.as_ref());
+
+    if let Ok(stream) = derive::implement(&cx, kind, input) {
+        if !cx.has_errors() {
+            return stream.into();
+        }
+    }
+
+    cx.into_errors().into()
+}
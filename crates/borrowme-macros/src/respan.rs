@@ -39,7 +39,7 @@ fn respan(stream: TokenStream, spans: (Span, Span)) -> TokenStream {
         .collect()
 }
 
-fn respan_stream(stream: TokenStream, span: Span) -> TokenStream {
+pub(crate) fn respan_stream(stream: TokenStream, span: Span) -> TokenStream {
     stream.into_iter().map(|t| inner(t, span)).collect()
 }
 
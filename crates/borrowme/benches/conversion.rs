@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use borrowme::borrowme;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[borrowme]
+#[borrowme(inline = always)]
+struct Word<'a> {
+    text: &'a str,
+    lang: Option<&'a str>,
+    #[copy]
+    weight: u32,
+}
+
+#[borrowme]
+struct Dictionary<'a> {
+    name: &'a str,
+    words: Vec<Word<'a>>,
+    index: HashMap<&'a str, Word<'a>>,
+}
+
+fn dictionary() -> Dictionary<'static> {
+    const TEXTS: [&str; 8] = [
+        "alpha", "bravo", "charlie", "delta", "echo", "foxtrot", "golf", "hotel",
+    ];
+
+    let words = TEXTS
+        .iter()
+        .enumerate()
+        .map(|(n, text)| Word {
+            text,
+            lang: Some("en"),
+            weight: n as u32,
+        })
+        .collect();
+
+    let index = TEXTS
+        .iter()
+        .map(|text| {
+            let word = Word {
+                text,
+                lang: None,
+                weight: 0,
+            };
+
+            (*text, word)
+        })
+        .collect();
+
+    Dictionary {
+        name: "phonetic",
+        words,
+        index,
+    }
+}
+
+fn conversion(c: &mut Criterion) {
+    let dictionary = dictionary();
+    let owned: OwnedDictionary = borrowme::to_owned(&dictionary);
+
+    c.bench_function("to_owned", |b| {
+        b.iter(|| borrowme::to_owned(black_box(&dictionary)))
+    });
+
+    c.bench_function("borrow", |b| b.iter(|| borrowme::borrow(black_box(&owned))));
+}
+
+fn large_map(c: &mut Criterion) {
+    let keys = (0..10_000).map(|n| n.to_string()).collect::<Vec<_>>();
+
+    let map = keys
+        .iter()
+        .map(|key| (key.as_str(), key.as_str()))
+        .collect::<HashMap<_, _>>();
+
+    let owned: HashMap<String, String> = borrowme::to_owned(&map);
+
+    c.bench_function("large_map_to_owned", |b| {
+        b.iter(|| borrowme::to_owned(black_box(&map)))
+    });
+
+    c.bench_function("large_map_borrow", |b| {
+        b.iter(|| borrowme::borrow(black_box(&owned)))
+    });
+}
+
+criterion_group!(benches, conversion, large_map);
+criterion_main!(benches);
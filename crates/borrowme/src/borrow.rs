@@ -1,6 +1,19 @@
+#[cfg(feature = "alloc")]
+mod alloc;
+
 #[cfg(feature = "std")]
 mod std;
 
+#[cfg(feature = "hashbrown")]
+mod hashbrown;
+
+#[cfg(feature = "std")]
+use ::std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+#[cfg(feature = "std")]
+use ::std::time::{Instant, SystemTime};
+use core::ops::Bound;
+use core::time::Duration;
+
 /// Borrow from self.
 ///
 /// This works similarly to [`Borrow`][::std::borrow::Borrow] but allows
@@ -103,6 +116,25 @@ where
     }
 }
 
+impl<T> Borrow for Bound<T>
+where
+    T: Borrow,
+{
+    type Target<'a>
+        = Bound<T::Target<'a>>
+    where
+        T: 'a;
+
+    #[inline]
+    fn borrow(&self) -> Self::Target<'_> {
+        match self {
+            Bound::Included(value) => Bound::Included(value.borrow()),
+            Bound::Excluded(value) => Bound::Excluded(value.borrow()),
+            Bound::Unbounded => Bound::Unbounded,
+        }
+    }
+}
+
 impl<T> Borrow for [T] {
     type Target<'a>
         = &'a [T]
@@ -114,3 +146,111 @@ impl<T> Borrow for [T] {
         self
     }
 }
+
+/// Arrays are borrowed element-wise, so that `[String; N]` is borrowed as
+/// `[&'a str; N]`.
+impl<T, const N: usize> Borrow for [T; N]
+where
+    T: Borrow,
+{
+    type Target<'a>
+        = [T::Target<'a>; N]
+    where
+        T: 'a;
+
+    #[inline]
+    fn borrow(&self) -> Self::Target<'_> {
+        core::array::from_fn(|index| self[index].borrow())
+    }
+}
+
+macro_rules! primitive {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Borrow for $ty {
+                type Target<'a> = &'a $ty;
+
+                #[inline]
+                fn borrow(&self) -> Self::Target<'_> {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+// Primitives are owned as themselves, so a reference to them such as `&'a u32`
+// is borrowed back as a reference to the owned value. The same goes for plain
+// values such as `Duration`, so `HashMap<&'a str, &'a Duration>` is owned as a
+// `HashMap<String, Duration>`.
+primitive! {
+    (), bool, char,
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64,
+    Duration,
+}
+
+#[cfg(feature = "std")]
+primitive! {
+    IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr,
+    Instant, SystemTime,
+}
+
+macro_rules! tuple {
+    ($($ty:ident $n:tt),* $(,)?) => {
+        impl<$($ty,)*> Borrow for ($($ty,)*)
+        where
+            $($ty: Borrow,)*
+        {
+            type Target<'a>
+                = ($($ty::Target<'a>,)*)
+            where
+                Self: 'a;
+
+            #[inline]
+            fn borrow(&self) -> Self::Target<'_> {
+                ($(self.$n.borrow(),)*)
+            }
+        }
+    };
+}
+
+// Tuples are borrowed element-wise, so that `(String, OwnedWord)` is borrowed
+// as `(&'a str, Word<'a>)`.
+tuple!(A 0);
+tuple!(A 0, B 1);
+tuple!(A 0, B 1, C 2);
+tuple!(A 0, B 1, C 2, D 3);
+tuple!(A 0, B 1, C 2, D 3, E 4);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+macro_rules! atomic {
+    ($($width:literal => $($ty:ident),*;)*) => {
+        $($(
+            #[cfg(target_has_atomic = $width)]
+            impl Borrow for core::sync::atomic::$ty {
+                type Target<'a> = &'a core::sync::atomic::$ty;
+
+                #[inline]
+                fn borrow(&self) -> Self::Target<'_> {
+                    self
+                }
+            }
+        )*)*
+    };
+}
+
+atomic! {
+    "8" => AtomicBool, AtomicI8, AtomicU8;
+    "16" => AtomicI16, AtomicU16;
+    "32" => AtomicI32, AtomicU32;
+    "64" => AtomicI64, AtomicU64;
+    "ptr" => AtomicIsize, AtomicUsize;
+}
@@ -0,0 +1,200 @@
+use core::ffi::CStr;
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet, LinkedList, VecDeque};
+use alloc::ffi::CString;
+use alloc::rc::Rc;
+use alloc::string::String;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use crate::Borrow;
+
+impl Borrow for String {
+    type Target<'a> = &'a str;
+
+    #[inline]
+    fn borrow(&self) -> Self::Target<'_> {
+        self.as_str()
+    }
+}
+
+impl<B> Borrow for Cow<'static, B>
+where
+    B: ?Sized + alloc::borrow::ToOwned,
+{
+    type Target<'a> = Cow<'a, B>;
+
+    #[inline]
+    fn borrow(&self) -> Self::Target<'_> {
+        // This works because Cow implements `Deref<Target = B>`.
+        Cow::Borrowed(self)
+    }
+}
+
+// Trivial implementation for deref types.
+macro_rules! deref {
+    ($from:ty, $to:ty) => {
+        impl Borrow for $from {
+            type Target<'a>
+                = &'a $to
+            where
+                Self: 'a;
+
+            #[inline]
+            fn borrow(&self) -> Self::Target<'_> {
+                self
+            }
+        }
+    };
+}
+
+deref!(CString, CStr);
+
+/// Boxed values are borrowed in place, so that a recursive `Box<OwnedExpr>`
+/// is borrowed as a `Box<Expr<'a>>`. Note that this allocates.
+impl<T> Borrow for Box<T>
+where
+    T: Borrow,
+{
+    type Target<'a>
+        = Box<T::Target<'a>>
+    where
+        T: 'a;
+
+    #[inline]
+    fn borrow(&self) -> Self::Target<'_> {
+        Box::new(T::borrow(self))
+    }
+}
+
+/// Shared values are borrowed into a new `Rc`, so that an `Rc<OwnedNode>`
+/// is borrowed as an `Rc<Node<'a>>`. Note that this allocates.
+impl<T> Borrow for Rc<T>
+where
+    T: Borrow,
+{
+    type Target<'a>
+        = Rc<T::Target<'a>>
+    where
+        T: 'a;
+
+    #[inline]
+    fn borrow(&self) -> Self::Target<'_> {
+        Rc::new(T::borrow(self))
+    }
+}
+
+/// Shared values are borrowed into a new `Arc`, so that an `Arc<OwnedNode>`
+/// is borrowed as an `Arc<Node<'a>>`. Note that this allocates.
+#[cfg(target_has_atomic = "ptr")]
+impl<T> Borrow for Arc<T>
+where
+    T: Borrow,
+{
+    type Target<'a>
+        = Arc<T::Target<'a>>
+    where
+        T: 'a;
+
+    #[inline]
+    fn borrow(&self) -> Self::Target<'_> {
+        Arc::new(T::borrow(self))
+    }
+}
+
+// Unsized boxes are borrowed as a reference to their contents.
+deref!(Box<str>, str);
+deref!(Box<CStr>, CStr);
+
+impl<T> Borrow for Box<[T]> {
+    type Target<'a>
+        = &'a [T]
+    where
+        T: 'a;
+
+    #[inline]
+    fn borrow(&self) -> Self::Target<'_> {
+        self
+    }
+}
+
+macro_rules! seq {
+    (cap $seq:ident, $insert:ident $(, $trait:path)* $(,)?) => {
+        impl<T> Borrow for $seq<T>
+        where
+            T: Borrow,
+            $(for<'a> T::Target<'a>: $trait,)*
+        {
+            type Target<'a> = $seq<T::Target<'a>> where T: 'a;
+
+            #[inline]
+            fn borrow(&self) -> Self::Target<'_> {
+                let mut out = <$seq<_>>::with_capacity(self.len());
+
+                for value in self {
+                    out.$insert(value.borrow());
+                }
+
+                out
+            }
+        }
+    };
+
+    ($seq:ident, $insert:ident $(, $trait:path)* $(,)?) => {
+        impl<T> Borrow for $seq<T>
+        where
+            T: Borrow,
+            $(for<'a> T::Target<'a>: $trait,)*
+        {
+            type Target<'a> = $seq<T::Target<'a>> where T: 'a;
+
+            #[inline]
+            fn borrow(&self) -> Self::Target<'_> {
+                let mut out = <$seq<_>>::new();
+
+                for value in self {
+                    out.$insert(value.borrow());
+                }
+
+                out
+            }
+        }
+    };
+}
+
+macro_rules! map {
+    ($map:ident, $insert:ident $(, $trait:path)* $(,)?) => {
+        impl<K, V> Borrow for $map<K, V>
+        where
+            K: Borrow,
+            V: Borrow,
+            $(for<'a> K::Target<'a>: $trait,)*
+        {
+            type Target<'a> = $map<K::Target<'a>, V::Target<'a>> where K: 'a, V: 'a;
+
+            #[inline]
+            fn borrow(&self) -> Self::Target<'_> {
+                let mut out = <$map<_, _>>::new();
+
+                for (key, value) in self {
+                    out.$insert(key.borrow(), value.borrow());
+                }
+
+                // Keys which are distinct in the source might compare equal
+                // once converted, which would silently drop entries.
+                debug_assert_eq!(out.len(), self.len(), "converted map has colliding keys");
+                out
+            }
+        }
+    };
+}
+
+seq!(cap Vec, push);
+seq!(cap VecDeque, push_back);
+seq!(BTreeSet, insert, PartialOrd, Ord, Eq);
+seq!(LinkedList, push_back);
+
+map!(BTreeMap, insert, PartialOrd, Ord, Eq);
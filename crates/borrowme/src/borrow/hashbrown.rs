@@ -0,0 +1,60 @@
+use core::hash::{BuildHasher, Hash};
+
+use hashbrown::{HashMap, HashSet};
+
+use crate::Borrow;
+
+/// The borrowed set is constructed using a clone of the original hasher.
+impl<T, S> Borrow for HashSet<T, S>
+where
+    T: Borrow,
+    for<'a> T::Target<'a>: Hash + Eq,
+    S: Clone + BuildHasher,
+{
+    type Target<'a>
+        = HashSet<T::Target<'a>, S>
+    where
+        T: 'a,
+        S: 'a;
+
+    #[inline]
+    fn borrow(&self) -> Self::Target<'_> {
+        let mut out = HashSet::with_capacity_and_hasher(self.len(), self.hasher().clone());
+
+        for value in self {
+            out.insert(value.borrow());
+        }
+
+        out
+    }
+}
+
+/// The borrowed map is constructed using a clone of the original hasher.
+impl<K, V, S> Borrow for HashMap<K, V, S>
+where
+    K: Borrow,
+    V: Borrow,
+    for<'a> K::Target<'a>: Hash + Eq,
+    S: Clone + BuildHasher,
+{
+    type Target<'a>
+        = HashMap<K::Target<'a>, V::Target<'a>, S>
+    where
+        K: 'a,
+        V: 'a,
+        S: 'a;
+
+    #[inline]
+    fn borrow(&self) -> Self::Target<'_> {
+        let mut out = HashMap::with_capacity_and_hasher(self.len(), self.hasher().clone());
+
+        for (key, value) in self {
+            out.insert(key.borrow(), value.borrow());
+        }
+
+        // Keys which are distinct in the source might compare equal once
+        // converted, which would silently drop entries.
+        debug_assert_eq!(out.len(), self.len(), "converted map has colliding keys");
+        out
+    }
+}
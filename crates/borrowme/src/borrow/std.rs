@@ -1,30 +1,62 @@
-use std::borrow::Cow;
-use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList};
-use std::ffi::{CStr, CString, OsStr, OsString};
-use std::hash::Hash;
+use std::collections::{HashMap, HashSet};
+use std::ffi::{OsStr, OsString};
+use std::hash::{BuildHasher, Hash};
 use std::path::{Path, PathBuf};
 
 use crate::Borrow;
 
-impl Borrow for String {
-    type Target<'a> = &'a str;
+/// The borrowed set is constructed using a clone of the original hasher.
+impl<T, S> Borrow for HashSet<T, S>
+where
+    T: Borrow,
+    for<'a> T::Target<'a>: Hash + Eq,
+    S: Clone + BuildHasher,
+{
+    type Target<'a>
+        = HashSet<T::Target<'a>, S>
+    where
+        T: 'a,
+        S: 'a;
 
     #[inline]
     fn borrow(&self) -> Self::Target<'_> {
-        self.as_str()
+        let mut out = HashSet::with_capacity_and_hasher(self.len(), self.hasher().clone());
+
+        for value in self {
+            out.insert(value.borrow());
+        }
+
+        out
     }
 }
 
-impl<B> Borrow for Cow<'static, B>
+/// The borrowed map is constructed using a clone of the original hasher.
+impl<K, V, S> Borrow for HashMap<K, V, S>
 where
-    B: ?Sized + std::borrow::ToOwned,
+    K: Borrow,
+    V: Borrow,
+    for<'a> K::Target<'a>: Hash + Eq,
+    S: Clone + BuildHasher,
 {
-    type Target<'a> = Cow<'a, B>;
+    type Target<'a>
+        = HashMap<K::Target<'a>, V::Target<'a>, S>
+    where
+        K: 'a,
+        V: 'a,
+        S: 'a;
 
     #[inline]
     fn borrow(&self) -> Self::Target<'_> {
-        // This works because Cow implements `Deref<Target = B>`.
-        Cow::Borrowed(self)
+        let mut out = HashMap::with_capacity_and_hasher(self.len(), self.hasher().clone());
+
+        for (key, value) in self {
+            out.insert(key.borrow(), value.borrow());
+        }
+
+        // Keys which are distinct in the source might compare equal once
+        // converted, which would silently drop entries.
+        debug_assert_eq!(out.len(), self.len(), "converted map has colliding keys");
+        out
     }
 }
 
@@ -47,114 +79,5 @@ macro_rules! deref {
 
 deref!(PathBuf, Path);
 deref!(OsString, OsStr);
-deref!(CString, CStr);
-
-impl<T: ?Sized> Borrow for Box<T> {
-    type Target<'a>
-        = &'a T
-    where
-        T: 'a;
-
-    #[inline]
-    fn borrow(&self) -> Self::Target<'_> {
-        self
-    }
-}
-
-macro_rules! seq {
-    (cap $seq:ident, $insert:ident $(, $trait:path)* $(,)?) => {
-        impl<T> Borrow for $seq<T>
-        where
-            T: Borrow,
-            $(for<'a> T::Target<'a>: $trait,)*
-        {
-            type Target<'a> = $seq<T::Target<'a>> where T: 'a;
-
-            #[inline]
-            fn borrow(&self) -> Self::Target<'_> {
-                let mut out = <$seq<_>>::with_capacity(self.len());
-
-                for value in self {
-                    out.$insert(value.borrow());
-                }
-
-                out
-            }
-        }
-    };
-
-    ($seq:ident, $insert:ident $(, $trait:path)* $(,)?) => {
-        impl<T> Borrow for $seq<T>
-        where
-            T: Borrow,
-            $(for<'a> T::Target<'a>: $trait,)*
-        {
-            type Target<'a> = $seq<T::Target<'a>> where T: 'a;
-
-            #[inline]
-            fn borrow(&self) -> Self::Target<'_> {
-                let mut out = <$seq<_>>::new();
-
-                for value in self {
-                    out.$insert(value.borrow());
-                }
-
-                out
-            }
-        }
-    };
-}
-
-macro_rules! map {
-    (cap $map:ident, $insert:ident $(, $trait:path)* $(,)?) => {
-        impl<K, V> Borrow for $map<K, V>
-        where
-            K: Borrow,
-            V: Borrow,
-            $(for<'a> K::Target<'a>: $trait,)*
-        {
-            type Target<'a> = $map<K::Target<'a>, V::Target<'a>> where K: 'a, V: 'a;
-
-            #[inline]
-            fn borrow(&self) -> Self::Target<'_> {
-                let mut out = <$map<_, _>>::with_capacity(self.len());
-
-                for (key, value) in self {
-                    out.$insert(key.borrow(), value.borrow());
-                }
-
-                out
-            }
-        }
-    };
-
-    ($map:ident, $insert:ident $(, $trait:path)* $(,)?) => {
-        impl<K, V> Borrow for $map<K, V>
-        where
-            K: Borrow,
-            V: Borrow,
-            $(for<'a> K::Target<'a>: $trait,)*
-        {
-            type Target<'a> = $map<K::Target<'a>, V::Target<'a>> where K: 'a, V: 'a;
-
-            #[inline]
-            fn borrow(&self) -> Self::Target<'_> {
-                let mut out = <$map<_, _>>::new();
-
-                for (key, value) in self {
-                    out.$insert(key.borrow(), value.borrow());
-                }
-
-                out
-            }
-        }
-    };
-}
-
-seq!(cap Vec, push);
-seq!(cap HashSet, insert, Hash, Eq);
-seq!(BTreeSet, insert, PartialOrd, Ord, Eq);
-seq!(LinkedList, push_back);
-
-map!(cap HashMap, insert, Hash, Eq);
-map!(BTreeMap, insert, PartialOrd, Ord, Eq);
+deref!(Box<Path>, Path);
+deref!(Box<OsStr>, OsStr);
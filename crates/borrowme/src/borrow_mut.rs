@@ -1,6 +1,19 @@
+#[cfg(feature = "alloc")]
+mod alloc;
+
 #[cfg(feature = "std")]
 mod std;
 
+#[cfg(feature = "hashbrown")]
+mod hashbrown;
+
+#[cfg(feature = "std")]
+use ::std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+#[cfg(feature = "std")]
+use ::std::time::{Instant, SystemTime};
+use core::ops::Bound;
+use core::time::Duration;
+
 /// Borrow mutably from self.
 ///
 /// This works similarly to [`BorrowMut`][std-borrow-mut] but allows borrowing
@@ -105,6 +118,25 @@ where
     }
 }
 
+impl<T> BorrowMut for Bound<T>
+where
+    T: BorrowMut,
+{
+    type TargetMut<'a>
+        = Bound<T::TargetMut<'a>>
+    where
+        T: 'a;
+
+    #[inline]
+    fn borrow_mut(&mut self) -> Self::TargetMut<'_> {
+        match self {
+            Bound::Included(value) => Bound::Included(value.borrow_mut()),
+            Bound::Excluded(value) => Bound::Excluded(value.borrow_mut()),
+            Bound::Unbounded => Bound::Unbounded,
+        }
+    }
+}
+
 impl<T> BorrowMut for [T] {
     type TargetMut<'a>
         = &'a mut [T]
@@ -116,3 +148,70 @@ impl<T> BorrowMut for [T] {
         self
     }
 }
+
+impl<T, const N: usize> BorrowMut for [T; N] {
+    type TargetMut<'a>
+        = &'a mut [T; N]
+    where
+        T: 'a;
+
+    #[inline]
+    fn borrow_mut(&mut self) -> Self::TargetMut<'_> {
+        self
+    }
+}
+
+macro_rules! primitive {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl BorrowMut for $ty {
+                type TargetMut<'a> = &'a mut $ty;
+
+                #[inline]
+                fn borrow_mut(&mut self) -> Self::TargetMut<'_> {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+// Primitives are owned as themselves, so a reference to them such as `&'a mut
+// u32` is borrowed back as a mutable reference to the owned value.
+primitive! {
+    (), bool, char,
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64,
+    Duration,
+}
+
+#[cfg(feature = "std")]
+primitive! {
+    IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr,
+    Instant, SystemTime,
+}
+
+macro_rules! atomic {
+    ($($width:literal => $($ty:ident),*;)*) => {
+        $($(
+            #[cfg(target_has_atomic = $width)]
+            impl BorrowMut for core::sync::atomic::$ty {
+                type TargetMut<'a> = &'a mut core::sync::atomic::$ty;
+
+                #[inline]
+                fn borrow_mut(&mut self) -> Self::TargetMut<'_> {
+                    self
+                }
+            }
+        )*)*
+    };
+}
+
+atomic! {
+    "8" => AtomicBool, AtomicI8, AtomicU8;
+    "16" => AtomicI16, AtomicU16;
+    "32" => AtomicI32, AtomicU32;
+    "64" => AtomicI64, AtomicU64;
+    "ptr" => AtomicIsize, AtomicUsize;
+}
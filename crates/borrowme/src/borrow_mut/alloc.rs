@@ -0,0 +1,109 @@
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, LinkedList, VecDeque};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{Borrow, BorrowMut};
+
+impl BorrowMut for String {
+    type TargetMut<'a> = &'a mut String;
+
+    #[inline]
+    fn borrow_mut(&mut self) -> Self::TargetMut<'_> {
+        self
+    }
+}
+
+/// Boxed values are borrowed in place. Note that this allocates.
+impl<T> BorrowMut for Box<T>
+where
+    T: BorrowMut,
+{
+    type TargetMut<'a>
+        = Box<T::TargetMut<'a>>
+    where
+        T: 'a;
+
+    #[inline]
+    fn borrow_mut(&mut self) -> Self::TargetMut<'_> {
+        Box::new(T::borrow_mut(self))
+    }
+}
+
+macro_rules! seq {
+    (cap $seq:ident, $insert:ident $(, $trait:path)* $(,)?) => {
+        impl<T> BorrowMut for $seq<T>
+        where
+            T: BorrowMut,
+            $(for<'a> T::TargetMut<'a>: $trait,)*
+        {
+            type TargetMut<'a> = $seq<T::TargetMut<'a>> where T: 'a;
+
+            #[inline]
+            fn borrow_mut(&mut self) -> Self::TargetMut<'_> {
+                let mut out = <$seq<_>>::with_capacity(self.len());
+
+                for value in self {
+                    out.$insert(value.borrow_mut());
+                }
+
+                out
+            }
+        }
+    };
+
+    ($seq:ident, $insert:ident $(, $trait:path)* $(,)?) => {
+        impl<T> BorrowMut for $seq<T>
+        where
+            T: BorrowMut,
+            $(for<'a> T::TargetMut<'a>: $trait,)*
+        {
+            type TargetMut<'a> = $seq<T::TargetMut<'a>> where T: 'a;
+
+            #[inline]
+            fn borrow_mut(&mut self) -> Self::TargetMut<'_> {
+                let mut out = <$seq<_>>::new();
+
+                for value in self {
+                    out.$insert(value.borrow_mut());
+                }
+
+                out
+            }
+        }
+    };
+}
+
+macro_rules! map {
+    ($map:ident, $insert:ident $(, $trait:path)* $(,)?) => {
+        impl<K, V> BorrowMut for $map<K, V>
+        where
+            K: Borrow,
+            V: BorrowMut,
+            $(for<'a> K::Target<'a>: $trait,)*
+        {
+            type TargetMut<'a> = $map<K::Target<'a>, V::TargetMut<'a>> where K: 'a, V: 'a;
+
+            #[inline]
+            fn borrow_mut(&mut self) -> Self::TargetMut<'_> {
+                let len = self.len();
+                let mut out = <$map<_, _>>::new();
+
+                for (key, value) in self {
+                    out.$insert(key.borrow(), value.borrow_mut());
+                }
+
+                // Keys which are distinct in the source might compare equal
+                // once converted, which would silently drop entries.
+                debug_assert_eq!(out.len(), len, "converted map has colliding keys");
+                out
+            }
+        }
+    };
+}
+
+seq!(cap Vec, push);
+seq!(cap VecDeque, push_back);
+seq!(LinkedList, push_back);
+
+map!(BTreeMap, insert, PartialOrd, Ord, Eq);
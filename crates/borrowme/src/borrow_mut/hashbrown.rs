@@ -0,0 +1,36 @@
+use core::hash::{BuildHasher, Hash};
+
+use hashbrown::HashMap;
+
+use crate::{Borrow, BorrowMut};
+
+/// The borrowed map is constructed using a clone of the original hasher.
+impl<K, V, S> BorrowMut for HashMap<K, V, S>
+where
+    K: Borrow,
+    V: BorrowMut,
+    for<'a> K::Target<'a>: Hash + Eq,
+    S: Clone + BuildHasher,
+{
+    type TargetMut<'a>
+        = HashMap<K::Target<'a>, V::TargetMut<'a>, S>
+    where
+        K: 'a,
+        V: 'a,
+        S: 'a;
+
+    #[inline]
+    fn borrow_mut(&mut self) -> Self::TargetMut<'_> {
+        let len = self.len();
+        let mut out = HashMap::with_capacity_and_hasher(len, self.hasher().clone());
+
+        for (key, value) in self {
+            out.insert(key.borrow(), value.borrow_mut());
+        }
+
+        // Keys which are distinct in the source might compare equal once
+        // converted, which would silently drop entries.
+        debug_assert_eq!(out.len(), len, "converted map has colliding keys");
+        out
+    }
+}
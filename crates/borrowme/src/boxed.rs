@@ -0,0 +1,16 @@
+//! Helpers used by the [`#[borrowme(boxed_slices)]`][boxed_slices] container
+//! attribute to own slices as a [`Box<[T]>`][Box].
+//!
+//! [boxed_slices]: crate::borrowme#borrowmeboxed_slices-container-attribute
+
+#[doc(inline)]
+pub use alloc::boxed::Box;
+
+/// Convert a slice into an owned boxed slice.
+#[inline]
+pub fn to_owned<T>(value: &[T]) -> Box<[T]>
+where
+    T: Clone,
+{
+    Box::from(value)
+}
@@ -0,0 +1,236 @@
+//! Helpers used by the [`#[borrowme(collect)]`][collect] field attribute to
+//! convert custom collections element-wise.
+//!
+//! [collect]: crate::borrowme#borrowmecollect-field-attribute
+
+use core::iter::FromIterator;
+
+use crate::{Borrow, ToOwned};
+
+/// A collection which can be converted element-wise by the
+/// [`#[borrowme(collect)]`][collect] field attribute.
+///
+/// Implementing this for a collection type means that it participates in
+/// conversions the same way as built-in collections such as `Vec<T>` do,
+/// without having to specify how each field is converted.
+///
+/// ```
+/// use borrowme::borrowme;
+/// use borrowme::collect::Collect;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Stack<T>(Vec<T>);
+///
+/// impl<T> Collect for Stack<T> {
+///     type Item = T;
+///     type Output<U> = Stack<U>;
+///     type Iter<'a> = std::slice::Iter<'a, T> where T: 'a;
+///
+///     fn items(&self) -> Self::Iter<'_> {
+///         self.0.iter()
+///     }
+///
+///     fn with_capacity(capacity: usize) -> Self {
+///         Stack(Vec::with_capacity(capacity))
+///     }
+///
+///     fn extend_items<I>(&mut self, iter: I)
+///     where
+///         I: IntoIterator<Item = T>,
+///     {
+///         self.0.extend(iter);
+///     }
+/// }
+///
+/// #[borrowme]
+/// struct Words<'a> {
+///     #[borrowme(collect)]
+///     words: Stack<&'a str>,
+/// }
+///
+/// let words = Words { words: Stack(vec!["hello", "world"]) };
+/// let owned: OwnedWords = borrowme::to_owned(&words);
+/// assert_eq!(owned.words, Stack(vec![String::from("hello"), String::from("world")]));
+/// ```
+///
+/// [collect]: crate::borrowme#borrowmecollect-field-attribute
+pub trait Collect {
+    /// The type of the elements in the collection.
+    type Item;
+
+    /// The same kind of collection holding elements of type `U`.
+    type Output<U>: Collect<Item = U>;
+
+    /// Iterator over references to the elements in the collection.
+    type Iter<'a>: Iterator<Item = &'a Self::Item>
+    where
+        Self: 'a;
+
+    /// Iterate over the elements in the collection.
+    fn items(&self) -> Self::Iter<'_>;
+
+    /// Construct an empty collection with space for at least `capacity`
+    /// elements.
+    fn with_capacity(capacity: usize) -> Self;
+
+    /// Extend the collection with the given elements.
+    fn extend_items<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Self::Item>;
+}
+
+/// Convert a collection into one holding owned elements.
+#[inline]
+pub fn to_owned<C>(value: &C) -> C::Output<<C::Item as ToOwned>::Owned>
+where
+    C: Collect,
+    C::Item: ToOwned,
+{
+    collect(value.items().map(ToOwned::to_owned))
+}
+
+/// Borrow a collection into one holding borrowed elements.
+#[inline]
+pub fn borrow<C>(value: &C) -> C::Output<<C::Item as Borrow>::Target<'_>>
+where
+    C: Collect,
+    C::Item: Borrow,
+{
+    collect(value.items().map(Borrow::borrow))
+}
+
+/// Convert the elements of a collection into owned values, and collect them
+/// into a collection of type `O`.
+///
+/// This is used by the [`#[borrowme(owned_collect = <type>)]`][owned_collect]
+/// field attribute.
+///
+/// [owned_collect]: crate::borrowme#borrowmeowned_collect--type-field-attribute
+#[inline]
+pub fn to_owned_from_iter<'a, I, T, O>(value: I) -> O
+where
+    I: IntoIterator<Item = &'a T>,
+    T: 'a + ?Sized + ToOwned,
+    O: FromIterator<T::Owned>,
+{
+    value.into_iter().map(ToOwned::to_owned).collect()
+}
+
+/// Borrow the elements of a collection, and collect them into a collection of
+/// type `O`.
+///
+/// This is used by the [`#[borrowme(owned_collect = <type>)]`][owned_collect]
+/// field attribute.
+///
+/// [owned_collect]: crate::borrowme#borrowmeowned_collect--type-field-attribute
+#[inline]
+pub fn borrow_from_iter<'a, I, T, O>(value: I) -> O
+where
+    I: IntoIterator<Item = &'a T>,
+    T: 'a + Borrow,
+    O: FromIterator<T::Target<'a>>,
+{
+    value.into_iter().map(Borrow::borrow).collect()
+}
+
+/// Collect an iterator, reserving capacity up front based on its size hint.
+fn collect<C, I>(iter: I) -> C
+where
+    C: Collect,
+    I: Iterator<Item = C::Item>,
+{
+    let mut out = C::with_capacity(iter.size_hint().0);
+    out.extend_items(iter);
+    out
+}
+
+#[cfg(feature = "alloc")]
+mod alloc {
+    use core::slice;
+
+    use alloc::collections::{linked_list, vec_deque, LinkedList, VecDeque};
+    use alloc::vec::Vec;
+
+    use super::Collect;
+
+    impl<T> Collect for Vec<T> {
+        type Item = T;
+        type Output<U> = Vec<U>;
+        type Iter<'a>
+            = slice::Iter<'a, T>
+        where
+            T: 'a;
+
+        #[inline]
+        fn items(&self) -> Self::Iter<'_> {
+            self.as_slice().iter()
+        }
+
+        #[inline]
+        fn with_capacity(capacity: usize) -> Self {
+            Vec::with_capacity(capacity)
+        }
+
+        #[inline]
+        fn extend_items<I>(&mut self, iter: I)
+        where
+            I: IntoIterator<Item = T>,
+        {
+            Extend::extend(self, iter);
+        }
+    }
+
+    impl<T> Collect for VecDeque<T> {
+        type Item = T;
+        type Output<U> = VecDeque<U>;
+        type Iter<'a>
+            = vec_deque::Iter<'a, T>
+        where
+            T: 'a;
+
+        #[inline]
+        fn items(&self) -> Self::Iter<'_> {
+            VecDeque::iter(self)
+        }
+
+        #[inline]
+        fn with_capacity(capacity: usize) -> Self {
+            VecDeque::with_capacity(capacity)
+        }
+
+        #[inline]
+        fn extend_items<I>(&mut self, iter: I)
+        where
+            I: IntoIterator<Item = T>,
+        {
+            Extend::extend(self, iter);
+        }
+    }
+
+    impl<T> Collect for LinkedList<T> {
+        type Item = T;
+        type Output<U> = LinkedList<U>;
+        type Iter<'a>
+            = linked_list::Iter<'a, T>
+        where
+            T: 'a;
+
+        #[inline]
+        fn items(&self) -> Self::Iter<'_> {
+            LinkedList::iter(self)
+        }
+
+        #[inline]
+        fn with_capacity(_: usize) -> Self {
+            LinkedList::new()
+        }
+
+        #[inline]
+        fn extend_items<I>(&mut self, iter: I)
+        where
+            I: IntoIterator<Item = T>,
+        {
+            Extend::extend(self, iter);
+        }
+    }
+}
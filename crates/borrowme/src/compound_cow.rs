@@ -0,0 +1,162 @@
+use core::fmt;
+
+use crate::{Borrow, ToOwned};
+
+/// A clone-on-write value over compound borrowme types.
+///
+/// The standard [`Cow`][std-cow] requires that the borrowed type implements
+/// [`std::borrow::ToOwned`], which compound types such as `[Word<'a>]` can't
+/// do (see [`ToOwned`]). This is instead parameterized over the *owned* type
+/// `T`, and either holds a borrowed view of it in the form of
+/// [`T::Target<'a>`][Borrow::Target] or an owned `T`.
+///
+/// So a `CompoundCow<'a, Vec<OwnedWord>>` holds either a `Vec<Word<'a>>` or a
+/// `Vec<OwnedWord>`, and is only converted into the latter once it has to be
+/// modified through [`CompoundCow::to_mut`].
+///
+/// As a field this is owned as `T`, and is borrowed back using
+/// [`CompoundCow::borrowed`] which has to be specified with
+/// [`#[borrowme(borrow_with = <path>)]`][borrow_with].
+///
+/// ```
+/// use borrowme::{borrowme, CompoundCow};
+///
+/// #[borrowme]
+/// struct Word<'a> {
+///     text: &'a str,
+/// }
+///
+/// #[borrowme]
+/// struct Sentence<'a> {
+///     #[borrowme(borrow_with = CompoundCow::borrowed)]
+///     words: CompoundCow<'a, Vec<OwnedWord>>,
+/// }
+///
+/// let mut sentence = Sentence {
+///     words: CompoundCow::Borrowed(vec![Word { text: "hello" }]),
+/// };
+///
+/// assert!(sentence.words.is_borrowed());
+///
+/// sentence.words.to_mut().push(OwnedWord { text: String::from("world") });
+/// assert!(sentence.words.is_owned());
+///
+/// let owned: OwnedSentence = borrowme::to_owned(&sentence);
+/// assert_eq!(owned.words.len(), 2);
+///
+/// let sentence: Sentence<'_> = borrowme::borrow(&owned);
+/// assert!(sentence.words.is_borrowed());
+/// ```
+///
+/// [std-cow]: https://doc.rust-lang.org/std/borrow/enum.Cow.html
+/// [`std::borrow::ToOwned`]: https://doc.rust-lang.org/std/borrow/trait.ToOwned.html
+/// [borrow_with]: crate::borrowme#borrowmeborrow_with--path-field-attribute
+pub enum CompoundCow<'a, T>
+where
+    T: 'a + Borrow,
+{
+    /// A borrowed view of the owned type.
+    Borrowed(T::Target<'a>),
+    /// An owned value.
+    Owned(T),
+}
+
+impl<'a, T> CompoundCow<'a, T>
+where
+    T: 'a + Borrow,
+{
+    /// Borrow from an owned value.
+    ///
+    /// This is intended to be used with
+    /// [`#[borrowme(borrow_with = <path>)]`][borrow_with] on fields of this
+    /// type.
+    ///
+    /// [borrow_with]: crate::borrowme#borrowmeborrow_with--path-field-attribute
+    #[inline]
+    pub fn borrowed(value: &'a T) -> Self {
+        CompoundCow::Borrowed(value.borrow())
+    }
+
+    /// Test if the value is borrowed.
+    #[inline]
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, CompoundCow::Borrowed(..))
+    }
+
+    /// Test if the value is owned.
+    #[inline]
+    pub fn is_owned(&self) -> bool {
+        matches!(self, CompoundCow::Owned(..))
+    }
+
+    /// Access the owned value mutably, converting a borrowed value into an
+    /// owned one first.
+    pub fn to_mut(&mut self) -> &mut T
+    where
+        T::Target<'a>: ToOwned<Owned = T>,
+    {
+        if let CompoundCow::Borrowed(value) = self {
+            *self = CompoundCow::Owned(value.to_owned());
+        }
+
+        match self {
+            CompoundCow::Owned(value) => value,
+            CompoundCow::Borrowed(..) => unreachable!(),
+        }
+    }
+
+    /// Convert into an owned value, converting a borrowed value if necessary.
+    #[inline]
+    pub fn into_owned(self) -> T
+    where
+        T::Target<'a>: ToOwned<Owned = T>,
+    {
+        match self {
+            CompoundCow::Borrowed(value) => value.to_owned(),
+            CompoundCow::Owned(value) => value,
+        }
+    }
+}
+
+impl<'a, T> ToOwned for CompoundCow<'a, T>
+where
+    T: 'a + Borrow,
+    for<'b> T::Target<'b>: ToOwned<Owned = T>,
+{
+    type Owned = T;
+
+    #[inline]
+    fn to_owned(&self) -> <Self as ToOwned>::Owned {
+        match self {
+            CompoundCow::Borrowed(value) => value.to_owned(),
+            CompoundCow::Owned(value) => value.borrow().to_owned(),
+        }
+    }
+}
+
+impl<'a, T> Clone for CompoundCow<'a, T>
+where
+    T: 'a + Clone + Borrow,
+    T::Target<'a>: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        match self {
+            CompoundCow::Borrowed(value) => CompoundCow::Borrowed(value.clone()),
+            CompoundCow::Owned(value) => CompoundCow::Owned(value.clone()),
+        }
+    }
+}
+
+impl<'a, T> fmt::Debug for CompoundCow<'a, T>
+where
+    T: 'a + fmt::Debug + Borrow,
+    T::Target<'a>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompoundCow::Borrowed(value) => f.debug_tuple("Borrowed").field(value).finish(),
+            CompoundCow::Owned(value) => f.debug_tuple("Owned").field(value).finish(),
+        }
+    }
+}
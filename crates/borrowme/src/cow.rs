@@ -0,0 +1,81 @@
+//! Helpers used by the [`#[borrowme(cow)]`][cow] field attribute to own a
+//! reference as a [`Cow<'static, T>`][Cow], and by the
+//! [`#[borrowme(borrow_cow)]`][borrow_cow] field attribute to borrow a value as
+//! a [`Cow<'a, T>`][Cow].
+//!
+//! [cow]: crate::borrowme#borrowmecow-field-attribute
+//! [borrow_cow]: crate::borrowme#borrowmeborrow_cow-field-attribute
+
+#[doc(inline)]
+pub use alloc::borrow::Cow;
+
+/// The owned value of a [`Cow<'a, T>`][Cow], such as a `String` for a
+/// `Cow<'a, str>`.
+pub type Owned<T> = <T as alloc::borrow::ToOwned>::Owned;
+
+/// Convert a reference into an owned [`Cow`].
+#[inline]
+pub fn to_owned<T>(value: &T) -> Cow<'static, T>
+where
+    T: 'static + ?Sized + alloc::borrow::ToOwned,
+{
+    Cow::Owned(value.to_owned())
+}
+
+/// Borrow the value inside of a [`Cow`].
+// NB: This is called with a reference to the owned field, so it has to accept
+// `&Cow` rather than `&T`.
+#[allow(clippy::ptr_arg)]
+#[inline]
+pub fn borrow<'a, T>(value: &'a Cow<'static, T>) -> &'a T
+where
+    T: 'static + ?Sized + alloc::borrow::ToOwned,
+{
+    value
+}
+
+/// Convert a [`Cow`] into one with a `'static` lifetime by value.
+///
+/// Unlike [`ToOwned`][crate::ToOwned] which only has access to a reference and
+/// therefore has to clone, this moves an already owned value so that it's
+/// reused without allocating.
+///
+/// ```
+/// use borrowme::cow::{self, Cow};
+///
+/// let text = String::from("hello");
+/// let ptr = text.as_ptr();
+///
+/// let owned: Cow<'static, str> = cow::into_static(Cow::Owned(text));
+/// assert_eq!(owned.as_ptr(), ptr);
+///
+/// let borrowed: Cow<'static, str> = cow::into_static(Cow::Borrowed("world"));
+/// assert_eq!(borrowed, "world");
+/// ```
+#[inline]
+pub fn into_static<T>(value: Cow<'_, T>) -> Cow<'static, T>
+where
+    T: 'static + ?Sized + alloc::borrow::ToOwned,
+{
+    Cow::Owned(value.into_owned())
+}
+
+/// Convert the value inside of a [`Cow`] into its owned value, which clones it
+/// regardless of whether it's borrowed or owned.
+#[allow(clippy::ptr_arg)]
+#[inline]
+pub fn clone_owned<T>(value: &Cow<'_, T>) -> Owned<T>
+where
+    T: ?Sized + alloc::borrow::ToOwned,
+{
+    T::to_owned(value)
+}
+
+/// Borrow an owned value as a [`Cow::Borrowed`].
+#[inline]
+pub fn borrowed<T>(value: &Owned<T>) -> Cow<'_, T>
+where
+    T: ?Sized + alloc::borrow::ToOwned,
+{
+    Cow::Borrowed(core::borrow::Borrow::borrow(value))
+}
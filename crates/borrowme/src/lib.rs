@@ -103,6 +103,9 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 /// Automatically build an *owned* variant of a type and implement [`ToOwned`] and
 /// [`Borrow`].
 ///
@@ -110,6 +113,10 @@
 /// To have detailed control over this behavior, see the
 /// `#[borrowed_attr(<meta>)]` and `#[owned_attr(<meta>)]` attributes below.
 ///
+/// The fields and variants of the generated variant are always declared in the
+/// same order as in the original type, so attributes such as `#[repr(C)]`
+/// result in the same field order in both.
+///
 /// In order to work as intended, `#[borrowme]` must be used *before* any
 /// attributes that you want it to capture such as derives.
 ///
@@ -163,9 +170,10 @@
 ///
 /// #### `&[T]`
 ///
-/// The [`ToOwned`] implementation produces a `Vec<T>`, while [`Borrow`] of
-/// `Vec<T>` produces a `Vec<&T::Target>`. This can be fixed using
-/// [`Vec::as_slice`].
+/// The [`ToOwned`] implementation converts each element, producing a
+/// `Vec<T::Owned>`, while [`Borrow`] of `Vec<T>` produces a `Vec<&T::Target>`.
+/// For elements which are their own owned variant, such as `String` or
+/// primitives, this can be fixed using [`Vec::as_slice`].
 ///
 /// ```
 /// use borrowme::borrowme;
@@ -177,12 +185,29 @@
 /// }
 /// ```
 ///
+/// Mutable slices such as `&'a mut [u8]` are owned as a `Vec<u8>` as well, but
+/// are borrowed back mutably as a `&'a mut [u8]` by default.
+///
+/// ```
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// struct Buffer<'a> {
+///     data: &'a mut [u8],
+/// }
+///
+/// let mut owned = OwnedBuffer { data: vec![1, 2, 3] };
+/// let buffer: Buffer<'_> = borrowme::borrow_mut(&mut owned);
+/// buffer.data.fill(0);
+/// assert_eq!(owned.data, [0, 0, 0]);
+/// ```
+///
 /// <br>
 ///
 /// #### `&[T]` as an owned `Box<[T]>`
 ///
-/// The [`ToOwned`] implementation produces a `Vec<T>`, while borrowing that
-/// produces a `Vec<&T::Target>`. This can be fixed using [`Box::from`].
+/// The [`ToOwned`] implementation produces a `Vec<T::Owned>`, while borrowing
+/// that produces a `Vec<&T::Target>`. This can be fixed using [`Box::from`].
 ///
 /// ```
 /// use borrowme::borrowme;
@@ -196,6 +221,460 @@
 ///
 /// <br>
 ///
+/// #### `&'a str` as an owned `Vec<u8>`
+///
+/// A string can be owned as its UTF-8 encoded bytes using the helpers in the
+/// [`util`] module. Since the *owned* field can hold any bytes, borrowing it
+/// back validates it and panics if it's not valid UTF-8.
+///
+/// ```
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// struct Word<'a> {
+///     #[borrowme(owned = Vec<u8>, to_owned_with = borrowme::util::str_to_vec, borrow_with = borrowme::util::vec_to_str)]
+///     text: &'a str,
+/// }
+/// ```
+///
+/// Validation can be avoided by borrowing the bytes without checking them, but
+/// that is only sound as long as the *owned* field can't be constructed or
+/// modified with invalid UTF-8, such as when it's private to a module which
+/// only ever populates it from a string.
+///
+/// ```
+/// mod word {
+///     use borrowme::borrowme;
+///
+///     #[borrowme]
+///     pub struct Word<'a> {
+///         #[borrowme(owned = Vec<u8>, to_owned_with = borrowme::util::str_to_vec, borrow_with = vec_to_str_unchecked)]
+///         text: &'a str,
+///     }
+///
+///     #[allow(clippy::ptr_arg)]
+///     fn vec_to_str_unchecked(bytes: &Vec<u8>) -> &str {
+///         // SAFETY: The field is private and only populated from a string.
+///         unsafe { core::str::from_utf8_unchecked(bytes) }
+///     }
+/// }
+/// ```
+///
+/// <br>
+///
+/// #### `&'a [&'a T]`
+///
+/// A slice of references such as `&'a [&'a str]` would be owned as a
+/// `Vec<String>`, but there is no way to borrow that back into a slice of
+/// references without allocating. Such fields are therefore rejected unless
+/// their conversion is specified. Use a `Vec<&'a str>` instead, which is owned
+/// as a `Vec<String>` and borrowed back into a new `Vec<&str>`.
+///
+/// ```
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// struct Args<'a> {
+///     program: &'a str,
+///     args: Vec<&'a str>,
+/// }
+/// ```
+///
+/// <br>
+///
+/// #### `&'a Word<'a>`
+///
+/// Similarly, a reference to a type with lifetimes such as `&'a Word<'a>` would
+/// be owned as an `OwnedWord`, which can only be borrowed back as a `Word<'_>`
+/// by value and not as a reference to one. Such fields are therefore rejected
+/// unless their conversion is specified. Store the value directly instead,
+/// which is owned and borrowed the same way.
+///
+/// ```
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// struct Word<'a> {
+///     text: &'a str,
+/// }
+///
+/// #[borrowme]
+/// struct Entry<'a> {
+///     // Instead of `&'a Word<'a>`.
+///     word: Word<'a>,
+/// }
+/// ```
+///
+/// <br>
+///
+/// #### `Box<T>`
+///
+/// A boxed value is converted in place, so a recursive field such as
+/// `Box<Expr<'a>>` is owned as a `Box<OwnedExpr>` and borrowed back into a new
+/// `Box<Expr<'_>>`. Note that this means borrowing allocates.
+///
+/// ```
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// enum Expr<'a> {
+///     Ident(&'a str),
+///     Not(Box<Expr<'a>>),
+/// }
+/// ```
+///
+/// The same applies to `Rc<T>` and `Arc<T>`, but note that the converted value
+/// is a new allocation which isn't shared with the original.
+///
+/// Unsized boxes are asymmetric. A `Box<str>` is owned as a `String` and a
+/// `Box<[T]>` as a `Box<[T::Owned]>`, while borrowing either of them produces a
+/// reference to its contents.
+///
+/// <br>
+///
+/// #### Tuples such as `(&'a str, Word<'a>)`
+///
+/// Tuples of up to 12 elements which aren't `Copy` are converted
+/// element-wise, so `(&'a str, Word<'a>)` is owned as a `(String, OwnedWord)`.
+/// Like in other containers, primitive elements are borrowed back as
+/// references to them, so they have to be declared as such.
+///
+/// ```
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// struct Word<'a> {
+///     text: &'a str,
+/// }
+///
+/// #[borrowme]
+/// struct Entry<'a> {
+///     pair: (&'a str, Word<'a>),
+///     // Instead of `(&'a str, u32)`.
+///     count: (&'a str, &'a u32),
+/// }
+/// ```
+///
+/// <br>
+///
+/// #### Plain values such as `&'a Duration`
+///
+/// Primitives and plain values such as [`Duration`] are their own owned
+/// variant, and so are [`Instant`], [`SystemTime`] and the addresses in
+/// [`std::net`] such as [`Ipv4Addr`] with the `std` feature. Fields which are
+/// just the value are kept as-is, while references to them such as `&'a
+/// Duration` are owned as the value.
+///
+/// Note that [`Borrow`] of these produces a reference to the owned value
+/// rather than a copy of it, since that's what such a reference is borrowed
+/// back as. So fields have to declare them as references when they are
+/// borrowed, such as in `Option<&'a Duration>` or `HashMap<&'a str, &'a
+/// Duration>`.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use std::time::{Duration, SystemTime};
+///
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// struct Timings<'a> {
+///     total: Duration,
+///     started: SystemTime,
+///     // Instead of `Option<Duration>`.
+///     fastest: Option<&'a Duration>,
+///     by_name: HashMap<&'a str, &'a Duration>,
+/// }
+/// ```
+///
+/// [`Duration`]: core::time::Duration
+/// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+/// [`Ipv4Addr`]: https://doc.rust-lang.org/std/net/struct.Ipv4Addr.html
+/// [`std::net`]: https://doc.rust-lang.org/std/net/index.html
+/// [`SystemTime`]: https://doc.rust-lang.org/std/time/struct.SystemTime.html
+///
+/// <br>
+///
+/// #### `&'a T` where `T` is a type parameter
+///
+/// A reference to a type parameter is owned as `T::Owned`, and borrowed back
+/// using [`core::borrow::Borrow<T>`]. The necessary bounds are added to the
+/// generated owned type and implementations.
+///
+/// ```
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// struct Wrap<'a, T: ?Sized> {
+///     value: &'a T,
+/// }
+///
+/// let wrap = Wrap::<str> { value: "Hello" };
+/// let owned: OwnedWrap<str> = borrowme::to_owned(&wrap);
+/// assert_eq!(owned.value, "Hello");
+/// ```
+///
+/// <br>
+///
+/// #### `T` where `T` is a type parameter
+///
+/// Fields which mention a type parameter without borrowing from it, such as `T`
+/// or `Option<T>`, are cloned in both directions. The generated conversions
+/// are then bounded by the field implementing `Clone`, while the owned type
+/// itself is not. To convert them instead, see [`#[borrowme(convert)]`][convert].
+///
+/// ```
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// #[derive(Debug, PartialEq)]
+/// enum Either<'a, T> {
+///     Left(&'a str),
+///     Right(T),
+/// }
+///
+/// let either = Either::<u32>::Right(42);
+/// let owned: OwnedEither<u32> = borrowme::to_owned(&either);
+/// assert_eq!(owned, OwnedEither::Right(42));
+/// assert_eq!(borrowme::borrow(&owned), either);
+/// ```
+///
+/// <br>
+///
+/// #### `T` where `T` is marked with `#[borrowme(convert)]`
+///
+/// A type parameter marked with `#[borrowme(convert)]` is instead converted
+/// through [`ToOwned`] and [`Borrow`], so that a `Wrapper<'a, Word<'a>>` is
+/// owned as an `OwnedWrapper<OwnedWord>`. In the *owned* variant the parameter
+/// stands in for its owned type, so the generated implementations use
+/// `OwnedWrapper<T::Owned>` and `Wrapper<'a, T::Target<'a>>` respectively.
+///
+/// Fields which mention such a parameter can't capture any lifetimes, such
+/// as `&'a T`, and the parameter can't have any bounds since they would apply
+/// to both the *borrowed* and the *owned* type. It also can't be used when a
+/// field requires mutable access.
+///
+/// ```
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// #[derive(Debug, PartialEq)]
+/// struct Word<'a> {
+///     text: &'a str,
+/// }
+///
+/// #[borrowme]
+/// #[derive(Debug, PartialEq)]
+/// struct Wrapper<'a, #[borrowme(convert)] T> {
+///     value: T,
+///     values: Vec<T>,
+///     text: &'a str,
+/// }
+///
+/// let wrapper = Wrapper {
+///     value: Word { text: "hello" },
+///     values: vec![Word { text: "world" }],
+///     text: "!",
+/// };
+///
+/// let owned: OwnedWrapper<OwnedWord> = borrowme::to_owned(&wrapper);
+/// assert_eq!(owned.value.text, "hello");
+/// assert_eq!(borrowme::borrow(&owned), wrapper);
+/// ```
+///
+/// <br>
+///
+/// #### `&'a [T; N]`
+///
+/// A reference to an array is owned as the array itself and borrowed back as a
+/// reference to it, which also works when the length is a const generic.
+///
+/// ```
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// struct Bytes<'a, const N: usize>(&'a [u8; N]);
+///
+/// let bytes = Bytes(&[1, 2, 3]);
+/// let owned: OwnedBytes<3> = borrowme::to_owned(&bytes);
+/// assert_eq!(owned.0, [1, 2, 3]);
+/// ```
+///
+/// An array stored by value is only `Copy` if its elements are. Otherwise it's
+/// converted element-wise, so `[&'a str; N]` is owned as a `[String; N]`.
+///
+/// ```
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// struct Names<'a> {
+///     names: [&'a str; 4],
+/// }
+///
+/// let names = Names { names: ["a", "b", "c", "d"] };
+/// let owned: OwnedNames = borrowme::to_owned(&names);
+/// assert_eq!(owned.names, ["a", "b", "c", "d"]);
+/// ```
+///
+/// <br>
+///
+/// #### `&'a Vec<T>` and `&'a String`
+///
+/// A reference to an owned container is owned as the container itself and
+/// borrowed back as a reference to it, rather than as a `Vec<&'a T>` or a
+/// `&'a str`. This only applies if the container doesn't capture any lifetimes
+/// or type parameters.
+///
+/// ```
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// struct Vector<'a> {
+///     data: &'a Vec<f32>,
+/// }
+///
+/// let data = vec![1.0, 2.0];
+/// let vector = Vector { data: &data };
+/// let owned: OwnedVector = borrowme::to_owned(&vector);
+/// assert_eq!(owned.data, [1.0, 2.0]);
+///
+/// let borrowed: Vector<'_> = borrowme::borrow(&owned);
+/// assert_eq!(borrowed.data, &data);
+/// ```
+///
+/// <br>
+///
+/// #### Atomics such as `&'a AtomicBool`
+///
+/// A reference to an atomic is owned as a new atomic constructed from its
+/// current value, which is loaded using [`Ordering::Relaxed`]. The owned value
+/// is a snapshot which doesn't synchronize with other threads, so if that is
+/// needed the field has to be converted explicitly using
+/// [`#[borrowme(to_owned_with = <path>)]`][to_owned_with].
+///
+/// Since atomics aren't `Clone`, an atomic which is stored by value has to
+/// specify that it's converted in both directions.
+///
+/// ```
+/// use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+///
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// struct Config<'a> {
+///     enabled: &'a AtomicBool,
+///     #[borrowme(owned = AtomicUsize, borrow_with = borrowme::ToOwned::to_owned)]
+///     count: AtomicUsize,
+/// }
+///
+/// let enabled = AtomicBool::new(true);
+/// let config = Config { enabled: &enabled, count: AtomicUsize::new(1) };
+/// let owned: OwnedConfig = borrowme::to_owned(&config);
+/// assert!(owned.enabled.load(Ordering::Relaxed));
+/// ```
+///
+/// [`Ordering::Relaxed`]: core::sync::atomic::Ordering::Relaxed
+///
+/// <br>
+///
+/// #### Type aliases
+///
+/// The macro only sees the name of a type alias such as `type Str<'a> = &'a
+/// str`, not the type it refers to. The *owned* type of a field using an alias
+/// with lifetimes is still inferred through [`ToOwned`], so `Str<'a>` is owned
+/// as a `String`. But heuristics which depend on the shape of the type, such as
+/// detecting `Copy` types or references to arrays, don't apply. So fields
+/// using such aliases might have to be annotated with [`#[copy]`][copy] or
+/// [`#[owned(<type>)]`][owned].
+///
+/// The same goes for associated types such as `<Lines<'a> as
+/// Iterator>::Item`, which are owned as `<<Lines<'static> as
+/// Iterator>::Item as ToOwned>::Owned`.
+///
+/// ```
+/// use borrowme::borrowme;
+///
+/// type Str<'a> = &'a str;
+/// type Id = Position;
+///
+/// #[derive(Clone, Copy)]
+/// struct Position(u32);
+///
+/// #[borrowme]
+/// struct Word<'a> {
+///     text: Str<'a>,
+///     #[copy]
+///     id: Id,
+/// }
+///
+/// let word = Word { text: "hello", id: Position(1) };
+/// let owned: OwnedWord = borrowme::to_owned(&word);
+/// assert_eq!(owned.text, "hello");
+/// ```
+///
+/// <br>
+///
+/// ## Unions
+///
+/// Unions are supported as long as every field is copied, since there is no
+/// way to tell which field to convert. The owned union is identical to the
+/// borrowed one and both are converted by copying them bitwise, which is why
+/// the union also has to be `#[repr(C)]`. For the same reason its `repr` can't
+/// be changed through `owned_attr` or `borrowed_attr`, and every field type has
+/// to implement `Copy`. Fields also can't capture lifetimes or specify how
+/// they are converted.
+///
+/// ```
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// #[repr(C)]
+/// pub union Payload {
+///     #[copy]
+///     int: u32,
+///     bytes: [u8; 4],
+/// }
+///
+/// let payload = Payload { int: 42 };
+/// let owned: OwnedPayload = borrowme::to_owned(&payload);
+/// assert_eq!(unsafe { owned.bytes }, 42u32.to_ne_bytes());
+/// ```
+///
+/// <br>
+///
+/// ## Allocations
+///
+/// Conversions always go through a reference, since [`ToOwned::to_owned`] takes
+/// `&self` and there is no consuming conversion. A collection such as
+/// `Vec<Word<'a>>` is therefore owned by allocating a new collection for its
+/// elements, and its spare capacity isn't carried over. The original
+/// allocation can't be reused either, even if `OwnedWord` happens to have the
+/// same size and alignment as `Word<'a>`, since it's still borrowed.
+///
+/// ```
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// struct Word<'a> {
+///     text: &'a str,
+/// }
+///
+/// #[borrowme]
+/// struct Words<'a> {
+///     words: Vec<Word<'a>>,
+/// }
+///
+/// let mut words = Vec::with_capacity(16);
+/// words.push(Word { text: "hello" });
+///
+/// let words = Words { words };
+/// let owned: OwnedWords = borrowme::to_owned(&words);
+/// assert_eq!(owned.words.len(), 1);
+/// assert_ne!(owned.words.as_ptr() as usize, words.words.as_ptr() as usize);
+/// ```
+///
+/// <br>
+///
 /// ## Why isn't this a derive?
 ///
 /// A derive macro can't see other attributes than the ones it declares as its
@@ -228,6 +707,46 @@
 ///   container by default.
 /// * [`#[borrowme(name = <ident>)]`][name] which is used to change the name of
 ///   the generated *owned* variant.
+/// * [`#[borrowme(inline = <always|never>)]`][inline] which controls how
+///   the generated conversion functions are inlined.
+/// * [`#[borrowme(mut_view = <ident>)]`][mut_view] which generates a mutable
+///   view of the container.
+/// * [`#[borrowme(leak)]`][leak] which generates a method to leak the *owned*
+///   variant into a `'static` borrowed variant.
+/// * [`#[borrowme(static_variant = <ident>)]`][static_variant] which generates
+///   a type alias for the borrowed variant with `'static` lifetimes.
+/// * [`#[borrowme(error = <type>)]`][error] which specifies the error type of
+///   the generated [`TryToOwned`] implementation.
+/// * [`#[borrowme(debug)]`][debug] which generates a minimal `Debug`
+///   implementation for both variants.
+/// * [`#[borrowme(no_heuristics)]`][no_heuristics] which disables the copy
+///   and reference heuristics for every field in the container.
+/// * [`#[borrowme(owned_static)]`][owned_static] which requires that the
+///   *owned* variant is `'static`.
+/// * [`#[borrowme(owned_hash)]`][owned_hash] which generates `Hash`,
+///   `PartialEq` and `Eq` implementations for the *owned* variant over a
+///   subset of its fields.
+/// * [`#[borrowme(map_type(<type> => <type>))]`][map_type] which changes the
+///   *owned* type used for every field of a given reference type.
+/// * [`#[borrowme(boxed_slices)]`][boxed_slices] which owns every immutable
+///   slice field as a `Box<[T]>` instead of a `Vec<T>`.
+/// * [`#[borrowme(with = <path>)]`][container-with] which converts every field
+///   using the functions in the given module by default.
+/// * [`#[borrowme(std_borrow)]`][std_borrow] which implements
+///   [`core::borrow::Borrow`] for the *owned* variant of a newtype over a
+///   reference, so that it can be used to look up values in maps.
+/// * [`#[borrowme(serde)]`][serde] which adds `#[serde(borrow)]` to every field
+///   of the *borrowed* variant which captures lifetimes.
+/// * [`#[borrowme(from)]`][from] which implements [`From`] conversions between
+///   the *owned* and *borrowed* variants.
+/// * [`#[borrowme(borrow_ref)]`][borrow_ref] which generates an inherent
+///   `borrow_ref` method on the *owned* variant.
+/// * [`#[borrowme(bound = "<predicates>")]`][bound] which replaces the bounds
+///   inferred for the generated conversions.
+/// * [`#[borrowme(arbitrary)]`][arbitrary] which derives `Arbitrary` for the
+///   *owned* variant.
+/// * [`#[borrowme(crate = <path>)]`][crate] which changes the path used to
+///   refer to this crate in generated code.
 /// * [`#[borrowed_attr(<meta>)]`][b-c] and [`#[owned_attr(<meta>)]`][o-c] which
 ///   are used to add custom attributes.
 ///
@@ -243,69 +762,689 @@
 ///     /* body */
 /// }
 ///
-/// #[borrowme(name = EnumBuf)]
-/// enum Enum<'a> {
-///     /* body */
-/// # First { text: &'a str },
-/// # Second { text: &'a str },
-/// }
+/// #[borrowme(name = EnumBuf)]
+/// enum Enum<'a> {
+///     /* body */
+/// # First { text: &'a str },
+/// # Second { text: &'a str },
+/// }
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(std)]` container attribute
+///
+/// This container attribute acts as if [`#[borrowme(std)]`][std] is applied to
+/// every field or variant in the container.
+///
+/// Note that this defeats copy and reference heuristics.
+///
+/// ```
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// #[borrowme(std)]
+/// struct StdStruct<'a> {
+///     a: &'a String,
+///     #[borrowme(copy)]
+///     b: u32,
+/// }
+///
+/// #[borrowme]
+/// #[borrowme(std)]
+/// enum StdEnum<'a> {
+///     Variant {
+///         a: &'a String,
+///         #[borrowme(copy)]
+///         b: u32,
+///     }
+/// }
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(name = <ident>)]` container attribute
+///
+/// This allows you to pick the name to use for the generated type. By default
+/// this is the original identifier prefixed with `Owned`.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme(name = WordBuf)]
+/// #[derive(Debug, PartialEq)]
+/// struct Word<'a> {
+///     text: &'a str,
+/// }
+///
+/// let word = Word {
+///     text: "Hello World",
+/// };
+///
+/// let word2 = WordBuf {
+///     text: String::from("Hello World"),
+/// };
+///
+/// assert_eq!(borrowme::to_owned(&word), word2);
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(inline = <always|never>)]` container attribute
+///
+/// Controls how the generated conversion functions are inlined. By default
+/// they are marked with `#[inline]`, this changes them to `#[inline(always)]`
+/// or `#[inline(never)]` which can be useful in hot paths converting many
+/// small values.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme(inline = always)]
+/// struct Word<'a> {
+///     text: &'a str,
+/// }
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(mut_view = <ident>)]` container attribute
+///
+/// Generates a third *mutable view* type with the given name, which is a copy
+/// of the borrowed struct where every reference field such as `&'a str`
+/// becomes a mutable reference such as `&'a mut str`. Other fields are
+/// borrowed the same way as in the borrowed variant. The *owned* variant
+/// implements [`BorrowMut`] with the view as its target.
+///
+/// Attributes are not forwarded to the view, since most derives can't be
+/// implemented for mutable references. This is only supported on structs
+/// which don't otherwise require mutable access.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme(mut_view = MutWord)]
+/// struct Word<'a> {
+///     text: &'a str,
+///     tags: Vec<&'a str>,
+/// }
+///
+/// let mut word = OwnedWord {
+///     text: String::from("hello"),
+///     tags: vec![String::from("greeting")],
+/// };
+///
+/// let view: MutWord<'_> = borrowme::borrow_mut(&mut word);
+/// view.text.make_ascii_uppercase();
+/// assert_eq!(view.tags, ["greeting"]);
+///
+/// assert_eq!(word.text, "HELLO");
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(leak)]` container attribute
+///
+/// Generates an inherent `leak` method on the *owned* variant which converts
+/// it into a borrowed variant with the `'static` lifetime using [`leak()`].
+///
+/// **This leaks memory** since the owned value is never dropped. It's only
+/// intended for values which live for the remainder of the program anyway,
+/// such as configuration which is loaded once. This requires the `alloc`
+/// feature.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme(leak)]
+/// struct Config<'a> {
+///     name: &'a str,
+/// }
+///
+/// let config = OwnedConfig {
+///     name: String::from("app"),
+/// };
+///
+/// let config: Config<'static> = config.leak();
+/// assert_eq!(config.name, "app");
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(static_variant = <ident>)]` container attribute
+///
+/// Generates a type alias with the given name for the *borrowed* variant where
+/// every lifetime is `'static`, such as `type StaticWord = Word<'static>`. This
+/// is useful when the same type is either borrowed from runtime data or stored
+/// as constant data, like in embedded firmware.
+///
+/// No separate type is needed, since the *borrowed* variant can be constructed
+/// in a `const` as long as every field can be `'static`. Such a value is
+/// converted into an *owned* variant as usual, and borrowing from an *owned*
+/// value which lives for `'static` produces the static variant. An owned value
+/// which doesn't can be turned into one by leaking it using
+/// [`#[borrowme(leak)]`][leak].
+///
+/// This requires that the container has lifetime parameters.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme(static_variant = StaticWord)]
+/// #[derive(Debug, PartialEq)]
+/// pub struct Word<'a> {
+///     text: &'a str,
+/// }
+///
+/// const HELLO: StaticWord = Word { text: "hello" };
+///
+/// let owned: OwnedWord = borrowme::to_owned(&HELLO);
+/// assert_eq!(owned.text, "hello");
+/// assert_eq!(borrowme::borrow(&owned), HELLO);
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(error = <type>)]` container attribute
+///
+/// Specifies the error type of the generated [`TryToOwned`] implementation.
+/// This is required when any field uses
+/// [`#[borrowme(try_to_owned_with = <path>)]`][try_to_owned_with], and if
+/// specified without it the implementation never fails.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme(error = &'static str)]
+/// struct Word<'a> {
+///     #[borrowme(try_to_owned_with = ascii)]
+///     text: &'a str,
+/// }
+///
+/// fn ascii(text: &str) -> Result<String, &'static str> {
+///     if !text.is_ascii() {
+///         return Err("text is not ascii");
+///     }
+///
+///     Ok(text.to_owned())
+/// }
+///
+/// let word: OwnedWord = borrowme::try_to_owned(Word { text: "hello" })?;
+/// assert_eq!(word.text, "hello");
+/// # Ok::<_, &'static str>(())
+/// ```
+///
+/// This also implements [`TryFrom`] for the *owned* variant taking the
+/// *borrowed* variant by value, using the same error type. Note that this still
+/// converts through a reference, so values are not moved out of the borrowed
+/// variant.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// use std::convert::TryFrom;
+///
+/// #[borrowme(error = &'static str)]
+/// struct Word<'a> {
+///     #[borrowme(try_to_owned_with = non_empty)]
+///     text: &'a str,
+/// }
+///
+/// fn non_empty(text: &str) -> Result<String, &'static str> {
+///     if text.is_empty() {
+///         return Err("text is empty");
+///     }
+///
+///     Ok(text.to_owned())
+/// }
+///
+/// let word = OwnedWord::try_from(Word { text: "hello" })?;
+/// assert_eq!(word.text, "hello");
+/// assert!(OwnedWord::try_from(Word { text: "" }).is_err());
+/// # Ok::<_, &'static str>(())
+/// ```
+///
+/// [`TryFrom`]: core::convert::TryFrom
+///
+/// <br>
+///
+/// #### `#[borrowme(debug)]` container attribute
+///
+/// Generates a minimal `Debug` implementation for both the *borrowed* and the
+/// *owned* variant which lists every field, the same way as `#[derive(Debug)]`
+/// would. This is handy during development when neither variant derives
+/// `Debug`, and fields which mention a type parameter are required to
+/// implement `Debug`.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme(debug)]
+/// struct Word<'a> {
+///     text: &'a str,
+/// }
+///
+/// let word = OwnedWord {
+///     text: String::from("hello"),
+/// };
+///
+/// assert_eq!(format!("{word:?}"), r#"OwnedWord { text: "hello" }"#);
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(no_heuristics)]` container attribute
+///
+/// Disables the [copy and reference heuristics][copy] which are otherwise used
+/// to determine how a field is converted from its type. Instead every field
+/// has to specify this explicitly using [`#[copy]`][copy],
+/// [`#[owned(<type>)]`][owned], [`#[borrowme(std)]`][std] or
+/// [`#[borrowme(cow)]`][cow], which is an error if missing.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme(no_heuristics)]
+/// struct Word<'a> {
+///     #[owned(String)]
+///     text: &'a str,
+///     #[copy]
+///     count: u32,
+/// }
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(owned_static)]` container attribute
+///
+/// Adds bounds to the *owned* variant and its implementations requiring that
+/// it is `'static`. A type parameter `T` which is referenced such as in `&'a
+/// T` is owned as `T::Owned`, so this adds `T::Owned: 'static`. Other type
+/// parameters are required to be `'static` themselves.
+///
+/// This is useful when owned values are stored in long-lived contexts such as
+/// registries, since generic code can rely on the bound.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// use std::any::Any;
+///
+/// #[borrowme(owned_static)]
+/// struct Wrap<'a, T: ?Sized> {
+///     value: &'a T,
+/// }
+///
+/// fn store<T>(owned: OwnedWrap<T>) -> Box<dyn Any>
+/// where
+///     T: ?Sized + borrowme::ToOwned,
+/// {
+///     Box::new(owned.value)
+/// }
+///
+/// let owned: OwnedWrap<str> = borrowme::to_owned(&Wrap { value: "hello" });
+/// let stored = store(owned);
+/// assert!(stored.is::<String>());
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(owned_hash)]` container attribute
+///
+/// Generates `Hash`, `PartialEq` and `Eq` implementations for the *owned*
+/// variant which only consider some of its fields. This is useful when owned
+/// values are used as keys in maps, but contain fields such as caches which
+/// shouldn't affect their identity.
+///
+/// Fields are excluded by marking them with `#[borrowme(no_hash)]`.
+/// Alternatively if any field is marked with `#[borrowme(hash)]`, only the
+/// marked fields are used. Values of different enum variants never compare
+/// equal.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// use std::collections::HashSet;
+///
+/// #[borrowme(owned_hash)]
+/// struct Word<'a> {
+///     text: &'a str,
+///     #[borrowme(no_hash)]
+///     cache: Vec<u32>,
+/// }
+///
+/// let a: OwnedWord = borrowme::to_owned(&Word { text: "hello", cache: vec![1] });
+/// let b: OwnedWord = borrowme::to_owned(&Word { text: "hello", cache: vec![] });
+///
+/// let mut set = HashSet::new();
+/// assert!(set.insert(a));
+/// assert!(!set.insert(b));
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(map_type(<type> => <type>))]` container attribute
+///
+/// Changes the *owned* type of every field whose type is the given immutable
+/// reference. Lifetimes are ignored when matching, so `&str` matches both
+/// `&'a str` and `&'static str`. The attribute can be repeated to map several
+/// types.
+///
+/// The *owned* type is constructed using [`From<&T>`][From] and borrowed
+/// through [`Deref`], so it has to implement both. Fields which specify their
+/// own conversion, such as through [`#[owned(<type>)]`][owned] or
+/// [`#[borrowme(with = <path>)]`][with], are left as-is.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// use std::sync::Arc;
+///
+/// #[borrowme(map_type(&str => Arc<str>))]
+/// struct Word<'a> {
+///     text: &'a str,
+///     lang: &'a str,
+///     #[owned(String)]
+///     note: &'a str,
+/// }
+///
+/// let word = Word { text: "hello", lang: "en", note: "greeting" };
+/// let owned: OwnedWord = borrowme::to_owned(&word);
+///
+/// let text: Arc<str> = owned.text.clone();
+/// let note: &String = &owned.note;
+/// assert_eq!(&*text, "hello");
+/// assert_eq!(note, "greeting");
+/// ```
+///
+/// [From]: core::convert::From
+/// [`Deref`]: core::ops::Deref
+///
+/// <br>
+///
+/// #### `#[borrowme(boxed_slices)]` container attribute
+///
+/// Owns every field of type `&'a [T]` as a `Box<[T]>` instead of a `Vec<T>`.
+/// This avoids keeping around spare capacity for slices which are never
+/// modified once owned, and saves annotating each field individually.
+///
+/// Elements are cloned into the box, so `T` has to implement `Clone`. Fields
+/// which specify their own conversion, such as through
+/// [`#[owned(<type>)]`][owned], are left as-is.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme(boxed_slices)]
+/// struct Packet<'a> {
+///     header: &'a [u8],
+///     body: &'a [u16],
+///     #[borrowme(owned = Vec<u8>, deref_borrow)]
+///     trailer: &'a [u8],
+/// }
+///
+/// let packet = Packet { header: &[1, 2], body: &[3, 4], trailer: &[5] };
+/// let owned: OwnedPacket = borrowme::to_owned(&packet);
+///
+/// let header: Box<[u8]> = owned.header.clone();
+/// let trailer: &Vec<u8> = &owned.trailer;
+/// assert_eq!(&header[..], &[1, 2]);
+/// assert_eq!(&trailer[..], &[5]);
+///
+/// let packet: Packet<'_> = borrowme::borrow(&owned);
+/// assert_eq!(packet.body, &[3, 4]);
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(with = <path>)]` container attribute
+///
+/// This container attribute acts as if [`#[borrowme(with = <path>)]`][with] is
+/// applied to every field in the container by default. This is useful when
+/// every field is converted in the same way, such as for types which don't
+/// implement the traits in this crate themselves.
+///
+/// Fields which specify how they are converted, such as through
+/// [`#[borrowme(to_owned_with = <path>)]`][to_owned_with], [`#[copy]`][copy]
+/// or [`#[borrowme(skip)]`][skip], don't use the container module. This can't
+/// be combined with [`#[borrowme(std)]`][container-std].
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme(with = option)]
+/// struct Word<'a> {
+///     #[owned(Option<String>)]
+///     text: Option<&'a str>,
+///     #[owned(Option<String>)]
+///     lang: Option<&'a str>,
+///     #[copy]
+///     count: u32,
+/// }
+///
+/// mod option {
+///     use borrowme::{Borrow, ToOwned};
+///
+///     pub(crate) fn borrow<T>(this: &Option<T>) -> Option<T::Target<'_>>
+///     where
+///         T: Borrow,
+///     {
+///         this.as_ref().map(Borrow::borrow)
+///     }
+///
+///     pub(crate) fn to_owned<T>(this: &Option<T>) -> Option<T::Owned>
+///     where
+///         T: ToOwned,
+///     {
+///         this.as_ref().map(ToOwned::to_owned)
+///     }
+/// }
+///
+/// let word = Word { text: Some("hello"), lang: None, count: 1 };
+/// let owned: OwnedWord = borrowme::to_owned(&word);
+/// assert_eq!(owned.text.as_deref(), Some("hello"));
+///
+/// let word: Word<'_> = borrowme::borrow(&owned);
+/// assert_eq!(word.text, Some("hello"));
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(std_borrow)]` container attribute
+///
+/// Implements [`core::borrow::Borrow`] for the *owned* variant, borrowing the
+/// type that the field refers to. So `struct Word<'a>(&'a str)` gets an
+/// implementation of `Borrow<str>` for `OwnedWord`, which means that an
+/// `OwnedWord` key in a map such as `HashMap<OwnedWord, V>` can be looked up
+/// using a `&str`.
+///
+/// Since the *borrowed* variant has lifetimes it can't be borrowed this way in
+/// general, so this has a number of constraints:
+/// * It's only supported on structs with a single field, such as a newtype.
+/// * The field has to be an immutable reference, such as `&'a str`.
+/// * The referenced type can't capture any lifetimes, so `&'a Word<'a>` is not
+///   supported.
+/// * The owned type of the field has to implement `Borrow` for the referenced
+///   type, like `String` does for `str` and `PathBuf` does for `Path`.
+///
+/// Like with the standard `Borrow` trait, it's up to the *owned* variant to
+/// make sure that its implementations of traits such as `Hash` and `Eq`
+/// behave the same as they do for the referenced type. This is the case when
+/// they are derived, since the struct only has the one field.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// use std::collections::HashMap;
+///
+/// #[borrowme(std_borrow)]
+/// #[derive(PartialEq, Eq, Hash)]
+/// struct Word<'a>(&'a str);
+///
+/// let mut counts = HashMap::<OwnedWord, u32>::new();
+/// counts.insert(borrowme::to_owned(&Word("hello")), 1);
+/// assert_eq!(counts.get("hello"), Some(&1));
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(serde)]` container attribute
+///
+/// Adds `#[serde(borrow)]` to every field of the *borrowed* variant which
+/// captures a lifetime, so that the fields are deserialized by borrowing from
+/// the input. Fields without lifetimes and the *owned* variant are left
+/// untouched, as are fields which already have a `#[serde(borrow)]` attribute
+/// through [`#[borrowed_attr(<meta>)]`][b-f].
+///
+/// ```
+/// # use borrowme::borrowme;
+/// use serde::Deserialize;
+///
+/// #[borrowme(serde)]
+/// #[derive(Deserialize)]
+/// struct Record<'a> {
+///     name: &'a str,
+///     tags: Vec<&'a str>,
+///     count: u32,
+/// }
+///
+/// let record: Record<'_> = serde_json::from_str(r#"{"name":"a","tags":["b"],"count":1}"#)?;
+/// let owned: OwnedRecord = borrowme::to_owned(&record);
+/// assert_eq!(owned.tags, ["b"]);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(from)]` container attribute
+///
+/// Implements [`From`] for the *owned* variant taking the *borrowed* variant by
+/// value, and [`From`] for the *borrowed* variant taking a reference to the
+/// *owned* variant. These use [`to_owned()`] and [`borrow()`] respectively. If
+/// any field uses [`#[borrowme(mut)]`][mut], the *borrowed* variant is instead
+/// converted from a mutable reference using [`borrow_mut()`].
+///
+/// This can't be combined with [`#[borrowme(error = <type>)]`][error], since a
+/// fallible conversion implements [`TryFrom`] instead.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme(from)]
+/// #[derive(Debug, PartialEq)]
+/// struct Word<'a> {
+///     text: &'a str,
+/// }
+///
+/// let owned = OwnedWord::from(Word { text: "Hello" });
+/// assert_eq!(owned.text, "Hello");
+/// assert_eq!(Word::from(&owned), Word { text: "Hello" });
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(borrow_ref)]` container attribute
+///
+/// Generates an inherent `borrow_ref` method on the *owned* variant with the
+/// same visibility as the type. It behaves the same as [`borrow()`], but
+/// doesn't require the [`Borrow`] trait to be in scope. If any field uses
+/// [`#[borrowme(mut)]`][mut], it takes `&mut self` and behaves the same as
+/// [`borrow_mut()`] instead.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme(borrow_ref)]
+/// #[derive(Debug, PartialEq)]
+/// pub struct Word<'a> {
+///     text: &'a str,
+/// }
+///
+/// let word = Word { text: "Hello" };
+/// let owned: OwnedWord = borrowme::to_owned(&word);
+/// assert_eq!(owned.borrow_ref(), word);
 /// ```
 ///
+/// Note that there is no way to borrow *into* an existing borrowed variant to
+/// reuse it across repeated conversions. The lifetime of the borrowed variant
+/// is tied to `&self` through the [`Borrow::Target`] generic associated type,
+/// so a new value has to be constructed every time.
+///
 /// <br>
 ///
-/// #### `#[borrowme(std)]` container attribute
+/// #### `#[borrowme(bound = "<predicates>")]` container attribute
 ///
-/// This container attribute acts as if [`#[borrowme(std)]`][std] is applied to
-/// every field or variant in the container.
+/// The generated [`ToOwned`] and [`Borrow`] implementations keep the `where`
+/// clause of the container, and are bounded by whatever the fields which
+/// mention a type parameter need to be converted. Fields which are cloned
+/// require `Clone`, so a `BTreeSet<T>` field adds `BTreeSet<T>: Clone`.
 ///
-/// Note that this defeats copy and reference heuristics.
+/// This replaces those inferred bounds with the given predicates, which is
+/// needed when they can't be inferred, such as when a field is converted
+/// using functions with bounds of their own. Bounds needed by the *owned*
+/// variant itself are still added, and the predicates can't mention any
+/// lifetimes since the *owned* variant doesn't have them.
 ///
 /// ```
-/// use borrowme::borrowme;
+/// # use borrowme::borrowme;
+/// use std::collections::BTreeSet;
 ///
-/// #[borrowme]
-/// #[borrowme(std)]
-/// struct StdStruct<'a> {
-///     a: &'a String,
-///     #[borrowme(copy)]
-///     b: u32,
+/// fn to_strings<T: ToString>(items: &[T]) -> Vec<String> {
+///     items.iter().map(ToString::to_string).collect()
 /// }
 ///
-/// #[borrowme]
-/// #[borrowme(std)]
-/// enum StdEnum<'a> {
-///     Variant {
-///         a: &'a String,
-///         #[borrowme(copy)]
-///         b: u32,
-///     }
+/// fn parse<T: std::str::FromStr>(items: &[String]) -> Vec<T> {
+///     items.iter().filter_map(|item| item.parse().ok()).collect()
+/// }
+///
+/// #[borrowme(bound = "T: Clone + ToString + std::str::FromStr")]
+/// struct Labels<'a, T> where T: Ord {
+///     name: &'a str,
+///     #[borrowme(owned = Vec<String>, to_owned_with = to_strings, borrow_with = parse)]
+///     items: Vec<T>,
+///     sorted: BTreeSet<T>,
 /// }
+///
+/// let labels = Labels { name: "labels", items: vec![1, 2], sorted: BTreeSet::new() };
+/// let owned: OwnedLabels<u32> = borrowme::to_owned(&labels);
+/// assert_eq!(owned.items, ["1", "2"]);
 /// ```
 ///
 /// <br>
 ///
-/// #### `#[borrowme(name = <ident>)]` container attribute
+/// #### `#[borrowme(arbitrary)]` container attribute
 ///
-/// This allows you to pick the name to use for the generated type. By default
-/// this is the original identifier prefixed with `Owned`.
+/// Derives [`Arbitrary`] for the *owned* variant, which is useful for fuzzing
+/// and property testing since the *owned* variant doesn't have any lifetimes.
+/// Arbitrary borrowed values can then be produced by borrowing from it. This
+/// is the same as adding `#[owned_attr(derive(arbitrary::Arbitrary))]`, so it
+/// requires that the [`arbitrary`] crate is a dependency.
 ///
 /// ```
 /// # use borrowme::borrowme;
-/// #[borrowme(name = WordBuf)]
-/// #[derive(Debug, PartialEq)]
+/// use arbitrary::{Arbitrary, Unstructured};
+///
+/// #[borrowme(arbitrary)]
 /// struct Word<'a> {
 ///     text: &'a str,
 /// }
 ///
-/// let word = Word {
-///     text: "Hello World",
-/// };
+/// let mut u = Unstructured::new(b"\x05hello");
+/// let owned = OwnedWord::arbitrary(&mut u)?;
+/// let word: Word<'_> = borrowme::borrow(&owned);
+/// # let _ = word.text;
+/// # Ok::<_, arbitrary::Error>(())
+/// ```
 ///
-/// let word2 = WordBuf {
-///     text: String::from("Hello World"),
-/// };
+/// [`Arbitrary`]: https://docs.rs/arbitrary/1/arbitrary/trait.Arbitrary.html
+/// [`arbitrary`]: https://docs.rs/arbitrary
 ///
-/// assert_eq!(borrowme::to_owned(&word), word2);
+/// <br>
+///
+/// #### `#[borrowme(crate = <path>)]` container attribute
+///
+/// Changes the path used to refer to this crate in generated code, which by
+/// default is `::borrowme`. This is necessary if the crate is re-exported
+/// from another crate which is depended on instead, similarly to
+/// `#[serde(crate = "...")]`.
+///
+/// ```
+/// mod facade {
+///     pub mod reexport {
+///         pub use borrowme::*;
+///     }
+/// }
+///
+/// #[facade::reexport::borrowme(crate = facade::reexport)]
+/// struct Word<'a> {
+///     text: &'a str,
+/// }
+///
+/// let word: OwnedWord = facade::reexport::to_owned(&Word { text: "Hello" });
+/// assert_eq!(word.text, "Hello");
 /// ```
 ///
 /// <br>
@@ -338,6 +1477,11 @@
 /// Apply the given given `<meta>` as a container attribute, but only to the
 /// *owned* variant.
 ///
+/// Multiple `derive(..)` metas are merged into a single `#[derive(..)]`
+/// attribute, so `#[owned_attr(derive(Clone))]` followed by
+/// `#[owned_attr(derive(Debug))]` results in `#[derive(Clone, Debug)]`. The
+/// same applies to `#[borrowed_attr(..)]`.
+///
 /// ```
 /// # use borrowme::borrowme;
 /// #[borrowme]
@@ -354,6 +1498,45 @@
 /// assert_eq!(word.text, word2.text);
 /// ```
 ///
+/// Derives which are forwarded to both variants can be excluded from one of
+/// them using `no_derive(..)`. This is useful for derives such as `Copy` which
+/// only the *borrowed* variant can implement. Paths have to be spelled the same
+/// way as they are in the derive, so `no_derive(Copy)` doesn't exclude
+/// `derive(core::marker::Copy)`.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme]
+/// #[derive(Clone, Copy)]
+/// #[owned_attr(no_derive(Copy))]
+/// struct Word<'a> {
+///     text: &'a str,
+/// }
+///
+/// let word = Word { text: "Hello" };
+/// let copied = word;
+///
+/// let word: OwnedWord = borrowme::to_owned(&word);
+/// let word2 = word.clone();
+/// assert_eq!(word.text, word2.text);
+/// ```
+///
+/// Other attributes are forwarded to both variants, with the exception of
+/// `#[deprecated]` which is only kept on the *borrowed* variant since
+/// deprecating a borrowed API rarely means that its owned storage is
+/// deprecated. This also applies to variants and fields. Use
+/// `#[owned_attr(deprecated)]` to deprecate the *owned* variant as well.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme]
+/// #[deprecated = "use `Word` instead"]
+/// #[owned_attr(deprecated = "use `OwnedWord` instead")]
+/// struct OldWord<'a> {
+///     text: &'a str,
+/// }
+/// ```
+///
 /// <br>
 ///
 /// ## Variant attributes
@@ -450,10 +1633,31 @@
 /// * [`#[borrowme(to_owned_with = <path>)]`][to_owned_with],
 ///   [`#[borrowme(borrow_with = <path>)]`][borrow_with], and [`#[borrowme(with
 ///   = <path>)]`][with] which are used for customizing behavior.
+/// * [`#[borrowme(try_to_owned_with = <path>)]`][try_to_owned_with] which
+///   specifies a fallible conversion used by [`TryToOwned`].
+/// * [`#[borrowme(deref_borrow)]`][deref_borrow] which borrows the field
+///   through `Deref`.
+/// * [`#[borrowme(cow)]`][cow] which owns a reference as a `Cow<'static, T>`.
+/// * [`#[borrowme(borrow_cow)]`][borrow_cow] which borrows a reference as a
+///   `Cow<'a, T>`.
+/// * [`#[borrowme(collect)]`][collect] which converts a custom collection
+///   element-wise.
+/// * [`#[borrowme(owned_collect = <type>)]`][owned_collect] which owns a
+///   collection as a different type by collecting its elements.
+/// * [`#[borrowme(or_default)]`][or_default] which owns an option as its owned
+///   value, which is the default value if it's missing.
+/// * [`#[borrowme(skip)]`][skip] which constructs the field using `Default`
+///   instead of converting it.
+/// * [`#[borrowme(hash)]` and `#[borrowme(no_hash)]`][owned_hash] which select
+///   the fields used by `#[borrowme(owned_hash)]`.
 /// * [`#[copy]` and `#[no_copy]`][copy] which is used to indicate if a field is
 ///   `Copy` and does not require conversion.
 /// * [`#[borrowme(std)]`][std] which indicates that the field supports std-like
 ///   operations.
+/// * [`#[borrowme(clone_only)]`][clone_only] which owns the field as its own
+///   type using `Clone`, while still borrowing it through [`Borrow`].
+/// * [`#[borrowme(owned_vis = <visibility>)]`][owned_vis] which overrides the
+///   visibility of the field in the *owned* variant.
 /// * [`#[borrowed_attr(<meta>)]`][b-f] and [`#[owned_attr(<meta>)]`][o-f] which
 ///   are used to add custom attributes.
 ///
@@ -493,6 +1697,46 @@
 /// }
 /// ```
 ///
+/// Trait objects which capture lifetimes, such as
+/// `Box<dyn Fn(&'a str) -> bool + 'a>`, can't be converted automatically and
+/// always require an owned type to be specified. The signature of the trait
+/// object is left as-is in the borrowed variant.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// type Filter = Box<dyn Fn(&str) -> bool>;
+///
+/// #[borrowme]
+/// pub struct Matcher<'a> {
+///     #[borrowme(owned = Filter, to_owned_with = to_owned_filter, borrow_with = borrow_filter)]
+///     filter: Box<dyn Fn(&'a str) -> bool + 'a>,
+/// }
+///
+/// fn to_owned_filter<'a>(_: &(dyn Fn(&'a str) -> bool + 'a)) -> Filter {
+///     Box::new(|_| true)
+/// }
+///
+/// fn borrow_filter(filter: &Filter) -> Box<dyn Fn(&str) -> bool + '_> {
+///     Box::new(move |value| filter(value))
+/// }
+/// ```
+///
+/// The owned type can also be a macro invocation, which is passed through
+/// verbatim to the owned variant.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// macro_rules! owned_text {
+///     () => { Box<str> };
+/// }
+///
+/// #[borrowme]
+/// pub struct Word<'a> {
+///     #[borrowme(owned = owned_text!(), to_owned_with = Box::from, borrow_with = AsRef::as_ref)]
+///     text: &'a str,
+/// }
+/// ```
+///
 /// <br>
 ///
 /// #### `#[borrowme(mut)]` field attribute
@@ -561,38 +1805,346 @@
 ///     lang2: Option<&'a str>,
 /// }
 ///
-/// #[inline]
-/// pub(crate) fn option_borrow(option: &Option<String>) -> Option<&str> {
-///     option.as_deref()
-/// }
+/// #[inline]
+/// pub(crate) fn option_borrow(option: &Option<String>) -> Option<&str> {
+///     option.as_deref()
+/// }
+/// ```
+///
+/// This is also how a clone-on-write [`CompoundCow`] over compound types is
+/// borrowed back, using [`CompoundCow::borrowed`].
+///
+/// <br>
+///
+/// #### `#[borrowme(borrow_mut_with = <path>)]` field attribute
+///
+/// Using this implies `#[borrowme(mut)]`.
+///
+/// Specifies a path to use when borrowing a mutable field. By default this is:
+/// * A borrowed `&mut self.<field>` if `#[borrowme(std)]` is specified and a
+///   mutable field is detected or specified with `#[borrowme(mut)]`.
+/// * An owned `self.<field>` expression if `#[copy]` is specified.
+/// * `::borrowme::BorrowMut::borrow_mut`.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme]
+/// pub struct Word<'a> {
+///     #[borrowme(owned = Option<String>, borrow_mut_with = option_borrow)]
+///     lang: Option<&'a mut str>,
+///     #[borrowme(owned = Option<String>, borrow_mut_with = Option::as_deref_mut)]
+///     lang2: Option<&'a mut str>,
+/// }
+///
+/// #[inline]
+/// pub(crate) fn option_borrow(option: &mut Option<String>) -> Option<&mut str> {
+///     option.as_deref_mut()
+/// }
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(try_to_owned_with = <path>)]` field attribute
+///
+/// Specifies a fallible path to use when making a field owned through
+/// [`TryToOwned`], which returns a `Result` whose error is converted into the
+/// container [`#[borrowme(error = <type>)]`][error] using `?`. This causes
+/// [`TryToOwned`] to be implemented for the container.
+///
+/// This only affects [`TryToOwned`], [`ToOwned`] keeps using the conversion
+/// specified through `to_owned_with` or its default. Fields without this
+/// attribute are converted the same way in both traits.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme(error = String)]
+/// pub struct Word<'a> {
+///     #[borrowme(try_to_owned_with = non_empty)]
+///     text: &'a str,
+///     lang: &'a str,
+/// }
+///
+/// pub(crate) fn non_empty(text: &str) -> Result<String, String> {
+///     if text.is_empty() {
+///         return Err(String::from("empty text"));
+///     }
+///
+///     Ok(text.to_owned())
+/// }
+///
+/// let word = Word { text: "", lang: "en" };
+/// assert!(borrowme::try_to_owned(&word).is_err());
+///
+/// let word: OwnedWord = borrowme::to_owned(&word);
+/// assert_eq!(word.text, "");
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(deref_borrow)]` field attribute
+///
+/// Borrows the field by dereferencing the owned value, as in
+/// `&*self.<field>`, or `&mut *self.<field>` if the field is mutable. This
+/// works for any owned type which implements `Deref` (or `DerefMut`) with the
+/// borrowed type as its target, without having to name a `borrow_with`
+/// function.
+///
+/// This can't be combined with `borrow_with` or `borrow_mut_with`.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme]
+/// pub struct Word<'a> {
+///     #[borrowme(owned = Box<str>, to_owned_with = Box::from, deref_borrow)]
+///     text: &'a str,
+/// }
+///
+/// let word = Word { text: "Hello" };
+/// let owned: OwnedWord = borrowme::to_owned(&word);
+/// assert_eq!(borrowme::borrow(&owned).text, "Hello");
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(cow)]` field attribute
+///
+/// Owns a reference such as `&'a str` as a `Cow<'static, str>` instead of a
+/// `String`. Converting to owned always produces `Cow::Owned`, but the owned
+/// variant can also be constructed with `Cow::Borrowed` of static data. This
+/// requires the `alloc` feature.
+///
+/// This can't be combined with other attributes specifying how the field is
+/// converted, such as `owned` or `borrow_with`.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// use std::borrow::Cow;
+///
+/// #[borrowme]
+/// pub struct Word<'a> {
+///     #[borrowme(cow)]
+///     text: &'a str,
+/// }
+///
+/// let word = OwnedWord { text: Cow::Borrowed("Hello") };
+/// assert_eq!(borrowme::borrow(&word).text, "Hello");
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(borrow_cow)]` field attribute
+///
+/// Changes the type of a reference such as `&'a str` in the *borrowed* variant
+/// into a `Cow<'a, str>`, which is owned as a `String`. Borrowing always
+/// produces `Cow::Borrowed`, while the borrowed variant can also hold a
+/// `Cow::Owned` value which had to be allocated when it was constructed, such
+/// as text which needed to be unescaped. This requires the `alloc` feature.
+///
+/// This can't be combined with other attributes specifying how the field is
+/// converted, such as `owned` or `borrow_with`.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// use std::borrow::Cow;
+///
+/// #[borrowme]
+/// pub struct Word<'a> {
+///     #[borrowme(borrow_cow)]
+///     text: &'a str,
+/// }
+///
+/// let word = Word { text: Cow::Owned(String::from("Hello")) };
+/// let owned: OwnedWord = borrowme::to_owned(&word);
+/// assert_eq!(owned.text, "Hello");
+///
+/// let word = borrowme::borrow(&owned);
+/// assert!(matches!(word.text, Cow::Borrowed("Hello")));
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(collect)]` field attribute
+///
+/// Converts a collection element-wise through its [`Collect`] implementation,
+/// so that a field such as `Stack<&'a str>` is owned as a `Stack<String>` and
+/// borrowed back into a new `Stack<&str>`. The converted collection is
+/// constructed with capacity for every element up front.
+///
+/// This allows custom collections to be used without having to specify how
+/// each field is converted, and it can't be combined with other attributes
+/// specifying how the field is converted.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// use std::collections::VecDeque;
+///
+/// #[borrowme]
+/// pub struct Queue<'a> {
+///     #[borrowme(collect)]
+///     items: VecDeque<&'a str>,
+/// }
+///
+/// let queue = Queue { items: VecDeque::from(vec!["Hello", "World"]) };
+/// let owned: OwnedQueue = borrowme::to_owned(&queue);
+/// assert_eq!(owned.items[0], "Hello");
+/// assert_eq!(borrowme::borrow(&owned).items[1], "World");
+/// ```
+///
+/// [`Collect`]: crate::collect::Collect
+///
+/// <br>
+///
+/// #### `#[borrowme(owned_collect = <type>)]` field attribute
+///
+/// Owns a collection as the specified type, such as a `Vec<Word<'a>>` as a
+/// `SmallVec<[OwnedWord; 4]>`, by converting each element and collecting them
+/// through [`FromIterator`]. So this generates the following for the field:
+///
+/// ```text
+/// // to_owned
+/// (&self.words).into_iter().map(ToOwned::to_owned).collect()
+/// // borrow
+/// (&self.words).into_iter().map(Borrow::borrow).collect()
+/// ```
+///
+/// A slice such as `&'a [u32]` can't be collected into, so it's instead
+/// borrowed by dereferencing the owned collection. This means that the owned
+/// collection has to dereference into the slice, like `Box<[u32]>` or
+/// `Rc<[u32]>` do. Slices of elements with lifetimes such as `&'a [Word<'a>]`
+/// can't be borrowed back at all, so they have to use a collection instead.
+///
+/// This can't be combined with other attributes specifying how the field is
+/// converted.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// use std::collections::VecDeque;
+/// use std::rc::Rc;
+///
+/// #[borrowme]
+/// pub struct Word<'a> {
+///     text: &'a str,
+/// }
+///
+/// #[borrowme]
+/// pub struct Sentence<'a> {
+///     #[borrowme(owned_collect = VecDeque<OwnedWord>)]
+///     words: Vec<Word<'a>>,
+///     #[borrowme(owned_collect = Rc<[u32]>)]
+///     counts: &'a [u32],
+/// }
+///
+/// let sentence = Sentence {
+///     words: vec![Word { text: "hello" }],
+///     counts: &[1, 2],
+/// };
+///
+/// let owned: OwnedSentence = borrowme::to_owned(&sentence);
+/// assert_eq!(owned.words[0].text, "hello");
+///
+/// let sentence = borrowme::borrow(&owned);
+/// assert_eq!(sentence.words[0].text, "hello");
+/// assert_eq!(sentence.counts, &[1, 2]);
+/// ```
+///
+/// [`FromIterator`]: core::iter::FromIterator
+///
+/// <br>
+///
+/// #### `#[borrowme(or_default)]` field attribute
+///
+/// Owns an option such as `Option<&'a str>` as the owned value of its argument,
+/// such as a `String`, which is the [`Default`] value if the option is missing.
+/// Borrowing it back produces `None` if the owned value is equal to its default
+/// value, so `Some("")` is owned as an empty string which is borrowed back as
+/// `None`.
+///
+/// The *owned* type can be overridden using [`#[owned(<type>)]`][owned], as long
+/// as the argument of the option owns into it. It has to implement [`Default`]
+/// and [`PartialEq`]. This can't be combined with other attributes specifying
+/// how the field is converted.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme]
+/// pub struct Word<'a> {
+///     #[borrowme(or_default)]
+///     lang: Option<&'a str>,
+///     #[borrowme(owned = String, or_default)]
+///     text: Option<&'a str>,
+/// }
+///
+/// let word = Word {
+///     lang: None,
+///     text: Some("hello"),
+/// };
+///
+/// let owned: OwnedWord = borrowme::to_owned(&word);
+/// assert_eq!(owned.lang, "");
+/// assert_eq!(owned.text, "hello");
+///
+/// let word = borrowme::borrow(&owned);
+/// assert_eq!(word.lang, None);
+/// assert_eq!(word.text, Some("hello"));
 /// ```
 ///
 /// <br>
 ///
-/// #### `#[borrowme(borrow_mut_with = <path>)]` field attribute
+/// #### `#[borrowme(skip)]` field attribute
 ///
-/// Using this implies `#[borrowme(mut)]`.
+/// Skips converting the field, which is instead constructed using [`Default`]
+/// in both directions. This is useful for fields such as caches which are
+/// derived from the rest of the value. The *owned* type of the field is the
+/// same with its lifetimes replaced by `'static`, unless it's specified using
+/// [`#[owned(<type>)]`][owned].
 ///
-/// Specifies a path to use when borrowing a mutable field. By default this is:
-/// * A borrowed `&mut self.<field>` if `#[borrowme(std)]` is specified and a
-///   mutable field is detected or specified with `#[borrowme(mut)]`.
-/// * An owned `self.<field>` expression if `#[copy]` is specified.
-/// * `::borrowme::BorrowMut::borrow_mut`.
+/// The field has to implement [`Default`], and this can't be combined with
+/// other attributes specifying how the field is converted.
 ///
 /// ```
 /// # use borrowme::borrowme;
+/// use std::cell::RefCell;
+/// use std::collections::HashMap;
+///
 /// #[borrowme]
-/// pub struct Word<'a> {
-///     #[borrowme(owned = Option<String>, borrow_mut_with = option_borrow)]
-///     lang: Option<&'a mut str>,
-///     #[borrowme(owned = Option<String>, borrow_mut_with = Option::as_deref_mut)]
-///     lang2: Option<&'a mut str>,
+/// struct Parsed<'a> {
+///     tokens: Vec<&'a str>,
+///     #[borrowme(skip)]
+///     cache: RefCell<HashMap<usize, String>>,
 /// }
 ///
-/// #[inline]
-/// pub(crate) fn option_borrow(option: &mut Option<String>) -> Option<&mut str> {
-///     option.as_deref_mut()
+/// let parsed = Parsed {
+///     tokens: vec!["hello"],
+///     cache: RefCell::new(HashMap::new()),
+/// };
+///
+/// parsed.cache.borrow_mut().insert(0, String::from("hello"));
+///
+/// let owned: OwnedParsed = borrowme::to_owned(&parsed);
+/// assert_eq!(owned.tokens, ["hello"]);
+/// assert!(owned.cache.borrow().is_empty());
+/// ```
+///
+/// Fields which should be kept identical in both variants don't need to be
+/// skipped. A field without lifetimes such as an `Arc<Mutex<State>>` is
+/// already cloned as-is in both directions, so the same state is shared by the
+/// *owned* and *borrowed* variants instead of being reset. This also differs
+/// from [`#[borrowme(std)]`][std], which clones the referenced value of a
+/// field such as `&'a T` into an owned `T` and borrows it back as a reference.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// use std::sync::{Arc, Mutex};
+///
+/// #[borrowme]
+/// struct Request<'a> {
+///     path: &'a str,
+///     cache: Arc<Mutex<Vec<String>>>,
 /// }
+///
+/// let request = Request { path: "/", cache: Arc::default() };
+/// let owned: OwnedRequest = borrowme::to_owned(&request);
+/// assert!(Arc::ptr_eq(&owned.cache, &request.cache));
 /// ```
 ///
 /// <br>
@@ -602,6 +2154,9 @@
 /// Specifies a path to use when calling `to_owned` and `borrow` on a field.
 ///
 /// The sets `to_owned` to `<path>::to_owned`, and `borrow` to `<path>::borrow`.
+/// If the field is borrowed mutably, `borrow_mut` is set to
+/// `<path>::borrow_mut`. So modules which are only used for immutable fields
+/// don't need to define it.
 ///
 /// Unless `#[copy]` or `#[borrowme(std)]` are specified, these are by
 /// default:
@@ -677,13 +2232,42 @@
 /// * `u8`, `u16`, `u32`, `u64`, `u128`, and `usize`.
 /// * `i8`, `i16`, `i32`, `i64`, `i128`, and `isize`.
 /// * `f32` and `f64`.
-/// * `bool`.
+/// * `bool` and `char`.
+/// * Non-zero integers such as `NonZeroU32` or `NonZeroI64`, either by their
+///   bare name or through `core::num` or `std::num`.
+/// * The zero-sized `PhantomPinned` marker.
 /// * Tuple types `(A, B, ..)` for which all of its elements look like they are
 ///   copy.
 /// * Array types `[T; N]` for which the element `T` looks like they are copy.
+/// * `Option<T>` for which `T` looks like it is copy, such as `Option<u32>` or
+///   `Option<&'static str>`.
+///
+/// Markers such as `PhantomData<&'a T>` are constructed anew in both
+/// directions, and any lifetimes they capture are erased in the *owned*
+/// variant. References are replaced with the types in [`marker`], so that
+/// `PhantomData<&'a T>` is owned as `PhantomData<marker::Ref<T>>` which has the
+/// same auto traits as the reference. This means that a struct which only has copy fields and a marker
+/// for its lifetime is supported:
+///
+/// ```
+/// # use borrowme::borrowme;
+/// use core::marker::PhantomData;
+///
+/// #[borrowme]
+/// pub struct Point<'a> {
+///     _marker: PhantomData<&'a ()>,
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// let point = OwnedPoint { _marker: PhantomData, x: 1, y: 2 };
+/// let borrowed: Point<'_> = borrowme::borrow(&point);
+/// assert_eq!(borrowed.x, 1);
+/// ```
 ///
 /// This heuristic can be defeated in a handful of ways, depending on what best
-/// suits your needs.
+/// suits your needs. It can also be disabled for an entire container using
+/// [`#[borrowme(no_heuristics)]`][no_heuristics].
 ///
 /// A field can specify that the type is not `Copy` using `#[no_copy]`, which
 /// makes it fall back to the default behavior:
@@ -779,6 +2363,68 @@
 ///
 /// <br>
 ///
+/// #### `#[borrowme(clone_only)]` field attribute
+///
+/// Owns the field as its own type using [`Clone`], ignoring any [`ToOwned`]
+/// implementation it might have. Unlike [`#[borrowme(std)]`][std] the field is
+/// still borrowed through [`Borrow`], so this is intended for types which are
+/// their own borrow target. Since the type of the field is used as-is in the
+/// *owned* variant, it can't have any lifetimes.
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[derive(Clone)]
+/// struct Id(u32);
+///
+/// impl borrowme::Borrow for Id {
+///     type Target<'a> = Id;
+///
+///     fn borrow(&self) -> Id {
+///         Id(self.0)
+///     }
+/// }
+///
+/// #[borrowme]
+/// pub struct Word<'a> {
+///     text: &'a str,
+///     #[borrowme(clone_only)]
+///     id: Id,
+/// }
+///
+/// let word = Word { text: "Hello", id: Id(1) };
+/// let owned: OwnedWord = borrowme::to_owned(&word);
+/// assert_eq!(owned.id.0, 1);
+/// assert_eq!(borrowme::borrow(&owned).id.0, 1);
+/// ```
+///
+/// <br>
+///
+/// #### `#[borrowme(owned_vis = <visibility>)]` field attribute
+///
+/// Overrides the visibility of the field in the *owned* variant, while the
+/// field in the *borrowed* variant keeps the visibility it was declared with.
+///
+/// This is useful when the borrowed variant is part of a public API, but the
+/// owned variant has invariants which should only be maintained internally.
+///
+/// ```
+/// mod word {
+///     use borrowme::borrowme;
+///
+///     #[borrowme]
+///     pub struct Word<'a> {
+///         #[borrowme(owned_vis = pub(crate))]
+///         pub text: &'a str,
+///     }
+/// }
+///
+/// let word = word::Word { text: "Hello" };
+/// let owned: word::OwnedWord = borrowme::to_owned(&word);
+/// assert_eq!(owned.text, "Hello");
+/// ```
+///
+/// <br>
+///
 /// #### `#[borrowed_attr(<meta>)]` field attribute
 ///
 /// Apply the given `<meta>` as a field attribute, but only for the *borrowed*
@@ -797,6 +2443,28 @@
 /// }
 /// ```
 ///
+/// This composes with attributes which apply to both variants, such as a
+/// flattened map where only the borrowed variant borrows from the input:
+///
+/// ```
+/// # use borrowme::borrowme;
+/// use std::collections::BTreeMap;
+/// use serde::{Serialize, Deserialize};
+///
+/// #[borrowme]
+/// #[derive(Serialize, Deserialize)]
+/// pub struct Record<'a> {
+///     name: &'a str,
+///     #[serde(flatten)]
+///     #[borrowed_attr(serde(borrow))]
+///     extra: BTreeMap<&'a str, &'a str>,
+/// }
+/// ```
+///
+/// It can also be combined with [`#[owned_attr(<meta>)]`][o-f] on the same
+/// field, such as to use different `#[serde(with = "...")]` modules for the
+/// *borrowed* and *owned* variants since their field types differ.
+///
 /// <br>
 ///
 /// #### `#[owned_attr(<meta>)]` field attribute
@@ -819,25 +2487,217 @@
 /// }
 /// ```
 ///
+/// [arbitrary]: #borrowmearbitrary-container-attribute
 /// [b-c]: #borrowed_attrmeta-container-attribute
 /// [b-f]: #borrowed_attrmeta-field-attribute
 /// [b-v]: #borrowed_attrmeta-variant-attribute
+/// [borrow_cow]: #borrowmeborrow_cow-field-attribute
+/// [borrow_ref]: #borrowmeborrow_ref-container-attribute
+/// [bound]: #borrowmebound--predicates-container-attribute
+/// [boxed_slices]: #borrowmeboxed_slices-container-attribute
 /// [borrow_with]: #borrowmeborrow_with--path-field-attribute
+/// [clone_only]: #borrowmeclone_only-field-attribute
+/// [crate]: #borrowmecrate--path-container-attribute
 /// [container-std]: #borrowmestd-container-attribute
+/// [container-with]: #borrowmewith--path-container-attribute
+/// [collect]: #borrowmecollect-field-attribute
+/// [convert]: #t-where-t-is-marked-with-borrowmeconvert
 /// [copy]: #copy-and-no_copy-field-attribute
+/// [cow]: #borrowmecow-field-attribute
+/// [debug]: #borrowmedebug-container-attribute
+/// [deref_borrow]: #borrowmederef_borrow-field-attribute
+/// [error]: #borrowmeerror--type-container-attribute
+/// [from]: #borrowmefrom-container-attribute
+/// [inline]: #borrowmeinline--alwaysnever-container-attribute
+/// [leak]: #borrowmeleak-container-attribute
+/// [map_type]: #borrowmemap_typetype--type-container-attribute
 /// [mut]: #borrowmemut-field-attribute
+/// [mut_view]: #borrowmemut_view--ident-container-attribute
 /// [name]: #borrowmename--ident-container-attribute
+/// [no_heuristics]: #borrowmeno_heuristics-container-attribute
 /// [o-c]: #owned_attrmeta-container-attribute
 /// [o-f]: #owned_attrmeta-field-attribute
 /// [o-v]: #owned_attrmeta-variant-attribute
+/// [or_default]: #borrowmeor_default-field-attribute
 /// [owned]: #ownedtype-or-borrowmeowned--type-field-attributes
+/// [owned_collect]: #borrowmeowned_collect--type-field-attribute
+/// [owned_hash]: #borrowmeowned_hash-container-attribute
+/// [owned_static]: #borrowmeowned_static-container-attribute
+/// [owned_vis]: #borrowmeowned_vis--visibility-field-attribute
+/// [serde]: #borrowmeserde-container-attribute
+/// [skip]: #borrowmeskip-field-attribute
+/// [static_variant]: #borrowmestatic_variant--ident-container-attribute
 /// [std]: #borrowmestd-field-attribute
+/// [std_borrow]: #borrowmestd_borrow-container-attribute
 /// [to_owned_with]: #borrowmeto_owned_with--path-field-attribute
+/// [try_to_owned_with]: #borrowmetry_to_owned_with--path-field-attribute
 /// [variant-std]: #borrowmestd-variant-attribute
 /// [with]: #borrowmewith--path-field-attribute
 #[doc(inline)]
 pub use borrowme_macros::borrowme;
 
+/// Implement [`ToOwned`] and [`Borrow`] for an existing pair of types.
+///
+/// This is useful when both the *borrowed* and the *owned* type are already
+/// written by hand, so that [`#[borrowme]`][macro@borrowme] can't be used to
+/// generate the owned type. Every field of the borrowed type has to be listed,
+/// and they are converted using [`ToOwned`] and [`Borrow`] unless otherwise
+/// specified.
+///
+/// The owned type has the same generic parameters as the borrowed type except
+/// for its lifetimes.
+///
+/// Each field can specify how it's converted using one or more options, with
+/// multiple options wrapped in parenthesis. Conversion functions receive a
+/// reference to the field, so a `&'a str` field is passed in as a `&&str`.
+/// * `copy` - the field is copied in both directions.
+/// * `to_owned_with = <path>` - see [`#[borrowme(to_owned_with = <path>)]`][to_owned_with].
+/// * `borrow_with = <path>` - see [`#[borrowme(borrow_with = <path>)]`][borrow_with].
+/// * `with = <path>` - see [`#[borrowme(with = <path>)]`][with].
+///
+/// ```
+/// struct Word<'a> {
+///     text: &'a str,
+///     lang: Option<&'a str>,
+///     count: u32,
+/// }
+///
+/// struct OwnedWord {
+///     text: String,
+///     lang: Option<Box<str>>,
+///     count: u32,
+/// }
+///
+/// borrowme::impl_borrow! {
+///     Word<'a> => OwnedWord {
+///         text,
+///         lang: (to_owned_with = lang_to_owned, borrow_with = lang_borrow),
+///         count: copy,
+///     }
+/// }
+///
+/// fn lang_to_owned(lang: &Option<&str>) -> Option<Box<str>> {
+///     lang.map(Box::from)
+/// }
+///
+/// fn lang_borrow(lang: &Option<Box<str>>) -> Option<&str> {
+///     lang.as_deref()
+/// }
+///
+/// let word = Word { text: "hello", lang: Some("en"), count: 42 };
+/// let owned: OwnedWord = borrowme::to_owned(&word);
+/// assert_eq!(owned.text, "hello");
+/// assert_eq!(owned.lang.as_deref(), Some("en"));
+///
+/// let word: Word<'_> = borrowme::borrow(&owned);
+/// assert_eq!(word.count, 42);
+/// ```
+///
+/// The path used to refer to this crate can be changed with a leading
+/// `#[borrowme(crate = <path>)]` attribute, see [`#[borrowme(crate =
+/// <path>)]`][crate].
+///
+/// ```
+/// mod facade {
+///     pub mod reexport {
+///         pub use borrowme::*;
+///     }
+/// }
+///
+/// struct Word<'a> {
+///     text: &'a str,
+/// }
+///
+/// struct OwnedWord {
+///     text: String,
+/// }
+///
+/// facade::reexport::impl_borrow! {
+///     #[borrowme(crate = facade::reexport)]
+///     Word<'a> => OwnedWord { text }
+/// }
+///
+/// let owned: OwnedWord = facade::reexport::to_owned(&Word { text: "hello" });
+/// assert_eq!(owned.text, "hello");
+/// ```
+///
+/// [to_owned_with]: crate::borrowme#borrowmeto_owned_with--path-field-attribute
+/// [borrow_with]: crate::borrowme#borrowmeborrow_with--path-field-attribute
+/// [with]: crate::borrowme#borrowmewith--path-field-attribute
+/// [crate]: crate::borrowme#borrowmecrate--path-container-attribute
+#[doc(inline)]
+pub use borrowme_macros::impl_borrow;
+
+/// Derive [`ToOwned`][trait@ToOwned] for a hand-written *borrowed* type.
+///
+/// This is useful when both types are maintained by hand, so that only the
+/// trait implementations have to be generated. The *owned* type to convert
+/// into is named using the `#[to_owned(target = <type>)]` attribute, and every
+/// field is converted using [`ToOwned`][trait@ToOwned] into the field with the
+/// same name in the target unless otherwise specified.
+///
+/// Fields can specify how they are converted using `#[to_owned(<option>)]`:
+/// * `copy` - the field is copied.
+/// * `to_owned_with = <path>` - see [`#[borrowme(to_owned_with = <path>)]`][to_owned_with].
+///
+/// The path used to refer to this crate can be changed using `#[to_owned(crate
+/// = <path>)]`, see [`#[borrowme(crate = <path>)]`][crate].
+///
+/// See [`Borrow`][derive@Borrow] for the other direction.
+///
+/// ```
+/// use borrowme::{Borrow, ToOwned};
+///
+/// #[derive(ToOwned)]
+/// #[to_owned(target = OwnedWord)]
+/// struct Word<'a> {
+///     text: &'a str,
+///     #[to_owned(copy)]
+///     count: u32,
+/// }
+///
+/// #[derive(Borrow)]
+/// #[borrow(target = Word<'a>)]
+/// struct OwnedWord {
+///     text: String,
+///     #[borrow(copy)]
+///     count: u32,
+/// }
+///
+/// let word = Word { text: "hello", count: 42 };
+/// let owned: OwnedWord = borrowme::to_owned(&word);
+/// assert_eq!(owned.text, "hello");
+///
+/// let word: Word<'_> = borrowme::borrow(&owned);
+/// assert_eq!(word.count, 42);
+/// ```
+///
+/// [to_owned_with]: crate::borrowme#borrowmeto_owned_with--path-field-attribute
+/// [crate]: crate::borrowme#borrowmecrate--path-container-attribute
+#[doc(inline)]
+pub use borrowme_macros::ToOwned;
+
+/// Derive [`Borrow`][trait@Borrow] for a hand-written *owned* type.
+///
+/// The *borrowed* type to borrow as is named using the `#[borrow(target =
+/// <type>)]` attribute, where every lifetime is replaced with the lifetime of
+/// the borrow. Every field is converted using [`Borrow`][trait@Borrow] into
+/// the field with the same name in the target unless otherwise specified.
+///
+/// Fields can specify how they are converted using `#[borrow(<option>)]`:
+/// * `copy` - the field is copied.
+/// * `borrow_with = <path>` - see [`#[borrowme(borrow_with = <path>)]`][borrow_with].
+///
+/// The path used to refer to this crate can be changed using `#[borrow(crate =
+/// <path>)]`, see [`#[borrowme(crate = <path>)]`][crate].
+///
+/// See [`ToOwned`][derive@ToOwned] for an example.
+///
+/// [borrow_with]: crate::borrowme#borrowmeborrow_with--path-field-attribute
+/// [crate]: crate::borrowme#borrowmecrate--path-container-attribute
+#[doc(inline)]
+pub use borrowme_macros::Borrow;
+
 mod borrow;
 pub use self::borrow::Borrow;
 
@@ -847,6 +2707,30 @@ pub use self::borrow_mut::BorrowMut;
 mod to_owned;
 pub use self::to_owned::ToOwned;
 
+mod try_to_owned;
+pub use self::try_to_owned::TryToOwned;
+
+mod compound_cow;
+pub use self::compound_cow::CompoundCow;
+
+#[cfg(feature = "alloc")]
+pub mod boxed;
+
+pub mod collect;
+
+#[cfg(feature = "alloc")]
+pub mod cow;
+
+pub mod marker;
+
+pub mod or_default;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "alloc")]
+pub mod util;
+
 /// Convert a value to owned.
 ///
 /// This helper function is provided so that you don't have to have the
@@ -856,6 +2740,18 @@ pub use self::to_owned::ToOwned;
 /// Using this also prevents conflicts with the built-in
 /// [`std::borrow::ToOwned`] which is in the prelude.
 ///
+/// Since [`ToOwned`] is implemented for references, unsized values such as
+/// `str` and `[T]` can be converted directly through a reference to them
+/// without any additional indirection.
+///
+/// ```
+/// let text: String = borrowme::to_owned("Hello");
+/// assert_eq!(text, "Hello");
+///
+/// let values: Vec<u32> = borrowme::to_owned(&[1u32, 2, 3][..]);
+/// assert_eq!(values, [1, 2, 3]);
+/// ```
+///
 /// <br>
 ///
 /// # Examples
@@ -906,6 +2802,83 @@ where
     value.to_owned()
 }
 
+/// Convert every element in a slice to owned, replacing the contents of `out`.
+///
+/// The vector is cleared before the converted elements are pushed to it, so
+/// its allocation can be reused across conversions in hot loops instead of
+/// allocating a new vector through [`to_owned()`] every time.
+///
+/// <br>
+///
+/// # Examples
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme]
+/// struct Word<'a> {
+///     text: &'a str,
+/// }
+///
+/// let mut out = Vec::new();
+///
+/// for line in ["Hello World", "Goodbye World"] {
+///     let words = line.split(' ').map(|text| Word { text }).collect::<Vec<_>>();
+///     borrowme::to_owned_extend(&words, &mut out);
+///     assert_eq!(out.len(), 2);
+/// }
+///
+/// assert_eq!(out[0].text, "Goodbye");
+/// assert_eq!(out[1].text, "World");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn to_owned_extend<T>(values: &[T], out: &mut alloc::vec::Vec<T::Owned>)
+where
+    T: ToOwned,
+{
+    out.clear();
+    out.extend(values.iter().map(ToOwned::to_owned));
+}
+
+/// Fallibly convert a value to owned.
+///
+/// This helper function is provided so that you don't have to have the
+/// [`TryToOwned`] trait in scope.
+///
+/// <br>
+///
+/// # Examples
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme(error = String)]
+/// struct Word<'a> {
+///     #[borrowme(try_to_owned_with = non_empty)]
+///     text: &'a str,
+/// }
+///
+/// fn non_empty(text: &str) -> Result<String, String> {
+///     if text.is_empty() {
+///         return Err(String::from("empty text"));
+///     }
+///
+///     Ok(text.to_owned())
+/// }
+///
+/// let word: OwnedWord = borrowme::try_to_owned(Word { text: "Hello" })?;
+/// assert_eq!(word.text, "Hello");
+///
+/// assert!(borrowme::try_to_owned(Word { text: "" }).is_err());
+/// # Ok::<_, String>(())
+/// ```
+#[inline]
+pub fn try_to_owned<T>(value: T) -> Result<T::Owned, T::Error>
+where
+    T: TryToOwned,
+{
+    value.try_to_owned()
+}
+
 /// Borrow from the given value.
 ///
 /// This helper function is provided so that you don't have to have the
@@ -1020,3 +2993,70 @@ where
 {
     value.borrow_mut()
 }
+
+/// Borrow from the given value for the `'static` lifetime by leaking it.
+///
+/// The value is moved into a [`Box`] which is leaked, so its memory is never
+/// reclaimed. This is only intended for values which live for the remainder
+/// of the program anyway, such as configuration which is loaded once.
+///
+/// <br>
+///
+/// # Examples
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme]
+/// struct Word<'a> {
+///     text: &'a str,
+/// }
+///
+/// let word = OwnedWord {
+///     text: String::from("Hello"),
+/// };
+///
+/// let word: Word<'static> = borrowme::leak(word);
+/// assert_eq!(word.text, "Hello");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn leak<T>(value: T) -> T::Target<'static>
+where
+    T: 'static + Borrow,
+{
+    alloc::boxed::Box::leak(alloc::boxed::Box::new(value)).borrow()
+}
+
+/// Borrow mutably from the given value for the `'static` lifetime by leaking
+/// it.
+///
+/// The value is moved into a [`Box`] which is leaked, so its memory is never
+/// reclaimed. See [`leak()`] for more details.
+///
+/// <br>
+///
+/// # Examples
+///
+/// ```
+/// # use borrowme::borrowme;
+/// #[borrowme]
+/// struct Word<'a> {
+///     text: &'a mut String,
+/// }
+///
+/// let word = OwnedWord {
+///     text: String::from("Hello"),
+/// };
+///
+/// let word: Word<'static> = borrowme::leak_mut(word);
+/// word.text.push_str(" World");
+/// assert_eq!(word.text, "Hello World");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn leak_mut<T>(value: T) -> T::TargetMut<'static>
+where
+    T: 'static + BorrowMut,
+{
+    alloc::boxed::Box::leak(alloc::boxed::Box::new(value)).borrow_mut()
+}
@@ -0,0 +1,72 @@
+//! Types which stand in for references in markers such as `PhantomData<&'a T>`,
+//! which is owned as `PhantomData<Ref<T>>` since the *owned* variant can't
+//! capture the lifetime.
+//!
+//! These are never constructed, and only serve to preserve the variance and
+//! the auto traits of the reference they replace.
+
+use core::marker::PhantomData;
+
+/// Stands in for `&'a T` in the *owned* variant of a marker.
+///
+/// Like `&'a T` this is covariant over `T`, and it's `Send` and `Sync` if `T`
+/// is `Sync`.
+///
+/// ```
+/// use core::cell::Cell;
+/// use core::marker::PhantomData;
+///
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// pub struct Marked<'a, T> {
+///     _marker: PhantomData<&'a T>,
+/// }
+///
+/// fn is_send<T: Send>() {}
+///
+/// let owned: OwnedMarked<Cell<u8>> = OwnedMarked {
+///     _marker: PhantomData,
+/// };
+///
+/// let _: PhantomData<borrowme::marker::Ref<Cell<u8>>> = owned._marker;
+/// is_send::<OwnedMarked<u8>>();
+/// ```
+///
+/// ```compile_fail
+/// use core::cell::Cell;
+/// use core::marker::PhantomData;
+///
+/// use borrowme::borrowme;
+///
+/// #[borrowme]
+/// pub struct Marked<'a, T> {
+///     _marker: PhantomData<&'a T>,
+/// }
+///
+/// fn is_send<T: Send>() {}
+///
+/// // `&Cell<u8>` isn't `Send`, so neither is the owned variant.
+/// is_send::<OwnedMarked<Cell<u8>>>();
+/// ```
+pub struct Ref<T>(PhantomData<*const T>)
+where
+    T: ?Sized;
+
+// SAFETY: `Ref<T>` never holds a value, and is `Send` and `Sync` under the same
+// conditions as `&T`.
+unsafe impl<T> Send for Ref<T> where T: ?Sized + Sync {}
+unsafe impl<T> Sync for Ref<T> where T: ?Sized + Sync {}
+
+/// Stands in for `&'a mut T` in the *owned* variant of a marker.
+///
+/// Like `&'a mut T` this is invariant over `T`, it's `Send` if `T` is `Send`,
+/// and it's `Sync` if `T` is `Sync`.
+pub struct Mut<T>(PhantomData<*mut T>)
+where
+    T: ?Sized;
+
+// SAFETY: `Mut<T>` never holds a value, and is `Send` and `Sync` under the same
+// conditions as `&mut T`.
+unsafe impl<T> Send for Mut<T> where T: ?Sized + Send {}
+unsafe impl<T> Sync for Mut<T> where T: ?Sized + Sync {}
@@ -0,0 +1,34 @@
+//! Helpers used by the [`#[borrowme(or_default)]`][or_default] field attribute
+//! to own an optional value as its default value when it's missing.
+//!
+//! [or_default]: crate::borrowme#borrowmeor_default-field-attribute
+
+use crate::{Borrow, ToOwned};
+
+/// Convert an optional value into an owned value, which is the default value
+/// if it's missing.
+#[inline]
+pub fn to_owned<T>(value: &Option<T>) -> T::Owned
+where
+    T: ToOwned,
+    T::Owned: Default,
+{
+    match value {
+        Some(value) => T::to_owned(value),
+        None => T::Owned::default(),
+    }
+}
+
+/// Borrow an owned value as an optional value, which is missing if it's equal
+/// to the default value.
+#[inline]
+pub fn borrow<T>(value: &T) -> Option<T::Target<'_>>
+where
+    T: Borrow + Default + PartialEq,
+{
+    if *value == T::default() {
+        None
+    } else {
+        Some(T::borrow(value))
+    }
+}
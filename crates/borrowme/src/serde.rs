@@ -0,0 +1,187 @@
+//! Helpers for using [`Cow`] fields with serde in borrowed structs.
+//!
+//! By default serde deserializes a `Cow<'a, str>` by always allocating, since
+//! it can't know whether the input outlives `'a`. The modules in here are
+//! intended to be used with `#[serde(with = "...")]` so that borrowed
+//! variants deserialize without copying when the underlying format allows it,
+//! while the owned variant produced by `#[borrowme]` still round-trips.
+//!
+//! ```
+//! use std::borrow::Cow;
+//!
+//! use borrowme::borrowme;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[borrowme]
+//! #[derive(Serialize, Deserialize)]
+//! struct Word<'a> {
+//!     #[serde(borrow, with = "borrowme::serde::cow_str")]
+//!     text: Cow<'a, str>,
+//! }
+//!
+//! let word: Word<'_> = serde_json::from_str(r#"{"text":"hello"}"#).unwrap();
+//! assert!(matches!(word.text, Cow::Borrowed("hello")));
+//!
+//! let owned: OwnedWord = borrowme::to_owned(&word);
+//! assert_eq!(serde_json::to_string(&owned).unwrap(), r#"{"text":"hello"}"#);
+//! ```
+//!
+//! [`Cow`]: alloc::borrow::Cow
+
+/// Serialize and deserialize a `Cow<'a, str>`, borrowing from the input when
+/// possible.
+pub mod cow_str {
+    use alloc::borrow::Cow;
+    use alloc::string::String;
+    use core::fmt;
+
+    use ::serde::de::{Deserializer, Error, Visitor};
+    use ::serde::ser::Serializer;
+
+    /// Serialize a `Cow<'_, str>` as a string.
+    #[inline]
+    #[allow(clippy::ptr_arg)]
+    pub fn serialize<S>(value: &Cow<'_, str>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(value)
+    }
+
+    /// Deserialize a `Cow<'de, str>`, borrowing from the input if possible.
+    #[inline]
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Cow<'de, str>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct CowStrVisitor;
+
+        impl<'de> Visitor<'de> for CowStrVisitor {
+            type Value = Cow<'de, str>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a string")
+            }
+
+            #[inline]
+            fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(Cow::Borrowed(v))
+            }
+
+            #[inline]
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(Cow::Owned(String::from(v)))
+            }
+
+            #[inline]
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(Cow::Owned(v))
+            }
+        }
+
+        deserializer.deserialize_str(CowStrVisitor)
+    }
+}
+
+/// Serialize and deserialize a `Cow<'a, [u8]>` as bytes, borrowing from the
+/// input when possible.
+pub mod cow_bytes {
+    use alloc::borrow::Cow;
+    use alloc::vec::Vec;
+    use core::fmt;
+
+    use ::serde::de::{Deserializer, Error, SeqAccess, Visitor};
+    use ::serde::ser::Serializer;
+
+    /// Serialize a `Cow<'_, [u8]>` as bytes.
+    #[inline]
+    #[allow(clippy::ptr_arg)]
+    pub fn serialize<S>(value: &Cow<'_, [u8]>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(value)
+    }
+
+    /// Deserialize a `Cow<'de, [u8]>`, borrowing from the input if possible.
+    #[inline]
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Cow<'de, [u8]>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct CowBytesVisitor;
+
+        impl<'de> Visitor<'de> for CowBytesVisitor {
+            type Value = Cow<'de, [u8]>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("bytes")
+            }
+
+            #[inline]
+            fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(Cow::Borrowed(v))
+            }
+
+            #[inline]
+            fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(Cow::Borrowed(v.as_bytes()))
+            }
+
+            #[inline]
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(Cow::Owned(v.to_vec()))
+            }
+
+            #[inline]
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(Cow::Owned(v.as_bytes().to_vec()))
+            }
+
+            #[inline]
+            fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(Cow::Owned(v))
+            }
+
+            #[inline]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+                while let Some(byte) = seq.next_element()? {
+                    out.push(byte);
+                }
+
+                Ok(Cow::Owned(out))
+            }
+        }
+
+        deserializer.deserialize_bytes(CowBytesVisitor)
+    }
+}
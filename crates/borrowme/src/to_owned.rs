@@ -1,6 +1,19 @@
+#[cfg(feature = "alloc")]
+mod alloc;
+
 #[cfg(feature = "std")]
 mod std;
 
+#[cfg(feature = "hashbrown")]
+mod hashbrown;
+
+#[cfg(feature = "std")]
+use ::std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+#[cfg(feature = "std")]
+use ::std::time::{Instant, SystemTime};
+use core::ops::Bound;
+use core::time::Duration;
+
 /// Convert to owned.
 ///
 /// This works similarly to [`ToOwned`][::std::borrow::ToOwned] with a few
@@ -63,6 +76,19 @@ mod std;
 ///     }
 /// }
 /// ```
+///
+/// <br>
+///
+/// # Maps
+///
+/// Converting a map such as a `HashMap` or a `BTreeMap` converts every key
+/// individually and builds a new map out of them. This assumes that the
+/// `Hash`, `Eq` and `Ord` implementations of the converted keys agree with the
+/// original ones, since keys which are distinct in the source would otherwise
+/// collide and entries would be dropped. With debug assertions enabled, the
+/// conversion panics if the converted map doesn't have the same length as the
+/// source. The same applies to [`Borrow`][crate::Borrow] and
+/// [`BorrowMut`][crate::BorrowMut].
 pub trait ToOwned {
     /// The owned type this is being converted to.
     type Owned;
@@ -82,3 +108,149 @@ where
         T::to_owned(*self)
     }
 }
+
+impl<T> ToOwned for &mut T
+where
+    T: ?Sized + ToOwned,
+{
+    type Owned = T::Owned;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        T::to_owned(*self)
+    }
+}
+
+impl<T> ToOwned for Option<T>
+where
+    T: ToOwned,
+{
+    type Owned = Option<T::Owned>;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        self.as_ref().map(ToOwned::to_owned)
+    }
+}
+
+impl<T> ToOwned for Bound<T>
+where
+    T: ToOwned,
+{
+    type Owned = Bound<T::Owned>;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        match self {
+            Bound::Included(value) => Bound::Included(value.to_owned()),
+            Bound::Excluded(value) => Bound::Excluded(value.to_owned()),
+            Bound::Unbounded => Bound::Unbounded,
+        }
+    }
+}
+
+macro_rules! identity {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl ToOwned for $ty {
+                type Owned = $ty;
+
+                #[inline]
+                fn to_owned(&self) -> Self::Owned {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+// Primitives and plain values such as `Duration` are their own owned variant,
+// which allows containers of them such as `[u8]` to be converted element-wise.
+identity! {
+    (), bool, char,
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64,
+    Duration,
+}
+
+// Addresses and points in time are plain values as well.
+#[cfg(feature = "std")]
+identity! {
+    IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr,
+    Instant, SystemTime,
+}
+
+macro_rules! tuple {
+    ($($ty:ident $n:tt),* $(,)?) => {
+        impl<$($ty,)*> ToOwned for ($($ty,)*)
+        where
+            $($ty: ToOwned,)*
+        {
+            type Owned = ($($ty::Owned,)*);
+
+            #[inline]
+            fn to_owned(&self) -> Self::Owned {
+                ($(self.$n.to_owned(),)*)
+            }
+        }
+    };
+}
+
+// Tuples are converted element-wise, so that `(&'a str, Word<'a>)` is owned as
+// `(String, OwnedWord)`.
+tuple!(A 0);
+tuple!(A 0, B 1);
+tuple!(A 0, B 1, C 2);
+tuple!(A 0, B 1, C 2, D 3);
+tuple!(A 0, B 1, C 2, D 3, E 4);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+/// Arrays are converted element-wise, so that `[&'a str; N]` is owned as
+/// `[String; N]`.
+impl<T, const N: usize> ToOwned for [T; N]
+where
+    T: ToOwned,
+{
+    type Owned = [T::Owned; N];
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        core::array::from_fn(|index| self[index].to_owned())
+    }
+}
+
+macro_rules! atomic {
+    ($($width:literal => $($ty:ident),*;)*) => {
+        $($(
+            /// Atomics are converted by loading their current value with
+            /// [`Ordering::Relaxed`][core::sync::atomic::Ordering::Relaxed] into
+            /// a new atomic. The conversion is a snapshot which doesn't
+            /// synchronize with other threads, so if that is needed the field
+            /// should be converted explicitly.
+            #[cfg(target_has_atomic = $width)]
+            impl ToOwned for core::sync::atomic::$ty {
+                type Owned = core::sync::atomic::$ty;
+
+                #[inline]
+                fn to_owned(&self) -> Self::Owned {
+                    core::sync::atomic::$ty::new(self.load(core::sync::atomic::Ordering::Relaxed))
+                }
+            }
+        )*)*
+    };
+}
+
+atomic! {
+    "8" => AtomicBool, AtomicI8, AtomicU8;
+    "16" => AtomicI16, AtomicU16;
+    "32" => AtomicI32, AtomicU32;
+    "64" => AtomicI64, AtomicU64;
+    "ptr" => AtomicIsize, AtomicUsize;
+}
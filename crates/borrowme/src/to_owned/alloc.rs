@@ -0,0 +1,219 @@
+use core::ffi::CStr;
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet, LinkedList, VecDeque};
+use alloc::ffi::CString;
+use alloc::rc::Rc;
+use alloc::string::String;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use crate::ToOwned;
+
+impl ToOwned for str {
+    type Owned = String;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        String::from(self)
+    }
+}
+
+impl ToOwned for String {
+    type Owned = String;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        String::from(self.as_str())
+    }
+}
+
+/// Slices are converted element-wise, so that a slice of compound types such as
+/// `[Word<'a>]` is owned as a `Vec<OwnedWord>`.
+impl<T> ToOwned for [T]
+where
+    T: ToOwned,
+{
+    type Owned = Vec<T::Owned>;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        self.iter().map(ToOwned::to_owned).collect()
+    }
+}
+
+/// Boxed values are converted in place, so that a recursive `Box<Expr<'a>>`
+/// is owned as a `Box<OwnedExpr>`.
+impl<T> ToOwned for Box<T>
+where
+    T: ToOwned,
+{
+    type Owned = Box<T::Owned>;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        Box::new(T::to_owned(self))
+    }
+}
+
+/// A boxed string is owned as a `String`, the same as `str`.
+impl ToOwned for Box<str> {
+    type Owned = String;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        String::from(&**self)
+    }
+}
+
+/// Boxed slices are converted element-wise and stay boxed.
+impl<T> ToOwned for Box<[T]>
+where
+    T: ToOwned,
+{
+    type Owned = Box<[T::Owned]>;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        self.iter().map(ToOwned::to_owned).collect()
+    }
+}
+
+/// Shared values are converted into a new `Rc` which isn't shared with the
+/// original, so that an `Rc<Node<'a>>` is owned as an `Rc<OwnedNode>`.
+impl<T> ToOwned for Rc<T>
+where
+    T: ToOwned,
+{
+    type Owned = Rc<T::Owned>;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        Rc::new(T::to_owned(self))
+    }
+}
+
+/// Shared values are converted into a new `Arc` which isn't shared with the
+/// original, so that an `Arc<Node<'a>>` is owned as an `Arc<OwnedNode>`.
+#[cfg(target_has_atomic = "ptr")]
+impl<T> ToOwned for Arc<T>
+where
+    T: ToOwned,
+{
+    type Owned = Arc<T::Owned>;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        Arc::new(T::to_owned(self))
+    }
+}
+
+impl<B> ToOwned for Cow<'_, B>
+where
+    B: 'static + ?Sized + alloc::borrow::ToOwned,
+{
+    type Owned = Cow<'static, B>;
+
+    #[inline]
+    fn to_owned(&self) -> <Self as ToOwned>::Owned {
+        // NB: Since this only has access to `&self`, an owned value can't be
+        // moved out and has to be cloned. Either way this allocates at most
+        // once, use `borrowme::cow::into_static` to reuse an owned value.
+        match self {
+            Cow::Borrowed(value) => Cow::Owned(alloc::borrow::ToOwned::to_owned(*value)),
+            Cow::Owned(value) => {
+                let value: &B = alloc::borrow::Borrow::borrow(value);
+                Cow::Owned(alloc::borrow::ToOwned::to_owned(value))
+            }
+        }
+    }
+}
+
+impl ToOwned for CStr {
+    type Owned = CString;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        alloc::borrow::ToOwned::to_owned(self)
+    }
+}
+
+macro_rules! seq {
+    (cap $seq:ident, $insert:ident $(, $trait:path)* $(,)?) => {
+        impl<T> ToOwned for $seq<T>
+        where
+            T: ToOwned,
+            $(T::Owned: $trait,)*
+        {
+            type Owned = $seq<T::Owned>;
+
+            #[inline]
+            fn to_owned(&self) -> Self::Owned {
+                let mut out = <$seq<T::Owned>>::with_capacity(self.len());
+
+                for value in self.iter() {
+                    out.$insert(value.to_owned());
+                }
+
+                out
+            }
+        }
+    };
+
+    ($seq:ident, $insert:ident $(, $trait:path)* $(,)?) => {
+        impl<T> ToOwned for $seq<T>
+        where
+            T: ToOwned,
+            $(T::Owned: $trait,)*
+        {
+            type Owned = $seq<T::Owned>;
+
+            #[inline]
+            fn to_owned(&self) -> Self::Owned {
+                let mut out = <$seq<T::Owned>>::new();
+
+                for value in self.iter() {
+                    out.$insert(value.to_owned());
+                }
+
+                out
+            }
+        }
+    };
+}
+
+macro_rules! map {
+    ($map:ident, $insert:ident $(, $trait:path)* $(,)?) => {
+        impl<K, V> ToOwned for $map<K, V>
+        where
+            K: ToOwned,
+            V: ToOwned,
+            $(K::Owned: $trait,)*
+        {
+            type Owned = $map<K::Owned, V::Owned>;
+
+            #[inline]
+            fn to_owned(&self) -> Self::Owned {
+                let mut out = <$map<_, _>>::new();
+
+                for (key, value) in self.iter() {
+                    out.$insert(key.to_owned(), value.to_owned());
+                }
+
+                // Keys which are distinct in the source might compare equal
+                // once converted, which would silently drop entries.
+                debug_assert_eq!(out.len(), self.len(), "converted map has colliding keys");
+                out
+            }
+        }
+    };
+}
+
+seq!(cap Vec, push);
+seq!(cap VecDeque, push_back);
+seq!(BTreeSet, insert, PartialOrd, Ord, Eq);
+seq!(LinkedList, push_back);
+
+map!(BTreeMap, insert, PartialOrd, Ord, Eq);
@@ -0,0 +1,51 @@
+use core::hash::{BuildHasher, Hash};
+
+use hashbrown::{HashMap, HashSet};
+
+use crate::ToOwned;
+
+/// The owned set is constructed using a clone of the original hasher.
+impl<T, S> ToOwned for HashSet<T, S>
+where
+    T: ToOwned,
+    T::Owned: Hash + Eq,
+    S: Clone + BuildHasher,
+{
+    type Owned = HashSet<T::Owned, S>;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        let mut out = HashSet::with_capacity_and_hasher(self.len(), self.hasher().clone());
+
+        for value in self.iter() {
+            out.insert(value.to_owned());
+        }
+
+        out
+    }
+}
+
+/// The owned map is constructed using a clone of the original hasher.
+impl<K, V, S> ToOwned for HashMap<K, V, S>
+where
+    K: ToOwned,
+    V: ToOwned,
+    K::Owned: Hash + Eq,
+    S: Clone + BuildHasher,
+{
+    type Owned = HashMap<K::Owned, V::Owned, S>;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        let mut out = HashMap::with_capacity_and_hasher(self.len(), self.hasher().clone());
+
+        for (key, value) in self.iter() {
+            out.insert(key.to_owned(), value.to_owned());
+        }
+
+        // Keys which are distinct in the source might compare equal once
+        // converted, which would silently drop entries.
+        debug_assert_eq!(out.len(), self.len(), "converted map has colliding keys");
+        out
+    }
+}
@@ -1,82 +1,53 @@
-use std::borrow::Cow;
-use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList};
-use std::ffi::{CStr, CString, OsStr, OsString};
-use std::hash::Hash;
+use std::collections::{HashMap, HashSet};
+use std::ffi::{OsStr, OsString};
+use std::hash::{BuildHasher, Hash};
 use std::path::{Path, PathBuf};
 
 use crate::ToOwned;
 
-impl ToOwned for str {
-    type Owned = String;
-
-    #[inline]
-    fn to_owned(&self) -> Self::Owned {
-        String::from(self)
-    }
-}
-
-impl ToOwned for String {
-    type Owned = String;
-
-    #[inline]
-    fn to_owned(&self) -> Self::Owned {
-        String::from(self.as_str())
-    }
-}
-
-impl ToOwned for &mut String {
-    type Owned = String;
-
-    #[inline]
-    fn to_owned(&self) -> Self::Owned {
-        String::from(self.as_str())
-    }
-}
-
-impl ToOwned for &mut str {
-    type Owned = String;
-
-    #[inline]
-    fn to_owned(&self) -> Self::Owned {
-        String::from(&**self)
-    }
-}
-
-impl<T> ToOwned for Option<T>
+/// The owned set is constructed using a clone of the original hasher.
+impl<T, S> ToOwned for HashSet<T, S>
 where
     T: ToOwned,
+    T::Owned: Hash + Eq,
+    S: Clone + BuildHasher,
 {
-    type Owned = Option<T::Owned>;
+    type Owned = HashSet<T::Owned, S>;
 
     #[inline]
     fn to_owned(&self) -> Self::Owned {
-        self.as_ref().map(ToOwned::to_owned)
+        let mut out = HashSet::with_capacity_and_hasher(self.len(), self.hasher().clone());
+
+        for value in self.iter() {
+            out.insert(value.to_owned());
+        }
+
+        out
     }
 }
 
-impl<T> ToOwned for [T]
+/// The owned map is constructed using a clone of the original hasher.
+impl<K, V, S> ToOwned for HashMap<K, V, S>
 where
-    T: Clone,
+    K: ToOwned,
+    V: ToOwned,
+    K::Owned: Hash + Eq,
+    S: Clone + BuildHasher,
 {
-    type Owned = Vec<T>;
+    type Owned = HashMap<K::Owned, V::Owned, S>;
 
     #[inline]
     fn to_owned(&self) -> Self::Owned {
-        self.to_vec()
-    }
-}
+        let mut out = HashMap::with_capacity_and_hasher(self.len(), self.hasher().clone());
 
-impl<B> ToOwned for Cow<'_, B>
-where
-    B: 'static + ?Sized + std::borrow::ToOwned,
-{
-    type Owned = Cow<'static, B>;
+        for (key, value) in self.iter() {
+            out.insert(key.to_owned(), value.to_owned());
+        }
 
-    #[inline]
-    fn to_owned(&self) -> <Self as ToOwned>::Owned {
-        // Cloning the cow will either clone the inner value - if it's already
-        // present - or the associated reference.
-        Cow::Owned(self.clone().into_owned())
+        // Keys which are distinct in the source might compare equal once
+        // converted, which would silently drop entries.
+        debug_assert_eq!(out.len(), self.len(), "converted map has colliding keys");
+        out
     }
 }
 
@@ -97,110 +68,3 @@ impl ToOwned for OsStr {
         self.to_os_string()
     }
 }
-
-impl ToOwned for CStr {
-    type Owned = CString;
-
-    #[inline]
-    fn to_owned(&self) -> Self::Owned {
-        std::borrow::ToOwned::to_owned(self)
-    }
-}
-
-macro_rules! seq {
-    (cap $seq:ident, $insert:ident $(, $trait:path)* $(,)?) => {
-        impl<T> ToOwned for $seq<T>
-        where
-            T: ToOwned,
-            $(T::Owned: $trait,)*
-        {
-            type Owned = $seq<T::Owned>;
-
-            #[inline]
-            fn to_owned(&self) -> Self::Owned {
-                let mut out = <$seq<T::Owned>>::with_capacity(self.len());
-
-                for value in self.iter() {
-                    out.$insert(value.to_owned());
-                }
-
-                out
-            }
-        }
-    };
-
-    ($seq:ident, $insert:ident $(, $trait:path)* $(,)?) => {
-        impl<T> ToOwned for $seq<T>
-        where
-            T: ToOwned,
-            $(T::Owned: $trait,)*
-        {
-            type Owned = $seq<T::Owned>;
-
-            #[inline]
-            fn to_owned(&self) -> Self::Owned {
-                let mut out = <$seq<T::Owned>>::new();
-
-                for value in self.iter() {
-                    out.$insert(value.to_owned());
-                }
-
-                out
-            }
-        }
-    };
-}
-
-macro_rules! map {
-    (cap $map:ident, $insert:ident $(, $trait:path)* $(,)?) => {
-        impl<K, V> ToOwned for $map<K, V>
-        where
-            K: ToOwned,
-            V: ToOwned,
-            $(K::Owned: $trait,)*
-        {
-            type Owned = $map<K::Owned, V::Owned>;
-
-            #[inline]
-            fn to_owned(&self) -> Self::Owned {
-                let mut out = <$map<_, _>>::with_capacity(self.len());
-
-                for (key, value) in self.iter() {
-                    out.$insert(key.to_owned(), value.to_owned());
-                }
-
-                out
-            }
-        }
-    };
-
-    ($map:ident, $insert:ident $(, $trait:path)* $(,)?) => {
-        impl<K, V> ToOwned for $map<K, V>
-        where
-            K: ToOwned,
-            V: ToOwned,
-            $(K::Owned: $trait,)*
-        {
-            type Owned = $map<K::Owned, V::Owned>;
-
-            #[inline]
-            fn to_owned(&self) -> Self::Owned {
-                let mut out = <$map<_, _>>::new();
-
-                for (key, value) in self.iter() {
-                    out.$insert(key.to_owned(), value.to_owned());
-                }
-
-                out
-            }
-        }
-    };
-}
-
-seq!(cap HashSet, insert, Hash, Eq);
-seq!(cap Vec, push);
-seq!(BTreeSet, insert, PartialOrd, Ord, Eq);
-seq!(LinkedList, push_back);
-
-map!(cap HashMap, insert, Hash, Eq);
-map!(BTreeMap, insert, PartialOrd, Ord, Eq);
@@ -0,0 +1,55 @@
+/// Fallibly convert to owned.
+///
+/// This works like [`ToOwned`][crate::ToOwned], except that the conversion
+/// can fail. It is implemented by [`#[borrowme]`][crate::borrowme] when an
+/// error type is specified with [`#[borrowme(error = <type>)]`][error], where
+/// fields using [`#[borrowme(try_to_owned_with = <path>)]`][try_to_owned_with]
+/// are converted fallibly and every other field is converted the same way as
+/// in [`ToOwned`][crate::ToOwned]. It is recommended that you use
+/// [`try_to_owned`][crate::try_to_owned()] instead of importing this trait.
+///
+/// [error]: crate::borrowme#borrowmeerror--type-container-attribute
+/// [try_to_owned_with]: crate::borrowme#borrowmetry_to_owned_with--path-field-attribute
+///
+/// ```
+/// use borrowme::TryToOwned;
+///
+/// struct Word<'a>(&'a str);
+/// struct OwnedWord(String);
+///
+/// impl TryToOwned for Word<'_> {
+///     type Owned = OwnedWord;
+///     type Error = &'static str;
+///
+///     fn try_to_owned(&self) -> Result<OwnedWord, &'static str> {
+///         if self.0.is_empty() {
+///             return Err("empty word");
+///         }
+///
+///         Ok(OwnedWord(self.0.to_string()))
+///     }
+/// }
+/// ```
+pub trait TryToOwned {
+    /// The owned type this is being converted to.
+    type Owned;
+
+    /// The error raised if the conversion fails.
+    type Error;
+
+    /// Perform a fallible conversion from a reference to owned value.
+    fn try_to_owned(&self) -> Result<Self::Owned, Self::Error>;
+}
+
+impl<T> TryToOwned for &T
+where
+    T: ?Sized + TryToOwned,
+{
+    type Owned = T::Owned;
+    type Error = T::Error;
+
+    #[inline]
+    fn try_to_owned(&self) -> Result<Self::Owned, Self::Error> {
+        T::try_to_owned(*self)
+    }
+}
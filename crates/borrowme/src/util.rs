@@ -0,0 +1,44 @@
+//! Helper functions to use with field attributes such as
+//! [`#[borrowme(to_owned_with = <path>)]`][to_owned_with] and
+//! [`#[borrowme(borrow_with = <path>)]`][borrow_with].
+//!
+//! [to_owned_with]: crate::borrowme#borrowmeto_owned_with--path-field-attribute
+//! [borrow_with]: crate::borrowme#borrowmeborrow_with--path-field-attribute
+
+use alloc::vec::Vec;
+
+/// Own a string as its UTF-8 encoded bytes.
+///
+/// ```
+/// use borrowme::util;
+///
+/// assert_eq!(util::str_to_vec("hello"), b"hello");
+/// ```
+#[inline]
+pub fn str_to_vec(value: &str) -> Vec<u8> {
+    value.as_bytes().to_vec()
+}
+
+/// Borrow UTF-8 encoded bytes as a string.
+///
+/// # Panics
+///
+/// Panics if the bytes are not valid UTF-8, which can only happen if the
+/// *owned* value was constructed or modified with invalid data.
+///
+/// ```
+/// use borrowme::util;
+///
+/// let bytes = Vec::from("hello");
+/// assert_eq!(util::vec_to_str(&bytes), "hello");
+/// ```
+// NB: This is called with a reference to the owned field, so it has to accept
+// `&Vec<u8>` rather than `&[u8]`.
+#[allow(clippy::ptr_arg)]
+#[inline]
+pub fn vec_to_str(value: &Vec<u8>) -> &str {
+    match core::str::from_utf8(value) {
+        Ok(value) => value,
+        Err(error) => panic!("owned value is not valid UTF-8: {}", error),
+    }
+}
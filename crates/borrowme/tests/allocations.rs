@@ -0,0 +1,26 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Word<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Words<'a> {
+    words: Vec<Word<'a>>,
+}
+
+#[test]
+fn allocation_is_not_reused() {
+    let mut words = Vec::with_capacity(16);
+    words.push(Word { text: "hello" });
+    words.push(Word { text: "world" });
+
+    let words = Words { words };
+    let owned: OwnedWords = borrowme::to_owned(&words);
+    assert_eq!(owned.words.len(), 2);
+    assert_ne!(owned.words.as_ptr() as usize, words.words.as_ptr() as usize);
+    assert_eq!(borrowme::borrow(&owned), words);
+}
@@ -0,0 +1,40 @@
+use arbitrary::{Arbitrary, Unstructured};
+use borrowme::borrowme;
+
+#[borrowme(arbitrary)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    text: &'a str,
+    lang: Option<&'a str>,
+}
+
+#[borrowme(arbitrary)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Sentence<'a> {
+    words: Vec<Word<'a>>,
+    count: u32,
+}
+
+#[borrowme]
+#[owned_attr(derive(Arbitrary))]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Entry<'a> {
+    Text(&'a str),
+    Bytes { bytes: Vec<&'a str> },
+}
+
+#[test]
+fn arbitrary_round_trip() {
+    let data = (0..=255u8).cycle().take(1024).collect::<Vec<_>>();
+    let mut u = Unstructured::new(&data);
+
+    for _ in 0..16 {
+        let owned = OwnedSentence::arbitrary(&mut u).unwrap();
+        let borrowed: Sentence<'_> = borrowme::borrow(&owned);
+        assert_eq!(borrowme::to_owned(&borrowed), owned);
+
+        let owned = OwnedEntry::arbitrary(&mut u).unwrap();
+        let borrowed: Entry<'_> = borrowme::borrow(&owned);
+        assert_eq!(borrowme::to_owned(&borrowed), owned);
+    }
+}
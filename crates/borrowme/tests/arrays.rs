@@ -0,0 +1,50 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Word<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Names<'a> {
+    names: [&'a str; 4],
+    words: [Word<'a>; 2],
+    strings: [String; 3],
+    numbers: [u32; 8],
+    bytes: &'a [u8; 3],
+}
+
+#[test]
+fn arrays() {
+    let names = Names {
+        names: ["a", "b", "c", "d"],
+        words: [Word { text: "hello" }, Word { text: "world" }],
+        strings: [String::from("x"), String::from("y"), String::from("z")],
+        numbers: [1, 2, 3, 4, 5, 6, 7, 8],
+        bytes: &[1, 2, 3],
+    };
+
+    let owned: OwnedNames = borrowme::to_owned(&names);
+    let _: &[String; 4] = &owned.names;
+    let _: &[OwnedWord; 2] = &owned.words;
+    let _: &[String; 3] = &owned.strings;
+    let _: &[u32; 8] = &owned.numbers;
+    let _: &[u8; 3] = &owned.bytes;
+    assert_eq!(owned.names, ["a", "b", "c", "d"]);
+    assert_eq!(owned.words[1].text, "world");
+    assert_eq!(owned.strings, ["x", "y", "z"]);
+    assert_eq!(owned.numbers, [1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(borrowme::borrow(&owned), names);
+}
+
+#[test]
+fn array_conversions() {
+    let strings = [String::from("a"), String::from("b"), String::from("c")];
+    let borrowed: [&str; 3] = borrowme::borrow(&strings);
+    assert_eq!(borrowed, ["a", "b", "c"]);
+
+    let owned: [String; 3] = borrowme::to_owned(&borrowed);
+    assert_eq!(owned, strings);
+}
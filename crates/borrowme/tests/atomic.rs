@@ -0,0 +1,36 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use borrowme::borrowme;
+
+#[borrowme]
+pub struct Config<'a> {
+    name: &'a str,
+    enabled: &'a AtomicBool,
+    #[borrowme(owned = AtomicUsize, borrow_with = borrowme::ToOwned::to_owned)]
+    count: AtomicUsize,
+}
+
+#[test]
+fn atomic() {
+    let enabled = AtomicBool::new(true);
+
+    let config = Config {
+        name: "app",
+        enabled: &enabled,
+        count: AtomicUsize::new(2),
+    };
+
+    let owned: OwnedConfig = borrowme::to_owned(&config);
+    assert_eq!(owned.name, "app");
+    assert!(owned.enabled.load(Ordering::Relaxed));
+    assert_eq!(owned.count.load(Ordering::Relaxed), 2);
+
+    // The owned value is a snapshot which is independent of the original.
+    enabled.store(false, Ordering::Relaxed);
+    assert!(owned.enabled.load(Ordering::Relaxed));
+
+    let borrowed = borrowme::borrow(&owned);
+    borrowed.enabled.store(false, Ordering::Relaxed);
+    assert!(!owned.enabled.load(Ordering::Relaxed));
+    assert_eq!(borrowed.count.load(Ordering::Relaxed), 2);
+}
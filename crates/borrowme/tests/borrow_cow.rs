@@ -0,0 +1,42 @@
+use borrowme::borrowme;
+use std::borrow::Cow;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    #[borrowme(borrow_cow)]
+    text: &'a str,
+    #[borrowme(borrow_cow)]
+    bytes: &'a [u8],
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Token<'a> {
+    Text(#[borrowme(borrow_cow)] &'a str),
+    Empty,
+}
+
+#[test]
+fn borrow_cow() {
+    let word = Word {
+        text: Cow::Owned(String::from("hello")),
+        bytes: Cow::Borrowed(b"world"),
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(owned.text, "hello");
+    assert_eq!(owned.bytes, b"world");
+
+    let word = borrowme::borrow(&owned);
+    assert!(matches!(word.text, Cow::Borrowed("hello")));
+    assert!(matches!(word.bytes, Cow::Borrowed(b"world")));
+}
+
+#[test]
+fn borrow_cow_enum() {
+    let token = Token::Text(Cow::Borrowed("hello"));
+    let owned: OwnedToken = borrowme::to_owned(&token);
+    assert_eq!(owned, OwnedToken::Text(String::from("hello")));
+    assert_eq!(borrowme::borrow(&owned), token);
+}
@@ -0,0 +1,72 @@
+use borrowme::borrowme;
+
+#[borrowme(borrow_ref)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    text: &'a str,
+    lang: Option<&'a str>,
+}
+
+#[borrowme(borrow_ref)]
+#[derive(Debug, PartialEq, Eq)]
+enum Entry<'a> {
+    Word(Word<'a>),
+    Text { text: &'a str },
+}
+
+#[borrowme(borrow_ref)]
+struct Wrap<'a, T: ?Sized + borrowme::ToOwned> {
+    value: &'a T,
+}
+
+#[borrowme(borrow_ref)]
+struct Counter<'a> {
+    #[borrowme(mut)]
+    count: &'a mut u32,
+}
+
+// An inherent `borrow_ref` method is only generated when it's asked for, so it
+// doesn't collide with existing methods.
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Custom<'a> {
+    text: &'a str,
+}
+
+impl OwnedCustom {
+    fn borrow_ref(&self) -> &str {
+        &self.text
+    }
+}
+
+#[test]
+fn borrow_ref() {
+    let word = Word {
+        text: "hello",
+        lang: Some("en"),
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(owned.borrow_ref(), word);
+
+    let entry = Entry::Text { text: "world" };
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert_eq!(owned.borrow_ref(), entry);
+
+    let wrap = Wrap::<str> { value: "hello" };
+    let owned: OwnedWrap<str> = borrowme::to_owned(&wrap);
+    assert_eq!(owned.borrow_ref().value, "hello");
+}
+
+#[test]
+fn borrow_ref_mut() {
+    let mut owned = OwnedCounter { count: 1 };
+    *owned.borrow_ref().count += 1;
+    assert_eq!(owned.count, 2);
+}
+
+#[test]
+fn borrow_ref_custom() {
+    let owned: OwnedCustom = borrowme::to_owned(&Custom { text: "hello" });
+    assert_eq!(owned.borrow_ref(), "hello");
+}
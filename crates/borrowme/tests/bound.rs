@@ -0,0 +1,48 @@
+use std::ops::Bound;
+
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Range<'a> {
+    start: Bound<&'a str>,
+    end: Bound<&'a str>,
+    limit: Bound<u32>,
+}
+
+#[borrowme]
+struct RangeMut<'a> {
+    start: Bound<&'a mut String>,
+}
+
+#[test]
+fn bound() {
+    let range = Range {
+        start: Bound::Included("a"),
+        end: Bound::Excluded("z"),
+        limit: Bound::Unbounded,
+    };
+
+    let owned: OwnedRange = borrowme::to_owned(&range);
+    assert_eq!(owned.start, Bound::Included(String::from("a")));
+    assert_eq!(owned.end, Bound::Excluded(String::from("z")));
+    assert_eq!(owned.limit, Bound::Unbounded);
+    assert_eq!(borrowme::borrow(&owned), range);
+}
+
+#[test]
+fn bound_mut() {
+    let mut start = String::from("a");
+
+    let range = RangeMut {
+        start: Bound::Included(&mut start),
+    };
+
+    let mut owned: OwnedRangeMut = borrowme::to_owned(&range);
+
+    if let Bound::Included(start) = borrowme::borrow_mut(&mut owned).start {
+        start.push('b');
+    }
+
+    assert_eq!(owned.start, Bound::Included(String::from("ab")));
+}
@@ -0,0 +1,70 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+enum Expr<'a> {
+    Ident(&'a str),
+    Not(Box<Expr<'a>>),
+    Binary(Box<Binary<'a>>),
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Binary<'a> {
+    op: &'a str,
+    lhs: Expr<'a>,
+    rhs: Expr<'a>,
+}
+
+#[borrowme]
+#[derive(Debug)]
+struct Node<'a> {
+    text: &'a mut String,
+    #[borrowme(mut)]
+    next: Option<Box<Node<'a>>>,
+}
+
+#[test]
+fn boxed_recursion() {
+    let expr = Expr::Not(Box::new(Expr::Binary(Box::new(Binary {
+        op: "&&",
+        lhs: Expr::Ident("a"),
+        rhs: Expr::Not(Box::new(Expr::Ident("b"))),
+    }))));
+
+    let owned: OwnedExpr = borrowme::to_owned(&expr);
+
+    let OwnedExpr::Not(inner) = owned else {
+        panic!("expected a negation");
+    };
+
+    let inner: Box<OwnedExpr> = inner;
+    let owned = OwnedExpr::Not(inner);
+    assert_eq!(borrowme::borrow(&owned), expr);
+}
+
+#[test]
+fn boxed_recursion_mut() {
+    let mut owned = OwnedNode {
+        text: String::from("hello"),
+        next: Some(Box::new(OwnedNode {
+            text: String::from("world"),
+            next: None,
+        })),
+    };
+
+    {
+        let mut node = Some(Box::new(borrowme::borrow_mut(&mut owned)));
+
+        while let Some(current) = node {
+            current.text.make_ascii_uppercase();
+            node = current.next;
+        }
+    }
+
+    assert_eq!(owned.text, "HELLO");
+    assert_eq!(
+        owned.next.as_ref().map(|next| next.text.as_str()),
+        Some("WORLD")
+    );
+}
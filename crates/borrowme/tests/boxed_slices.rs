@@ -0,0 +1,79 @@
+use borrowme::borrowme;
+
+#[borrowme(boxed_slices)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Packet<'a> {
+    bytes: &'a [u8],
+    names: &'a [String],
+    #[borrowme(owned = Vec<u8>, deref_borrow)]
+    explicit: &'a [u8],
+    text: &'a str,
+}
+
+#[borrowme(boxed_slices)]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Entry<'a> {
+    Bytes(&'a [u8]),
+    Named { values: &'a [u32] },
+}
+
+#[borrowme(boxed_slices)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Interned<'a> {
+    keys: &'a [u32],
+    #[borrowme(borrow_with = Vec::as_slice)]
+    values: &'a [u32],
+}
+
+#[test]
+fn boxed_slices() {
+    let names = [String::from("a"), String::from("b")];
+
+    let packet = Packet {
+        bytes: &[1, 2, 3],
+        names: &names,
+        explicit: &[4, 5],
+        text: "text",
+    };
+
+    let owned: OwnedPacket = borrowme::to_owned(&packet);
+    let _: Box<[u8]> = owned.bytes.clone();
+    let _: Box<[String]> = owned.names.clone();
+    let _: &Vec<u8> = &owned.explicit;
+    assert_eq!(&owned.bytes[..], &[1, 2, 3]);
+    assert_eq!(&owned.names[..], &names[..]);
+
+    let borrowed: Packet<'_> = borrowme::borrow(&owned);
+    assert_eq!(borrowed, packet);
+}
+
+#[test]
+fn boxed_slices_enum() {
+    let entry = Entry::Named { values: &[1, 2, 3] };
+
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+
+    let OwnedEntry::Named { values } = &owned else {
+        panic!("expected named entry");
+    };
+
+    let _: Box<[u32]> = values.clone();
+    assert_eq!(borrowme::borrow(&owned), entry);
+
+    let entry = Entry::Bytes(&[1]);
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert!(matches!(&owned, OwnedEntry::Bytes(bytes) if bytes[..] == [1]));
+}
+
+#[test]
+fn boxed_slices_owned_override() {
+    let interned = Interned {
+        keys: &[1, 2],
+        values: &[3, 4],
+    };
+
+    let owned: OwnedInterned = borrowme::to_owned(&interned);
+    let _: Box<[u32]> = owned.keys.clone();
+    let _: &Vec<u32> = &owned.values;
+    assert_eq!(borrowme::borrow(&owned), interned);
+}
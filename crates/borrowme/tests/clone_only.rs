@@ -0,0 +1,69 @@
+use borrowme::borrowme;
+
+/// A type which is its own borrow target, but whose `ToOwned` implementation
+/// produces something else entirely.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Id {
+    value: u32,
+    borrowed: bool,
+}
+
+impl borrowme::ToOwned for Id {
+    type Owned = String;
+
+    fn to_owned(&self) -> String {
+        self.value.to_string()
+    }
+}
+
+impl borrowme::Borrow for Id {
+    type Target<'a> = Id;
+
+    fn borrow(&self) -> Id {
+        Id {
+            value: self.value,
+            borrowed: true,
+        }
+    }
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    text: &'a str,
+    #[borrowme(clone_only)]
+    id: Id,
+}
+
+#[borrowme(no_heuristics)]
+pub enum Entry<'a> {
+    Id(#[borrowme(clone_only)] Id),
+    Text(#[owned(String)] &'a str),
+}
+
+#[test]
+fn clone_only() {
+    let word = Word {
+        text: "hello",
+        id: Id {
+            value: 1,
+            borrowed: false,
+        },
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    let _: &Id = &owned.id;
+    assert!(!owned.id.borrowed);
+
+    let borrowed: Word<'_> = borrowme::borrow(&owned);
+    assert!(borrowed.id.borrowed);
+    assert_eq!(borrowed.id.value, 1);
+
+    let entry = Entry::Id(Id {
+        value: 3,
+        borrowed: false,
+    });
+
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert!(matches!(&owned, OwnedEntry::Id(id) if id.value == 3 && !id.borrowed));
+}
@@ -0,0 +1,79 @@
+use std::collections::VecDeque;
+
+use borrowme::borrowme;
+use borrowme::collect::Collect;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Stack<T>(Vec<T>);
+
+impl<T> Collect for Stack<T> {
+    type Item = T;
+    type Output<U> = Stack<U>;
+    type Iter<'a>
+        = std::slice::Iter<'a, T>
+    where
+        T: 'a;
+
+    fn items(&self) -> Self::Iter<'_> {
+        self.0.iter()
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        Stack(Vec::with_capacity(capacity))
+    }
+
+    fn extend_items<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.0.extend(iter);
+    }
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Dictionary<'a> {
+    #[borrowme(collect)]
+    names: Stack<&'a str>,
+    #[borrowme(collect)]
+    words: Stack<Word<'a>>,
+    #[borrowme(collect)]
+    queue: VecDeque<&'a str>,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Entry<'a> {
+    Names(#[borrowme(collect)] Stack<&'a str>),
+    Empty,
+}
+
+#[test]
+fn collect() {
+    let dictionary = Dictionary {
+        names: Stack(vec!["hello", "world"]),
+        words: Stack(vec![Word { text: "hej" }]),
+        queue: VecDeque::from(vec!["first"]),
+    };
+
+    let owned: OwnedDictionary = borrowme::to_owned(&dictionary);
+    let names: &Stack<String> = &owned.names;
+    assert_eq!(names.0, ["hello", "world"]);
+    assert_eq!(names.0.capacity(), 2);
+    let words: &Stack<OwnedWord> = &owned.words;
+    assert_eq!(words.0[0].text, "hej");
+    let queue: &VecDeque<String> = &owned.queue;
+    assert_eq!(queue[0], "first");
+    assert_eq!(borrowme::borrow(&owned), dictionary);
+
+    let entry = Entry::Names(Stack(vec!["hello"]));
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert!(matches!(&owned, OwnedEntry::Names(names) if names.0 == ["hello"]));
+    assert_eq!(borrowme::borrow(&owned), entry);
+}
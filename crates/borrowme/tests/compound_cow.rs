@@ -0,0 +1,51 @@
+use borrowme::{borrowme, CompoundCow};
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+#[derive(Debug)]
+pub struct Sentence<'a> {
+    #[borrowme(borrow_with = CompoundCow::borrowed)]
+    words: CompoundCow<'a, Vec<OwnedWord>>,
+    #[borrowme(borrow_with = CompoundCow::borrowed)]
+    title: CompoundCow<'a, OwnedWord>,
+}
+
+#[test]
+fn compound_cow() {
+    let mut sentence = Sentence {
+        words: CompoundCow::Borrowed(vec![Word { text: "hello" }]),
+        title: CompoundCow::Borrowed(Word { text: "greeting" }),
+    };
+
+    let owned: OwnedSentence = borrowme::to_owned(&sentence);
+    let _: &Vec<OwnedWord> = &owned.words;
+    assert_eq!(owned.words[0].text, "hello");
+    assert_eq!(owned.title.text, "greeting");
+
+    sentence.words.to_mut().push(OwnedWord {
+        text: String::from("world"),
+    });
+
+    assert!(sentence.words.is_owned());
+    assert!(sentence.title.is_borrowed());
+
+    let owned: OwnedSentence = borrowme::to_owned(&sentence);
+    assert_eq!(owned.words.len(), 2);
+    assert_eq!(owned.words[1].text, "world");
+
+    let borrowed: Sentence<'_> = borrowme::borrow(&owned);
+
+    let CompoundCow::Borrowed(words) = &borrowed.words else {
+        panic!("expected borrowed words");
+    };
+
+    assert_eq!(words, &[Word { text: "hello" }, Word { text: "world" }]);
+
+    let title = borrowed.title.into_owned();
+    assert_eq!(title.text, "greeting");
+}
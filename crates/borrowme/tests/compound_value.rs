@@ -0,0 +1,29 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    text: &'a str,
+}
+
+// NB: A reference such as `&'a Word<'a>` can't be borrowed back from an owned
+// value, so compound types are stored by value.
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Entry<'a> {
+    word: Word<'a>,
+    words: Option<Word<'a>>,
+}
+
+#[test]
+fn compound_value() {
+    let entry = Entry {
+        word: Word { text: "hello" },
+        words: Some(Word { text: "world" }),
+    };
+
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    let _: &OwnedWord = &owned.word;
+    assert_eq!(owned.word.text, "hello");
+    assert_eq!(borrowme::borrow(&owned), entry);
+}
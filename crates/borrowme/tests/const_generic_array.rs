@@ -0,0 +1,44 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Bytes<'a, const N: usize>(&'a [u8; N]);
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Named<'a, const N: usize> {
+    name: &'a str,
+    bytes: &'a [u8; N],
+}
+
+#[borrowme]
+pub struct BytesMut<'a, const N: usize>(&'a mut [u8; N]);
+
+#[test]
+fn const_generic_array() {
+    let bytes = Bytes(&[1, 2, 3]);
+    let owned: OwnedBytes<3> = borrowme::to_owned(&bytes);
+    assert_eq!(owned.0, [1, 2, 3]);
+    assert_eq!(borrowme::borrow(&owned), bytes);
+
+    let named = Named {
+        name: "hello",
+        bytes: &[1, 2],
+    };
+
+    let owned: OwnedNamed<2> = borrowme::to_owned(&named);
+    assert_eq!(owned.name, "hello");
+    assert_eq!(owned.bytes, [1, 2]);
+    assert_eq!(borrowme::borrow(&owned), named);
+}
+
+#[test]
+fn const_generic_array_mut() {
+    let mut array = [1, 2, 3];
+    let bytes = BytesMut(&mut array);
+
+    let mut owned: OwnedBytesMut<3> = borrowme::to_owned(&bytes);
+    let borrowed = borrowme::borrow_mut(&mut owned);
+    borrowed.0[0] = 4;
+    assert_eq!(owned.0, [4, 2, 3]);
+}
@@ -0,0 +1,92 @@
+use borrowme::borrowme;
+
+#[borrowme(with = self::option)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    #[owned(Option<String>)]
+    text: Option<&'a str>,
+    #[owned(Option<String>)]
+    lang: Option<&'a str>,
+    #[borrowme(owned = Option<String>, to_owned_with = self::upper::to_owned, borrow_with = Option::as_deref)]
+    upper: Option<&'a str>,
+    #[copy]
+    count: u32,
+}
+
+#[borrowme(with = self::option)]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Entry<'a> {
+    Word(#[owned(Option<String>)] Option<&'a str>),
+    Pair {
+        #[owned(Option<String>)]
+        first: Option<&'a str>,
+        #[borrowme(skip)]
+        second: Option<&'a str>,
+    },
+}
+
+mod option {
+    use borrowme::{Borrow, ToOwned};
+
+    pub(crate) fn borrow<T>(this: &Option<T>) -> Option<T::Target<'_>>
+    where
+        T: Borrow,
+    {
+        this.as_ref().map(Borrow::borrow)
+    }
+
+    pub(crate) fn to_owned<T>(this: &Option<T>) -> Option<T::Owned>
+    where
+        T: ToOwned,
+    {
+        this.as_ref().map(ToOwned::to_owned)
+    }
+}
+
+mod upper {
+    pub(crate) fn to_owned(this: &Option<&str>) -> Option<String> {
+        this.map(str::to_uppercase)
+    }
+}
+
+#[test]
+fn container_with() {
+    let word = Word {
+        text: Some("hello"),
+        lang: None,
+        upper: Some("en"),
+        count: 42,
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(owned.text.as_deref(), Some("hello"));
+    assert_eq!(owned.lang, None);
+    assert_eq!(owned.upper.as_deref(), Some("EN"));
+    assert_eq!(owned.count, 42);
+
+    let word: Word<'_> = borrowme::borrow(&owned);
+    assert_eq!(word.text, Some("hello"));
+    assert_eq!(word.upper, Some("EN"));
+}
+
+#[test]
+fn container_with_enum() {
+    let entry = Entry::Word(Some("hello"));
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert_eq!(borrowme::borrow(&owned), entry);
+
+    let entry = Entry::Pair {
+        first: Some("hello"),
+        second: Some("world"),
+    };
+
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+
+    assert_eq!(
+        borrowme::borrow(&owned),
+        Entry::Pair {
+            first: Some("hello"),
+            second: None,
+        }
+    );
+}
@@ -0,0 +1,56 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    text: &'a str,
+}
+
+#[borrowme(from, leak)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Wrapper<'a, #[borrowme(convert)] T> {
+    value: T,
+    values: Vec<T>,
+    text: &'a str,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Either<'a, #[borrowme(convert)] L, R> {
+    Left(Option<L>),
+    Right(R),
+    Text(&'a str),
+}
+
+#[test]
+fn convert_params() {
+    let wrapper = Wrapper {
+        value: Word { text: "hello" },
+        values: vec![Word { text: "world" }],
+        text: "text",
+    };
+
+    let owned: OwnedWrapper<OwnedWord> = borrowme::to_owned(&wrapper);
+    assert_eq!(owned.value.text, "hello");
+    assert_eq!(owned.values[0].text, "world");
+    assert_eq!(owned.text, "text");
+
+    let borrowed: Wrapper<'_, Word<'_>> = borrowme::borrow(&owned);
+    assert_eq!(borrowed, wrapper);
+    assert_eq!(Wrapper::from(&owned), wrapper);
+
+    let leaked: Wrapper<'static, Word<'static>> = OwnedWrapper::from(wrapper).leak();
+    assert_eq!(leaked.value.text, "hello");
+}
+
+#[test]
+fn convert_params_enum() {
+    let left: Either<'_, Word<'_>, u32> = Either::Left(Some(Word { text: "hello" }));
+    let owned: OwnedEither<OwnedWord, u32> = borrowme::to_owned(&left);
+    assert!(matches!(&owned, OwnedEither::Left(Some(word)) if word.text == "hello"));
+    assert_eq!(borrowme::borrow(&owned), left);
+
+    let right: Either<'_, Word<'_>, String> = Either::Right(String::from("world"));
+    let owned: OwnedEither<OwnedWord, String> = borrowme::to_owned(&right);
+    assert_eq!(borrowme::borrow(&owned), right);
+}
@@ -1,3 +1,11 @@
+#![allow(dead_code)]
+
+use core::marker::PhantomData;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128, NonZeroU16,
+    NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
 use borrowme::borrowme;
 
 #[allow(non_camel_case_types)]
@@ -30,6 +38,7 @@ struct MixedFields<'a> {
     primitive_i64: i64,
     primitive_i128: i128,
     primitive_isize: isize,
+    primitive_char: char,
     tuple_empty: (),
     tuple_copy: (u32, u32),
     array_copy: [u32; 8],
@@ -39,3 +48,168 @@ struct MixedFields<'a> {
     owned_list: Vec<String>,
     external_type: Option<ExternalType<'a>>,
 }
+
+#[borrowme]
+#[derive(Debug, PartialEq)]
+struct Point<'a> {
+    _marker: PhantomData<&'a ()>,
+    x: u32,
+    y: u32,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq)]
+struct Delimited<'a> {
+    text: &'a str,
+    delimiter: char,
+}
+
+#[borrowme]
+#[derive(Clone, Debug, PartialEq)]
+#[borrowed_attr(derive(Copy))]
+struct Token<'a> {
+    text: &'a str,
+    start: u32,
+    end: u32,
+    kind: Option<char>,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq)]
+struct Ids<'a> {
+    text: &'a str,
+    u8: NonZeroU8,
+    u16: NonZeroU16,
+    u32: core::num::NonZeroU32,
+    u64: NonZeroU64,
+    u128: NonZeroU128,
+    usize: NonZeroUsize,
+    i8: NonZeroI8,
+    i16: NonZeroI16,
+    i32: std::num::NonZeroI32,
+    i64: NonZeroI64,
+    i128: NonZeroI128,
+    isize: NonZeroIsize,
+    optional: Option<NonZeroU64>,
+}
+
+mod shadowed {
+    use borrowme::borrowme;
+
+    /// A type which shadows the non-zero integer and isn't `Copy`.
+    #[derive(Clone)]
+    pub(crate) struct NonZeroU32;
+
+    impl borrowme::ToOwned for NonZeroU32 {
+        type Owned = NonZeroU32;
+
+        fn to_owned(&self) -> Self::Owned {
+            NonZeroU32
+        }
+    }
+
+    impl borrowme::Borrow for NonZeroU32 {
+        type Target<'a> = NonZeroU32;
+
+        fn borrow(&self) -> Self::Target<'_> {
+            NonZeroU32
+        }
+    }
+
+    #[borrowme]
+    pub(crate) struct Shadowed<'a> {
+        pub(crate) text: &'a str,
+        #[no_copy]
+        pub(crate) id: NonZeroU32,
+    }
+}
+
+#[borrowme]
+struct Markers<'a, T> {
+    reference: PhantomData<&'a T>,
+    nested: PhantomData<(&'a str, &'a [T])>,
+    function: PhantomData<fn(&'a ())>,
+}
+
+#[test]
+fn copy_only_round_trip() {
+    let point = Point {
+        _marker: PhantomData,
+        x: 1,
+        y: 2,
+    };
+
+    let owned: OwnedPoint = borrowme::to_owned(&point);
+    assert_eq!(owned.x, 1);
+    assert_eq!(owned.y, 2);
+    assert_eq!(borrowme::borrow(&owned), point);
+}
+
+#[test]
+fn copy_borrowed_struct() {
+    let token = Token {
+        text: "hello",
+        start: 0,
+        end: 5,
+        kind: Some('w'),
+    };
+
+    // The borrowed variant is `Copy`, so it can still be used after being moved.
+    let copied = token;
+    let owned: OwnedToken = borrowme::to_owned(&copied);
+    assert_eq!(owned.text, "hello");
+    assert_eq!(owned.end, 5);
+
+    let borrowed: Token<'_> = borrowme::borrow(&owned);
+    let copied = borrowed;
+    assert_eq!(borrowed, token);
+    assert_eq!(copied, token);
+}
+
+#[test]
+fn copy_char() {
+    let delimited = Delimited {
+        text: "a,b",
+        delimiter: ',',
+    };
+
+    let owned: OwnedDelimited = borrowme::to_owned(&delimited);
+    let _: char = owned.delimiter;
+    assert_eq!(owned.delimiter, ',');
+    assert_eq!(borrowme::borrow(&owned), delimited);
+}
+
+#[test]
+fn copy_non_zero() {
+    let ids = Ids {
+        text: "id",
+        u8: NonZeroU8::new(1).unwrap(),
+        u16: NonZeroU16::new(2).unwrap(),
+        u32: core::num::NonZeroU32::new(3).unwrap(),
+        u64: NonZeroU64::new(4).unwrap(),
+        u128: NonZeroU128::new(5).unwrap(),
+        usize: NonZeroUsize::new(6).unwrap(),
+        i8: NonZeroI8::new(-1).unwrap(),
+        i16: NonZeroI16::new(-2).unwrap(),
+        i32: std::num::NonZeroI32::new(-3).unwrap(),
+        i64: NonZeroI64::new(-4).unwrap(),
+        i128: NonZeroI128::new(-5).unwrap(),
+        isize: NonZeroIsize::new(-6).unwrap(),
+        optional: NonZeroU64::new(7),
+    };
+
+    let owned: OwnedIds = borrowme::to_owned(&ids);
+    let _: core::num::NonZeroU32 = owned.u32;
+    let _: Option<NonZeroU64> = owned.optional;
+    assert_eq!(owned.u8.get(), 1);
+    assert_eq!(owned.isize.get(), -6);
+    assert_eq!(borrowme::borrow(&owned), ids);
+
+    let shadowed = shadowed::Shadowed {
+        text: "id",
+        id: shadowed::NonZeroU32,
+    };
+
+    let owned: shadowed::OwnedShadowed = borrowme::to_owned(&shadowed);
+    let _: shadowed::Shadowed<'_> = borrowme::borrow(&owned);
+}
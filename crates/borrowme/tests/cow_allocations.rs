@@ -0,0 +1,50 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    let value = f();
+    (value, ALLOCATIONS.load(Ordering::SeqCst) - before)
+}
+
+// NB: This is a single test since the allocation counter is shared with any
+// other test running in parallel.
+#[test]
+fn cow_allocations() {
+    let borrowed: Cow<'_, str> = Cow::Borrowed("hello");
+    let (owned, n) = allocations(|| borrowme::to_owned(&borrowed));
+    assert_eq!(owned, "hello");
+    assert_eq!(n, 1);
+
+    let owned_cow: Cow<'_, str> = Cow::Owned(String::from("hello"));
+    let (owned, n) = allocations(|| borrowme::to_owned(&owned_cow));
+    assert_eq!(owned, "hello");
+    assert_eq!(n, 1);
+
+    let (owned, n) = allocations(|| borrowme::cow::into_static(owned_cow));
+    assert_eq!(owned, "hello");
+    assert_eq!(n, 0);
+
+    let (owned, n) = allocations(|| borrowme::cow::into_static(borrowed));
+    assert_eq!(owned, "hello");
+    assert_eq!(n, 1);
+}
@@ -0,0 +1,53 @@
+use std::borrow::Cow;
+use std::path::Path;
+
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Word<'a> {
+    #[borrowme(cow)]
+    text: &'a str,
+    #[borrowme(cow)]
+    path: &'a Path,
+}
+
+#[borrowme]
+#[borrowme(std)]
+#[derive(Debug, PartialEq, Eq)]
+enum Entry<'a> {
+    Text(#[borrowme(cow)] &'a str),
+    Bytes {
+        #[borrowme(cow)]
+        bytes: &'a [u8],
+    },
+}
+
+#[test]
+fn cow_field() {
+    let word = Word {
+        text: "hello",
+        path: Path::new("/tmp"),
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    let text: &Cow<'static, str> = &owned.text;
+    assert!(matches!(text, Cow::Owned(text) if text == "hello"));
+    assert_eq!(owned.path, Path::new("/tmp"));
+    assert_eq!(borrowme::borrow(&owned), word);
+
+    let owned = OwnedWord {
+        text: Cow::Borrowed("static"),
+        path: Cow::Borrowed(Path::new("/")),
+    };
+
+    assert_eq!(borrowme::borrow(&owned).text, "static");
+
+    let entry = Entry::Text("world");
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert_eq!(borrowme::borrow(&owned), entry);
+
+    let entry = Entry::Bytes { bytes: b"bytes" };
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert_eq!(borrowme::borrow(&owned), entry);
+}
@@ -0,0 +1,65 @@
+mod facade {
+    pub mod reexport {
+        pub use borrowme::*;
+    }
+}
+
+use facade::reexport::{Borrow, ToOwned};
+
+#[facade::reexport::borrowme(crate = facade::reexport)]
+#[derive(Debug, PartialEq, Eq)]
+struct Word<'a> {
+    text: &'a str,
+}
+
+#[facade::reexport::borrowme]
+#[borrowme(crate = facade::reexport)]
+#[repr(C)]
+union Payload {
+    #[copy]
+    int: u32,
+    bytes: [u8; 4],
+}
+
+#[derive(ToOwned)]
+#[to_owned(target = OwnedLang, crate = facade::reexport)]
+struct Lang<'a> {
+    name: &'a str,
+}
+
+#[derive(Borrow)]
+#[borrow(target = Lang<'a>, crate = facade::reexport)]
+struct OwnedLang {
+    name: String,
+}
+
+struct Text<'a> {
+    text: &'a str,
+}
+
+struct OwnedText {
+    text: String,
+}
+
+facade::reexport::impl_borrow! {
+    #[borrowme(crate = facade::reexport)]
+    Text<'a> => OwnedText { text }
+}
+
+#[test]
+fn crate_path() {
+    let word = Word { text: "hello" };
+    let owned: OwnedWord = facade::reexport::to_owned(&word);
+    assert_eq!(facade::reexport::borrow(&owned), word);
+
+    let owned: OwnedPayload = facade::reexport::to_owned(&Payload { int: 42 });
+    assert_eq!(unsafe { owned.bytes }, 42u32.to_ne_bytes());
+
+    let owned: OwnedLang = facade::reexport::to_owned(&Lang { name: "en" });
+    let lang: Lang<'_> = facade::reexport::borrow(&owned);
+    assert_eq!(lang.name, "en");
+
+    let owned: OwnedText = facade::reexport::to_owned(&Text { text: "world" });
+    let text: Text<'_> = facade::reexport::borrow(&owned);
+    assert_eq!(text.text, "world");
+}
@@ -0,0 +1,66 @@
+use std::marker::PhantomData;
+
+use borrowme::borrowme;
+
+#[borrowme(debug)]
+pub struct Word<'a> {
+    text: &'a str,
+    count: u32,
+}
+
+#[borrowme(debug)]
+pub struct Tuple<'a>(&'a str, Option<&'a str>);
+
+#[borrowme(debug)]
+pub struct Unit;
+
+#[borrowme(debug)]
+pub enum Entry<'a> {
+    Text { text: &'a str },
+    Tuple(&'a str),
+    Empty,
+}
+
+#[borrowme(debug)]
+pub struct Generic<'a, T: ?Sized> {
+    value: &'a T,
+    marker: PhantomData<&'a str>,
+}
+
+#[test]
+fn debug() {
+    let word = Word {
+        text: "hello",
+        count: 1,
+    };
+
+    assert_eq!(format!("{word:?}"), r#"Word { text: "hello", count: 1 }"#);
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(
+        format!("{owned:?}"),
+        r#"OwnedWord { text: "hello", count: 1 }"#
+    );
+
+    let tuple = Tuple("a", Some("b"));
+    assert_eq!(format!("{tuple:?}"), r#"Tuple("a", Some("b"))"#);
+    let owned: OwnedTuple = borrowme::to_owned(&tuple);
+    assert_eq!(format!("{owned:?}"), r#"OwnedTuple("a", Some("b"))"#);
+
+    assert_eq!(format!("{:?}", Unit), "Unit");
+    assert_eq!(format!("{:?}", OwnedUnit), "OwnedUnit");
+
+    let entry = Entry::Text { text: "hello" };
+    assert_eq!(format!("{entry:?}"), r#"Text { text: "hello" }"#);
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert_eq!(format!("{owned:?}"), r#"Text { text: "hello" }"#);
+    assert_eq!(format!("{:?}", Entry::Tuple("a")), r#"Tuple("a")"#);
+    assert_eq!(format!("{:?}", OwnedEntry::Empty), "Empty");
+
+    let generic = Generic::<str> {
+        value: "hello",
+        marker: PhantomData,
+    };
+
+    let owned: OwnedGeneric<str> = borrowme::to_owned(&generic);
+    assert!(format!("{owned:?}").starts_with(r#"OwnedGeneric { value: "hello", marker: "#));
+}
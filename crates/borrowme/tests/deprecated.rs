@@ -0,0 +1,73 @@
+#![deny(deprecated)]
+
+use borrowme::borrowme;
+
+#[borrowme]
+#[deprecated = "use Word instead"]
+#[derive(Debug)]
+pub struct OldWord<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+#[deprecated = "use Word instead"]
+#[owned_attr(deprecated = "use OwnedWord instead")]
+pub struct LegacyWord<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+pub enum Entry<'a> {
+    #[deprecated]
+    Text(&'a str),
+    Word {
+        #[deprecated]
+        text: &'a str,
+    },
+}
+
+#[deprecated = "use Word instead"]
+#[derive(borrowme::ToOwned)]
+#[to_owned(target = OwnedDerivedWord)]
+pub struct DerivedWord<'a> {
+    text: &'a str,
+}
+
+#[deprecated = "use OwnedWord instead"]
+#[derive(borrowme::Borrow)]
+#[borrow(target = DerivedWord<'a>)]
+pub struct OwnedDerivedWord {
+    text: String,
+}
+
+#[test]
+fn owned_not_deprecated() {
+    let owned = OwnedOldWord {
+        text: String::from("hello"),
+    };
+
+    assert_eq!(owned.text, "hello");
+
+    let _ = OwnedEntry::Text(String::from("hello"));
+    let _ = OwnedEntry::Word {
+        text: String::from("hello"),
+    };
+}
+
+#[test]
+#[allow(deprecated)]
+fn borrowed_deprecated() {
+    let word = OldWord { text: "hello" };
+    let owned: OwnedOldWord = borrowme::to_owned(&word);
+    let word: OldWord<'_> = borrowme::borrow(&owned);
+    assert_eq!(word.text, "hello");
+
+    let word = LegacyWord { text: "hello" };
+    let owned: OwnedLegacyWord = borrowme::to_owned(&word);
+    assert_eq!(owned.text, "hello");
+
+    let word = DerivedWord { text: "hello" };
+    let owned: OwnedDerivedWord = borrowme::to_owned(&word);
+    let word: DerivedWord<'_> = borrowme::borrow(&owned);
+    assert_eq!(word.text, "hello");
+}
@@ -0,0 +1,78 @@
+use std::ops::{Deref, DerefMut};
+
+use borrowme::borrowme;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Name(String);
+
+impl Deref for Name {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl DerefMut for Name {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut str {
+        &mut self.0
+    }
+}
+
+fn name_from(value: &str) -> Name {
+    Name(value.to_owned())
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    #[borrowme(owned = Name, to_owned_with = name_from, deref_borrow)]
+    text: &'a str,
+    #[borrowme(owned = Box<str>, to_owned_with = Box::from, deref_borrow)]
+    lang: &'a str,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Entry<'a> {
+    Text(#[borrowme(owned = Name, to_owned_with = name_from, deref_borrow)] &'a str),
+}
+
+#[borrowme]
+pub struct Mutable<'a> {
+    #[borrowme(owned = Name, to_owned_with = name_from, deref_borrow)]
+    text: &'a mut str,
+}
+
+#[test]
+fn deref_borrow() {
+    let word = Word {
+        text: "hello",
+        lang: "en",
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(owned.text, Name(String::from("hello")));
+    assert_eq!(&*owned.lang, "en");
+    assert_eq!(borrowme::borrow(&owned), word);
+
+    let entry = Entry::Text("world");
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert_eq!(borrowme::borrow(&owned), entry);
+}
+
+#[test]
+fn deref_borrow_mut() {
+    let mut text = String::from("hello");
+
+    let mutable = Mutable {
+        text: text.as_mut_str(),
+    };
+
+    let mut owned: OwnedMutable = borrowme::to_owned(&mutable);
+    let borrowed = borrowme::borrow_mut(&mut owned);
+    borrowed.text.make_ascii_uppercase();
+    assert_eq!(owned.text, Name(String::from("HELLO")));
+}
@@ -0,0 +1,78 @@
+use borrowme::{Borrow, ToOwned};
+
+#[derive(Debug, PartialEq, ToOwned)]
+#[to_owned(target = OwnedWord)]
+struct Word<'a> {
+    text: &'a str,
+    #[to_owned(copy)]
+    count: u32,
+    #[to_owned(to_owned_with = upper)]
+    upper: &'a str,
+}
+
+#[derive(Debug, PartialEq, Borrow)]
+#[borrow(target = Word<'a>)]
+struct OwnedWord {
+    text: String,
+    #[borrow(copy)]
+    count: u32,
+    #[borrow(borrow_with = String::as_str)]
+    upper: String,
+}
+
+fn upper(value: &&str) -> String {
+    value.to_uppercase()
+}
+
+#[derive(Debug, PartialEq, ToOwned)]
+#[to_owned(target = OwnedPair)]
+struct Pair<'a>(&'a str, Word<'a>);
+
+#[derive(Debug, PartialEq, Borrow)]
+#[borrow(target = Pair<'a>)]
+struct OwnedPair(String, OwnedWord);
+
+#[test]
+fn named_fields() {
+    let word = Word {
+        text: "hello",
+        count: 42,
+        upper: "world",
+    };
+
+    let owned = borrowme::to_owned(&word);
+
+    assert_eq!(
+        owned,
+        OwnedWord {
+            text: String::from("hello"),
+            count: 42,
+            upper: String::from("WORLD"),
+        }
+    );
+
+    let word = borrowme::borrow(&owned);
+    assert_eq!(word.text, "hello");
+    assert_eq!(word.count, 42);
+    assert_eq!(word.upper, "WORLD");
+}
+
+#[test]
+fn unnamed_fields() {
+    let pair = Pair(
+        "first",
+        Word {
+            text: "second",
+            count: 1,
+            upper: "third",
+        },
+    );
+
+    let owned = borrowme::to_owned(&pair);
+    assert_eq!(owned.0, "first");
+    assert_eq!(owned.1.upper, "THIRD");
+
+    let pair = borrowme::borrow(&owned);
+    assert_eq!(pair.0, "first");
+    assert_eq!(pair.1.text, "second");
+}
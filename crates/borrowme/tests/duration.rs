@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant, SystemTime};
+
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Timings<'a> {
+    name: &'a str,
+    total: Duration,
+    laps: Vec<Duration>,
+    timeout: Option<Duration>,
+    by_name: HashMap<&'a str, &'a Duration>,
+    fastest: Option<&'a Duration>,
+}
+
+#[borrowme]
+pub struct TimingsMut<'a> {
+    laps: Vec<&'a mut Duration>,
+}
+
+#[test]
+fn duration() {
+    let second = Duration::from_secs(1);
+
+    let timings = Timings {
+        name: "race",
+        total: Duration::from_secs(3),
+        laps: vec![Duration::from_secs(1), Duration::from_secs(2)],
+        timeout: Some(Duration::from_secs(10)),
+        by_name: HashMap::from([("first", &second)]),
+        fastest: Some(&second),
+    };
+
+    let owned: OwnedTimings = borrowme::to_owned(&timings);
+    let _: &HashMap<String, Duration> = &owned.by_name;
+    let _: Option<Duration> = owned.fastest;
+    assert_eq!(owned.total, Duration::from_secs(3));
+    assert_eq!(owned.by_name["first"], second);
+    assert_eq!(owned.fastest, Some(second));
+    assert_eq!(borrowme::borrow(&owned), timings);
+
+    let mut lap = Duration::from_secs(1);
+
+    let mut owned: OwnedTimingsMut = borrowme::to_owned(&TimingsMut {
+        laps: vec![&mut lap],
+    });
+
+    for lap in borrowme::borrow_mut(&mut owned).laps {
+        *lap += Duration::from_secs(1);
+    }
+
+    assert_eq!(owned.laps, [Duration::from_secs(2)]);
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Event<'a> {
+    name: &'a str,
+    at: SystemTime,
+    started: Instant,
+    deadlines: Vec<SystemTime>,
+    last: Option<&'a Instant>,
+}
+
+#[borrowme]
+pub struct EventMut<'a> {
+    at: &'a mut SystemTime,
+}
+
+#[test]
+fn points_in_time() {
+    let started = Instant::now();
+    let at = SystemTime::UNIX_EPOCH + Duration::from_secs(60);
+
+    let event = Event {
+        name: "launch",
+        at,
+        started,
+        deadlines: vec![at + Duration::from_secs(1)],
+        last: Some(&started),
+    };
+
+    let owned: OwnedEvent = borrowme::to_owned(&event);
+    let _: SystemTime = owned.at;
+    let _: Option<Instant> = owned.last;
+    assert_eq!(owned.started, started);
+    assert_eq!(owned.deadlines, [at + Duration::from_secs(1)]);
+    assert_eq!(borrowme::borrow(&owned), event);
+
+    let mut at = SystemTime::UNIX_EPOCH;
+    let mut owned: OwnedEventMut = borrowme::to_owned(&EventMut { at: &mut at });
+    *borrowme::borrow_mut(&mut owned).at += Duration::from_secs(1);
+    assert_eq!(owned.at, SystemTime::UNIX_EPOCH + Duration::from_secs(1));
+}
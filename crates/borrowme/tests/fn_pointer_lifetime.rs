@@ -0,0 +1,37 @@
+use borrowme::borrowme;
+
+fn len(value: &str) -> usize {
+    value.len()
+}
+
+fn first(value: &str) -> &str {
+    &value[..1]
+}
+
+#[borrowme]
+pub struct Callback<'a> {
+    name: &'a str,
+    len: fn(&str) -> usize,
+    first: for<'x> fn(&'x str) -> &'x str,
+    fallback: Option<fn(&'static str) -> usize>,
+}
+
+#[test]
+fn fn_pointer_lifetime() {
+    let callback = Callback {
+        name: "hello",
+        len,
+        first,
+        fallback: Some(len),
+    };
+
+    let owned: OwnedCallback = borrowme::to_owned(&callback);
+    assert_eq!(owned.name, "hello");
+    assert_eq!((owned.len)(&owned.name), 5);
+    assert_eq!((owned.first)(&owned.name), "h");
+
+    let borrowed: Callback<'_> = borrowme::borrow(&owned);
+    assert_eq!((borrowed.len)(borrowed.name), 5);
+    assert_eq!((borrowed.first)(borrowed.name), "h");
+    assert_eq!(borrowed.fallback.map(|f| f("world")), Some(5));
+}
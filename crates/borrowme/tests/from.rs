@@ -0,0 +1,68 @@
+use borrowme::borrowme;
+
+#[borrowme(from)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    text: &'a str,
+    lang: Option<&'a str>,
+}
+
+#[borrowme(from)]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Entry<'a, T> {
+    Word(Word<'a>),
+    Value(T),
+}
+
+#[borrowme(from)]
+pub struct WordMut<'a> {
+    #[borrowme(mut)]
+    text: &'a mut String,
+}
+
+#[test]
+fn from() {
+    let word = Word {
+        text: "hello",
+        lang: Some("en"),
+    };
+
+    let owned = OwnedWord::from(Word {
+        text: "hello",
+        lang: Some("en"),
+    });
+
+    assert_eq!(owned.text, "hello");
+    assert_eq!(Word::from(&owned), word);
+
+    let borrowed: Word<'_> = (&owned).into();
+    assert_eq!(borrowed, word);
+
+    let entry = Entry::<u32>::Word(Word {
+        text: "hello",
+        lang: None,
+    });
+
+    let owned = OwnedEntry::from(entry);
+    assert_eq!(
+        Entry::from(&owned),
+        Entry::Word(Word {
+            text: "hello",
+            lang: None,
+        })
+    );
+
+    let owned = OwnedEntry::from(Entry::<u32>::Value(42));
+    assert_eq!(Entry::from(&owned), Entry::Value(42));
+}
+
+#[test]
+fn from_mut() {
+    let mut owned = OwnedWordMut {
+        text: String::from("hello"),
+    };
+
+    let word = WordMut::from(&mut owned);
+    word.text.push_str(" world");
+    assert_eq!(owned.text, "hello world");
+}
@@ -0,0 +1,64 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Either<'a, T> {
+    Left(&'a str),
+    Right(T),
+}
+
+#[borrowme]
+#[borrowed_attr(derive(Debug, PartialEq, Eq))]
+pub enum Node<'a, T, U: ?Sized + borrowme::ToOwned> {
+    Leaf { value: Option<T> },
+    Reference { name: &'a str, value: &'a U },
+    Empty,
+}
+
+#[borrowme]
+pub enum Mutable<'a, T> {
+    Text(&'a mut String),
+    Value(T),
+}
+
+struct NotClone;
+
+#[test]
+fn generic_enum() {
+    let left: Either<'_, u32> = Either::Left("hello");
+    let owned: OwnedEither<u32> = borrowme::to_owned(&left);
+    assert_eq!(owned, OwnedEither::Left(String::from("hello")));
+    assert_eq!(borrowme::borrow(&owned), left);
+
+    let right: Either<'_, u32> = Either::Right(42);
+    let owned: OwnedEither<u32> = borrowme::to_owned(&right);
+    assert_eq!(owned, OwnedEither::Right(42));
+    assert_eq!(borrowme::borrow(&owned), right);
+
+    let leaf: Node<'_, String, str> = Node::Leaf {
+        value: Some(String::from("hello")),
+    };
+    let owned: OwnedNode<String, str> = borrowme::to_owned(&leaf);
+    assert_eq!(borrowme::borrow(&owned), leaf);
+
+    let reference: Node<'_, String, str> = Node::Reference {
+        name: "hello",
+        value: "world",
+    };
+    let owned: OwnedNode<String, str> = borrowme::to_owned(&reference);
+    assert_eq!(borrowme::borrow(&owned), reference);
+
+    let mut text = String::from("hello");
+    let mutable: Mutable<'_, u32> = Mutable::Text(&mut text);
+    let mut owned: OwnedMutable<u32> = borrowme::to_owned(&mutable);
+
+    if let Mutable::Text(text) = borrowme::borrow_mut(&mut owned) {
+        text.push_str(" world");
+    }
+
+    assert!(matches!(&owned, OwnedMutable::Text(text) if text == "hello world"));
+
+    // The owned enum itself doesn't require that the parameter is `Clone`,
+    // only the conversions do.
+    let _ = OwnedEither::<NotClone>::Right(NotClone);
+}
@@ -0,0 +1,48 @@
+use borrowme::borrowme;
+
+#[borrowme]
+struct Wrap<'a, T: ?Sized + borrowme::ToOwned> {
+    value: &'a T,
+    text: &'a str,
+}
+
+#[borrowme]
+enum Either<'a, T> {
+    Left(&'a T),
+    Right { text: &'a str },
+}
+
+#[borrowme]
+struct WrapMut<'a, T> {
+    value: &'a mut T,
+}
+
+#[test]
+fn generic_reference() {
+    let wrap = Wrap::<str> {
+        value: "hello",
+        text: "world",
+    };
+
+    let owned: OwnedWrap<str> = borrowme::to_owned(&wrap);
+    assert_eq!(owned.value, "hello");
+    assert_eq!(owned.text, "world");
+
+    let wrap: Wrap<'_, str> = borrowme::borrow(&owned);
+    assert_eq!(wrap.value, "hello");
+    assert_eq!(wrap.text, "world");
+
+    let value = String::from("hello");
+    let either = Either::Left(&value);
+    let owned: OwnedEither<String> = borrowme::to_owned(&either);
+    assert!(matches!(&owned, OwnedEither::Left(value) if value == "hello"));
+    let either: Either<'_, String> = borrowme::borrow(&owned);
+    assert!(matches!(either, Either::Left(value) if value == "hello"));
+
+    let mut value = String::from("hello");
+    let wrap = WrapMut { value: &mut value };
+    let mut owned: OwnedWrapMut<String> = borrowme::to_owned(&wrap);
+    let wrap: WrapMut<'_, String> = borrowme::borrow_mut(&mut owned);
+    wrap.value.push_str(" world");
+    assert_eq!(owned.value, "hello world");
+}
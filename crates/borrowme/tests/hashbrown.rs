@@ -0,0 +1,65 @@
+#![cfg(feature = "hashbrown")]
+
+use std::collections::hash_map::RandomState;
+
+use borrowme::borrowme;
+use hashbrown::{HashMap, HashSet};
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Word<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Dictionary<'a> {
+    words: HashMap<&'a str, Word<'a>, RandomState>,
+    tags: HashSet<&'a str, RandomState>,
+}
+
+#[borrowme]
+struct DictionaryMut<'a> {
+    words: HashMap<&'a str, &'a mut String, RandomState>,
+}
+
+#[test]
+fn hashbrown_round_trip() {
+    let mut dictionary = Dictionary {
+        words: HashMap::with_hasher(RandomState::new()),
+        tags: HashSet::with_hasher(RandomState::new()),
+    };
+
+    dictionary.words.insert("hello", Word { text: "world" });
+    dictionary.words.insert("goodbye", Word { text: "moon" });
+    dictionary.tags.insert("greeting");
+
+    let owned: OwnedDictionary = borrowme::to_owned(&dictionary);
+    assert_eq!(owned.words.len(), 2);
+    assert_eq!(owned.words["hello"].text, "world");
+    assert!(owned.tags.contains("greeting"));
+
+    let borrowed: Dictionary<'_> = borrowme::borrow(&owned);
+    assert_eq!(borrowed, dictionary);
+}
+
+#[test]
+fn hashbrown_borrow_mut() {
+    let mut owned = OwnedDictionaryMut {
+        words: HashMap::with_hasher(RandomState::new()),
+    };
+
+    owned
+        .words
+        .insert(String::from("hello"), String::from("world"));
+
+    {
+        let view: DictionaryMut<'_> = borrowme::borrow_mut(&mut owned);
+
+        for value in view.words.into_values() {
+            value.make_ascii_uppercase();
+        }
+    }
+
+    assert_eq!(owned.words["hello"], "WORLD");
+}
@@ -0,0 +1,117 @@
+use borrowme::impl_borrow;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Word<'a> {
+    text: &'a str,
+    count: u32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct OwnedWord {
+    text: String,
+    count: u32,
+}
+
+impl_borrow! {
+    Word<'a> => OwnedWord {
+        text,
+        count: copy,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Sentence<'a, T> {
+    words: Vec<Word<'a>>,
+    lang: &'a str,
+    tag: T,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct OwnedSentence<T> {
+    words: Vec<OwnedWord>,
+    lang: Box<str>,
+    tag: T,
+}
+
+impl_borrow! {
+    Sentence<'a, T: Copy> => OwnedSentence {
+        words,
+        lang: (to_owned_with = boxed, borrow_with = AsRef::as_ref),
+        tag: copy,
+    }
+}
+
+fn boxed(value: &&str) -> Box<str> {
+    Box::from(*value)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Pair<'a>(&'a str, Option<&'a str>);
+
+#[derive(Debug, PartialEq, Eq)]
+struct OwnedPair(String, Option<String>);
+
+impl_borrow! {
+    Pair<'a> => OwnedPair {
+        0,
+        1: with = self::second,
+    }
+}
+
+mod second {
+    pub(crate) fn to_owned(value: &Option<&str>) -> Option<String> {
+        value.map(String::from)
+    }
+
+    pub(crate) fn borrow(value: &Option<String>) -> Option<&str> {
+        value.as_deref()
+    }
+}
+
+#[test]
+fn impl_borrow() {
+    let word = Word {
+        text: "hello",
+        count: 42,
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+
+    assert_eq!(
+        owned,
+        OwnedWord {
+            text: String::from("hello"),
+            count: 42,
+        }
+    );
+
+    assert_eq!(borrowme::borrow(&owned), word);
+}
+
+#[test]
+fn impl_borrow_generic() {
+    let sentence = Sentence {
+        words: vec![Word {
+            text: "hello",
+            count: 1,
+        }],
+        lang: "en",
+        tag: 'a',
+    };
+
+    let owned: OwnedSentence<char> = borrowme::to_owned(&sentence);
+    assert_eq!(&*owned.lang, "en");
+    assert_eq!(owned.words[0].text, "hello");
+    assert_eq!(borrowme::borrow(&owned), sentence);
+}
+
+#[test]
+fn impl_borrow_tuple() {
+    let pair = Pair("hello", Some("world"));
+    let owned: OwnedPair = borrowme::to_owned(&pair);
+    assert_eq!(
+        owned,
+        OwnedPair(String::from("hello"), Some(String::from("world")))
+    );
+    assert_eq!(borrowme::borrow(&owned), pair);
+}
@@ -0,0 +1,24 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[borrowme(inline = always)]
+#[derive(Debug, PartialEq, Eq)]
+struct Word<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+#[borrowme(inline = never, borrow_ref)]
+#[derive(Debug, PartialEq, Eq)]
+enum Entry<'a> {
+    Word(Word<'a>),
+    Text(&'a str),
+}
+
+#[test]
+fn inline() {
+    let entry = Entry::Word(Word { text: "hello" });
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert_eq!(borrowme::borrow(&owned), entry);
+    assert_eq!(owned.borrow_ref(), entry);
+}
@@ -0,0 +1,55 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[borrowme(leak)]
+#[derive(Debug, PartialEq, Eq)]
+struct Config<'a> {
+    name: &'a str,
+    paths: Vec<&'a str>,
+}
+
+#[borrowme]
+#[borrowme(leak)]
+enum Entry<'a> {
+    Word(&'a str),
+    Text(&'a mut String),
+}
+
+fn requires_static(config: Config<'static>) -> &'static str {
+    config.name
+}
+
+#[test]
+fn leak() {
+    let owned = OwnedConfig {
+        name: String::from("app"),
+        paths: vec![String::from("/etc")],
+    };
+
+    let config = owned.leak();
+    assert_eq!(config.paths, ["/etc"]);
+    assert_eq!(requires_static(config), "app");
+
+    let owned = OwnedConfig {
+        name: String::from("other"),
+        paths: Vec::new(),
+    };
+
+    let config: Config<'static> = borrowme::leak(owned);
+    assert_eq!(config.name, "other");
+}
+
+#[test]
+fn leak_mut() {
+    let owned = OwnedEntry::Text(String::from("hello"));
+
+    let Entry::Text(text) = owned.leak() else {
+        panic!("expected text");
+    };
+
+    text.push_str(" world");
+    assert_eq!(text, "hello world");
+
+    let owned = OwnedEntry::Word(String::from("word"));
+    assert!(matches!(owned.leak(), Entry::Word("word")));
+}
@@ -0,0 +1,66 @@
+use borrowme::borrowme;
+
+macro_rules! record {
+    ($name:ident { $($field:ident: $ty:ty),* $(,)? }) => {
+        #[borrowme]
+        #[derive(Debug, PartialEq, Eq)]
+        pub struct $name<'a> {
+            $($field: $ty,)*
+        }
+    };
+}
+
+macro_rules! word {
+    ($name:ident) => {
+        #[borrowme]
+        #[derive(Debug, PartialEq, Eq)]
+        pub enum $name<'a> {
+            Text(&'a str),
+            Count(u32),
+        }
+    };
+}
+
+record!(Word { text: &'a str, lang: Option<&'a str>, count: u32 });
+word!(Entry);
+
+// Generated paths are absolute, so a local module with the same name as the
+// crate doesn't interfere with them.
+mod shadowed {
+    #[allow(dead_code)]
+    mod borrowme {}
+
+    macro_rules! shadowed {
+        ($name:ident) => {
+            #[::borrowme::borrowme(borrow_ref)]
+            pub struct $name<'a> {
+                pub text: &'a str,
+            }
+        };
+    }
+
+    shadowed!(Word);
+}
+
+#[test]
+fn macro_rules() {
+    let word = Word {
+        text: "hello",
+        lang: Some("en"),
+        count: 1,
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(owned.text, "hello");
+    assert_eq!(owned.lang.as_deref(), Some("en"));
+    assert_eq!(borrowme::borrow(&owned), word);
+
+    let entry = Entry::Text("hello");
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert_eq!(borrowme::borrow(&owned), entry);
+
+    let word = shadowed::Word { text: "hello" };
+    let owned: shadowed::OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(owned.text, "hello");
+    assert_eq!(owned.borrow_ref().text, "hello");
+}
@@ -0,0 +1,115 @@
+#![cfg(debug_assertions)]
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+
+/// A key which compares case-insensitively once owned.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Key<'a>(&'a str);
+
+#[derive(Clone)]
+struct OwnedKey(String);
+
+impl PartialEq for OwnedKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+}
+
+impl Eq for OwnedKey {}
+
+impl PartialOrd for OwnedKey {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OwnedKey {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0
+            .to_ascii_lowercase()
+            .cmp(&other.0.to_ascii_lowercase())
+    }
+}
+
+impl Hash for OwnedKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_ascii_lowercase().hash(state);
+    }
+}
+
+impl borrowme::ToOwned for Key<'_> {
+    type Owned = OwnedKey;
+
+    fn to_owned(&self) -> OwnedKey {
+        OwnedKey(String::from(self.0))
+    }
+}
+
+/// An owned key which compares case-insensitively once borrowed.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct OwnedName(String);
+
+struct Name<'a>(&'a str);
+
+impl PartialEq for Name<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(other.0)
+    }
+}
+
+impl Eq for Name<'_> {}
+
+impl PartialOrd for Name<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Name<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0
+            .to_ascii_lowercase()
+            .cmp(&other.0.to_ascii_lowercase())
+    }
+}
+
+impl borrowme::Borrow for OwnedName {
+    type Target<'a> = Name<'a>;
+
+    fn borrow(&self) -> Name<'_> {
+        Name(&self.0)
+    }
+}
+
+#[test]
+fn distinct_keys() {
+    let map: BTreeMap<Key<'_>, &str> = [(Key("a"), "1"), (Key("b"), "2")].iter().copied().collect();
+    let owned = borrowme::to_owned(&map);
+    assert_eq!(owned.len(), 2);
+}
+
+#[test]
+#[should_panic = "converted map has colliding keys"]
+fn btree_map_collision() {
+    let map: BTreeMap<Key<'_>, &str> = [(Key("a"), "1"), (Key("A"), "2")].iter().copied().collect();
+    let _ = borrowme::to_owned(&map);
+}
+
+#[test]
+#[should_panic = "converted map has colliding keys"]
+fn btree_map_borrow_collision() {
+    let map = BTreeMap::from([
+        (OwnedName(String::from("a")), String::from("1")),
+        (OwnedName(String::from("A")), String::from("2")),
+    ]);
+
+    let _ = borrowme::borrow(&map);
+}
+
+#[test]
+#[should_panic = "converted map has colliding keys"]
+fn hash_map_collision() {
+    let map: HashMap<Key<'_>, &str> = [(Key("a"), "1"), (Key("A"), "2")].iter().copied().collect();
+    let _ = borrowme::to_owned(&map);
+}
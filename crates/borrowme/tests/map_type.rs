@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use borrowme::borrowme;
+
+#[borrowme(map_type(&str => Arc<str>), map_type(&[u8] => Box<[u8]>))]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    text: &'a str,
+    lang: &'a str,
+    bytes: &'a [u8],
+    #[owned(String)]
+    explicit: &'a str,
+    count: u32,
+}
+
+#[borrowme(map_type(&str => Arc<str>))]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Entry<'a> {
+    Text(&'a str),
+    Named { text: &'a str },
+}
+
+#[borrowme(map_type(&str => Arc<str>), no_heuristics)]
+pub struct Explicit<'a> {
+    text: &'a str,
+}
+
+#[derive(Debug)]
+pub struct Error;
+
+fn non_empty(text: &str) -> Result<String, Error> {
+    if text.is_empty() {
+        return Err(Error);
+    }
+
+    Ok(text.to_owned())
+}
+
+#[borrowme(map_type(&str => Arc<str>), error = Error)]
+pub struct Fallible<'a> {
+    #[borrowme(try_to_owned_with = non_empty)]
+    text: &'a str,
+}
+
+#[test]
+fn map_type() {
+    let word = Word {
+        text: "hello",
+        lang: "en",
+        bytes: &[1, 2, 3],
+        explicit: "world",
+        count: 1,
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    let _: &Arc<str> = &owned.text;
+    let _: Box<[u8]> = owned.bytes.clone();
+    let _: &String = &owned.explicit;
+    assert_eq!(&*owned.text, "hello");
+    assert_eq!(&*owned.lang, "en");
+    assert_eq!(&*owned.bytes, [1, 2, 3]);
+    assert_eq!(borrowme::borrow(&owned), word);
+
+    let entry = Entry::Text("hello");
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert!(matches!(&owned, OwnedEntry::Text(text) if &**text == "hello"));
+    assert_eq!(borrowme::borrow(&owned), entry);
+
+    let entry = Entry::Named { text: "hello" };
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert_eq!(borrowme::borrow(&owned), entry);
+
+    let explicit = Explicit { text: "hello" };
+    let owned: OwnedExplicit = borrowme::to_owned(&explicit);
+    let _: Arc<str> = owned.text;
+
+    let fallible = Fallible { text: "hello" };
+    let owned: OwnedFallible = borrowme::try_to_owned(&fallible).unwrap();
+    let _: String = owned.text;
+}
@@ -0,0 +1,68 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Buffer<'a> {
+    #[borrowme(deref_borrow)]
+    data: &'a mut [u8],
+}
+
+#[borrowme]
+struct Frame<'a> {
+    #[borrowme(mut)]
+    buffer: Buffer<'a>,
+    #[borrowme(borrow_mut_with = Vec::as_mut_slice)]
+    padding: &'a mut [u8],
+}
+
+#[test]
+fn mut_slice_to_owned() {
+    let mut data = [1, 2, 3];
+    let slice: &mut [u8] = &mut data;
+
+    let owned: Vec<u8> = borrowme::to_owned(&slice);
+    assert_eq!(owned, [1, 2, 3]);
+}
+
+#[test]
+fn mut_slice_field() {
+    let mut data = [1, 2, 3];
+    let mut padding = [0, 0];
+
+    let frame = Frame {
+        buffer: Buffer { data: &mut data },
+        padding: &mut padding,
+    };
+
+    let mut owned: OwnedFrame = borrowme::to_owned(&frame);
+    let _: &Vec<u8> = &owned.buffer.data;
+    let _: &Vec<u8> = &owned.padding;
+
+    let view = borrowme::borrow_mut(&mut owned);
+    view.buffer.data[0] = 4;
+    view.padding.fill(9);
+
+    assert_eq!(owned.buffer.data, [4, 2, 3]);
+    assert_eq!(owned.padding, [9, 9]);
+}
+
+#[borrowme]
+struct Bare<'a> {
+    data: &'a mut [u8],
+}
+
+#[test]
+fn mut_slice_bare_field() {
+    let mut data = [1, 2, 3];
+
+    let bare = Bare { data: &mut data };
+
+    let mut owned: OwnedBare = borrowme::to_owned(&bare);
+    let _: &Vec<u8> = &owned.data;
+
+    let view = borrowme::borrow_mut(&mut owned);
+    let _: &mut [u8] = view.data;
+    view.data[0] = 4;
+
+    assert_eq!(owned.data, [4, 2, 3]);
+}
@@ -0,0 +1,60 @@
+use std::ops::{Deref, DerefMut};
+
+use borrowme::borrowme;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Name(String);
+
+impl Deref for Name {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl DerefMut for Name {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut str {
+        &mut self.0
+    }
+}
+
+fn name_from(value: &str) -> Name {
+    Name(value.to_owned())
+}
+
+#[borrowme]
+#[borrowme(mut_view = MutWord)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    text: &'a str,
+    #[borrowme(owned = Name, to_owned_with = name_from, deref_borrow)]
+    name: &'a str,
+    tags: Vec<&'a str>,
+    #[copy]
+    weight: u32,
+}
+
+#[test]
+fn mut_view() {
+    let word = Word {
+        text: "hello",
+        name: "greeting",
+        tags: vec!["a", "b"],
+        weight: 1,
+    };
+
+    let mut owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(borrowme::borrow(&owned), word);
+
+    let view: MutWord<'_> = borrowme::borrow_mut(&mut owned);
+    view.text.make_ascii_uppercase();
+    view.name.make_ascii_uppercase();
+    assert_eq!(view.tags, ["a", "b"]);
+    assert_eq!(view.weight, 1);
+
+    assert_eq!(owned.text, "HELLO");
+    assert_eq!(owned.name, Name(String::from("GREETING")));
+}
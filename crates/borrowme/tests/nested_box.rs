@@ -0,0 +1,53 @@
+// The nested `Box<String>` is intentional, since it is what is being tested.
+#![allow(clippy::box_collection)]
+
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    // `Box<String>` borrows as `&String`, so reaching `&str` requires going
+    // through both layers of indirection.
+    #[borrowme(owned = Option<Box<String>>, with = self::boxed_string)]
+    text: Option<&'a str>,
+    // `Box<str>` already borrows as `&str`, so only the conversion to the owned
+    // variant has to be specified.
+    #[borrowme(owned = Option<Box<str>>, to_owned_with = self::boxed_str)]
+    lang: Option<&'a str>,
+}
+
+mod boxed_string {
+    pub(crate) fn to_owned(this: &Option<&str>) -> Option<Box<String>> {
+        this.map(|s| Box::new(String::from(s)))
+    }
+
+    pub(crate) fn borrow(this: &Option<Box<String>>) -> Option<&str> {
+        this.as_deref().map(String::as_str)
+    }
+}
+
+fn boxed_str(this: &Option<&str>) -> Option<Box<str>> {
+    this.map(Box::from)
+}
+
+#[test]
+fn nested_box() {
+    let word = Word {
+        text: Some("hello"),
+        lang: Some("en"),
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(owned.text.as_deref().map(String::as_str), Some("hello"));
+    assert_eq!(owned.lang.as_deref(), Some("en"));
+    assert_eq!(borrowme::borrow(&owned), word);
+
+    let word = Word {
+        text: None,
+        lang: None,
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(owned.text, None);
+    assert_eq!(borrowme::borrow(&owned), word);
+}
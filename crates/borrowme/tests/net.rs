@@ -0,0 +1,45 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Endpoint<'a> {
+    name: &'a str,
+    ip: Ipv4Addr,
+    addr: SocketAddr,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Peer<'a> {
+    endpoint: Endpoint<'a>,
+    any: IpAddr,
+    v6: Option<&'a Ipv6Addr>,
+    fallbacks: Vec<SocketAddr>,
+}
+
+#[test]
+fn net() {
+    let v6 = Ipv6Addr::LOCALHOST;
+    let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 8080));
+
+    let peer = Peer {
+        endpoint: Endpoint {
+            name: "local",
+            ip: Ipv4Addr::new(10, 0, 0, 1),
+            addr,
+        },
+        any: IpAddr::V6(v6),
+        v6: Some(&v6),
+        fallbacks: vec![addr],
+    };
+
+    let owned: OwnedPeer = borrowme::to_owned(&peer);
+    let _: Ipv4Addr = owned.endpoint.ip;
+    let _: Option<Ipv6Addr> = owned.v6;
+    assert_eq!(owned.endpoint.name, "local");
+    assert_eq!(owned.endpoint.addr, addr);
+    assert_eq!(owned.fallbacks, [addr]);
+    assert_eq!(borrowme::borrow(&owned), peer);
+}
@@ -0,0 +1,42 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[owned_attr(no_derive(Copy))]
+pub struct Word<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[owned_attr(no_derive(Clone, Copy))]
+pub enum Token<'a> {
+    Word(Word<'a>),
+    Number(u32),
+}
+
+#[borrowme]
+#[derive(Debug, Clone, core::marker::Copy)]
+#[owned_attr(no_derive(core::marker::Copy))]
+pub struct Qualified<'a> {
+    text: &'a str,
+}
+
+#[test]
+fn no_derive() {
+    let word = Word { text: "hello" };
+    let copied = word;
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(owned.clone(), owned);
+    assert_eq!(borrowme::borrow(&owned), copied);
+
+    let token = Token::Word(word);
+    let copied = token;
+    let owned: OwnedToken = borrowme::to_owned(&token);
+    assert_eq!(borrowme::borrow(&owned), copied);
+
+    let qualified = Qualified { text: "hello" };
+    let owned: OwnedQualified = borrowme::to_owned(&qualified);
+    assert_eq!(owned.clone().text, "hello");
+}
@@ -0,0 +1,48 @@
+use borrowme::borrowme;
+
+#[borrowme(no_heuristics)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    #[owned(String)]
+    text: &'a str,
+    #[borrowme(std)]
+    lang: &'a String,
+    #[copy]
+    count: u32,
+    #[borrowme(std)]
+    flag: bool,
+}
+
+#[borrowme(no_heuristics)]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Entry<'a> {
+    Text(#[owned(String)] &'a str),
+    Count(#[copy] u32),
+}
+
+#[test]
+fn no_heuristics() {
+    let lang = String::from("en");
+
+    let word = Word {
+        text: "hello",
+        lang: &lang,
+        count: 1,
+        flag: true,
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(owned.text, "hello");
+    assert_eq!(owned.lang, "en");
+    assert_eq!(owned.count, 1);
+    assert!(owned.flag);
+    assert_eq!(borrowme::borrow(&owned), word);
+
+    let entry = Entry::Count(2);
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert_eq!(borrowme::borrow(&owned), entry);
+
+    let entry = Entry::Text("hello");
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert_eq!(borrowme::borrow(&owned), entry);
+}
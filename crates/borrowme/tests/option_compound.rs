@@ -0,0 +1,48 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    text: &'a str,
+    lang: Option<&'a str>,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Entry<'a> {
+    word: Option<Word<'a>>,
+    words: Vec<Option<Word<'a>>>,
+}
+
+#[test]
+fn option_compound() {
+    let entry = Entry {
+        word: Some(Word {
+            text: "hello",
+            lang: Some("en"),
+        }),
+        words: vec![
+            None,
+            Some(Word {
+                text: "hej",
+                lang: Some("sv"),
+            }),
+        ],
+    };
+
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    let word = owned.word.as_ref().expect("word");
+    assert_eq!(word.text, "hello");
+    assert_eq!(word.lang.as_deref(), Some("en"));
+    assert!(owned.words[0].is_none());
+    assert_eq!(borrowme::borrow(&owned), entry);
+
+    let entry = Entry {
+        word: None,
+        words: Vec::new(),
+    };
+
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert_eq!(owned.word, None);
+    assert_eq!(borrowme::borrow(&owned), entry);
+}
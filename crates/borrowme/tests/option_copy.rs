@@ -0,0 +1,31 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    count: Option<u32>,
+    text: Option<&'a str>,
+    flags: Option<(bool, u8)>,
+    nested: Option<Option<u16>>,
+}
+
+#[test]
+fn option_copy() {
+    let word = Word {
+        count: Some(42),
+        text: Some("hello"),
+        flags: Some((true, 1)),
+        nested: Some(None),
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    let _: Option<u32> = owned.count;
+    let _: &Option<String> = &owned.text;
+    assert_eq!(owned.count, Some(42));
+    assert_eq!(owned.text.as_deref(), Some("hello"));
+    assert_eq!(owned.flags, Some((true, 1)));
+    assert_eq!(owned.nested, Some(None));
+
+    let borrowed: Word<'_> = borrowme::borrow(&owned);
+    assert_eq!(borrowed, word);
+}
@@ -0,0 +1,60 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    #[borrowme(or_default)]
+    lang: Option<&'a str>,
+    #[borrowme(owned = String, or_default)]
+    text: Option<&'a str>,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Text<'a> {
+    Word(#[borrowme(or_default)] Option<&'a str>),
+    Empty,
+}
+
+#[test]
+fn or_default() {
+    let word = Word {
+        lang: None,
+        text: Some("hello"),
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    let lang: &String = &owned.lang;
+    assert_eq!(lang, "");
+    assert_eq!(owned.text, "hello");
+
+    let borrowed: Word<'_> = borrowme::borrow(&owned);
+    assert_eq!(borrowed, word);
+}
+
+#[test]
+fn or_default_borrows_default_as_none() {
+    let word = Word {
+        lang: Some(""),
+        text: None,
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(owned.lang, "");
+    assert_eq!(owned.text, "");
+
+    let borrowed: Word<'_> = borrowme::borrow(&owned);
+    assert_eq!(borrowed.lang, None);
+    assert_eq!(borrowed.text, None);
+}
+
+#[test]
+fn or_default_enum() {
+    let text = Text::Word(None);
+    let owned: OwnedText = borrowme::to_owned(&text);
+    assert_eq!(owned, OwnedText::Word(String::new()));
+    assert_eq!(borrowme::borrow(&owned), text);
+
+    let owned = OwnedText::Word(String::from("hello"));
+    assert_eq!(borrowme::borrow(&owned), Text::Word(Some("hello")));
+}
@@ -0,0 +1,24 @@
+use borrowme::borrowme;
+use serde::{Deserialize, Serialize};
+
+#[borrowme]
+#[owned_attr(derive(Debug, Clone))]
+#[owned_attr(serde(rename_all = "UPPERCASE"))]
+#[owned_attr(derive(PartialEq, Serialize, Deserialize))]
+#[borrowed_attr(derive(Debug))]
+#[borrowed_attr(derive(PartialEq))]
+pub struct Word<'a> {
+    text: &'a str,
+}
+
+#[test]
+fn merged_derives() {
+    let word = Word { text: "hello" };
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(owned.clone(), owned);
+    assert_eq!(borrowme::borrow(&owned), word);
+
+    let json = serde_json::to_string(&owned).unwrap();
+    assert_eq!(json, r#"{"TEXT":"hello"}"#);
+    assert_eq!(serde_json::from_str::<OwnedWord>(&json).unwrap(), owned);
+}
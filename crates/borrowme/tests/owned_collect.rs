@@ -0,0 +1,55 @@
+use std::collections::{BTreeSet, VecDeque};
+use std::rc::Rc;
+
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Word<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Sentence<'a> {
+    #[borrowme(owned_collect = VecDeque<OwnedWord>)]
+    words: Vec<Word<'a>>,
+    #[borrowme(owned_collect = Rc<[u32]>)]
+    counts: &'a [u32],
+    #[borrowme(owned_collect = Vec<String>)]
+    tags: BTreeSet<&'a str>,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Text<'a> {
+    Words(#[borrowme(owned_collect = Box<[OwnedWord]>)] Vec<Word<'a>>),
+    Empty,
+}
+
+#[test]
+fn owned_collect() {
+    let sentence = Sentence {
+        words: vec![Word { text: "hello" }, Word { text: "world" }],
+        counts: &[1, 2, 3],
+        tags: BTreeSet::from(["b", "a"]),
+    };
+
+    let owned: OwnedSentence = borrowme::to_owned(&sentence);
+    let words: &VecDeque<OwnedWord> = &owned.words;
+    let counts: &Rc<[u32]> = &owned.counts;
+    assert_eq!(words.len(), 2);
+    assert_eq!(words[1].text, "world");
+    assert_eq!(&counts[..], &[1, 2, 3]);
+    assert_eq!(owned.tags, [String::from("a"), String::from("b")]);
+
+    assert_eq!(borrowme::borrow(&owned), sentence);
+}
+
+#[test]
+fn owned_collect_enum() {
+    let text = Text::Words(vec![Word { text: "hello" }]);
+    let owned: OwnedText = borrowme::to_owned(&text);
+    assert!(matches!(&owned, OwnedText::Words(words) if words.len() == 1));
+    assert_eq!(borrowme::borrow(&owned), text);
+}
@@ -0,0 +1,100 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+use borrowme::borrowme;
+
+#[borrowme(owned_hash)]
+pub struct Word<'a> {
+    text: &'a str,
+    lang: Option<&'a str>,
+    #[borrowme(no_hash)]
+    cache: Vec<u32>,
+}
+
+#[borrowme(owned_hash)]
+pub struct Key<'a> {
+    #[borrowme(hash)]
+    id: u32,
+    name: &'a str,
+}
+
+#[borrowme(owned_hash)]
+pub enum Entry<'a> {
+    Text(&'a str, #[borrowme(no_hash)] u32),
+    Named { name: &'a str },
+    Empty,
+}
+
+#[borrowme(owned_hash)]
+pub struct Generic<'a, T> {
+    text: &'a str,
+    value: T,
+}
+
+fn hash<T>(value: &T) -> u64
+where
+    T: Hash,
+{
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn owned_hash() {
+    let a: OwnedWord = borrowme::to_owned(&Word {
+        text: "hello",
+        lang: Some("en"),
+        cache: vec![1, 2],
+    });
+
+    let b: OwnedWord = borrowme::to_owned(&Word {
+        text: "hello",
+        lang: Some("en"),
+        cache: Vec::new(),
+    });
+
+    let c: OwnedWord = borrowme::to_owned(&Word {
+        text: "hello",
+        lang: None,
+        cache: Vec::new(),
+    });
+
+    assert!(a == b);
+    assert_eq!(hash(&a), hash(&b));
+    assert!(a != c);
+
+    let mut set = HashSet::new();
+    assert!(set.insert(a));
+    assert!(!set.insert(b));
+    assert!(set.insert(c));
+
+    let a: OwnedKey = borrowme::to_owned(&Key { id: 1, name: "a" });
+    let b: OwnedKey = borrowme::to_owned(&Key { id: 1, name: "b" });
+    let c: OwnedKey = borrowme::to_owned(&Key { id: 2, name: "a" });
+    assert!(a == b);
+    assert_eq!(hash(&a), hash(&b));
+    assert!(a != c);
+
+    let a: OwnedEntry = borrowme::to_owned(&Entry::Text("hello", 1));
+    let b: OwnedEntry = borrowme::to_owned(&Entry::Text("hello", 2));
+    let c: OwnedEntry = borrowme::to_owned(&Entry::Named { name: "hello" });
+    let d: OwnedEntry = borrowme::to_owned(&Entry::Empty);
+    assert!(a == b);
+    assert_eq!(hash(&a), hash(&b));
+    assert!(a != c);
+    assert!(c != d);
+    assert!(d == OwnedEntry::Empty);
+    assert_ne!(hash(&c), hash(&d));
+
+    let a: OwnedGeneric<u32> = borrowme::to_owned(&Generic {
+        text: "hello",
+        value: 1u32,
+    });
+    let b: OwnedGeneric<u32> = borrowme::to_owned(&Generic {
+        text: "hello",
+        value: 2u32,
+    });
+    assert!(a != b);
+}
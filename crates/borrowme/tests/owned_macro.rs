@@ -0,0 +1,37 @@
+use borrowme::borrowme;
+
+macro_rules! owned_text {
+    () => {
+        Box<str>
+    };
+}
+
+macro_rules! owned_list {
+    ($ty:ty) => {
+        Vec<$ty>
+    };
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Word<'a> {
+    #[borrowme(owned = owned_text!(), to_owned_with = Box::from, borrow_with = AsRef::as_ref)]
+    text: &'a str,
+    #[owned(owned_list!(String))]
+    tags: Vec<&'a str>,
+}
+
+#[test]
+fn owned_macro() {
+    let word = Word {
+        text: "hello",
+        tags: vec!["greeting"],
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    let text: Box<str> = owned.text.clone();
+    let tags: Vec<String> = owned.tags.clone();
+    assert_eq!(&*text, "hello");
+    assert_eq!(tags, vec![String::from("greeting")]);
+    assert_eq!(borrowme::borrow(&owned), word);
+}
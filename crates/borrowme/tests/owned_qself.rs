@@ -0,0 +1,47 @@
+use borrowme::borrowme;
+
+trait Parser {
+    type Output;
+}
+
+struct Text;
+
+impl Parser for Text {
+    type Output = String;
+}
+
+mod parsers {
+    pub(crate) trait Parser {
+        type Output<T>;
+    }
+
+    pub(crate) struct Lines;
+
+    impl Parser for Lines {
+        type Output<T> = Vec<T>;
+    }
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Word<'a> {
+    #[borrowme(owned = <Text as Parser>::Output)]
+    text: &'a str,
+    #[owned(<parsers::Lines as parsers::Parser>::Output<String>)]
+    lines: Vec<&'a str>,
+}
+
+#[test]
+fn owned_qself() {
+    let word = Word {
+        text: "hello",
+        lines: vec!["a", "b"],
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    let text: &String = &owned.text;
+    let lines: &Vec<String> = &owned.lines;
+    assert_eq!(text, "hello");
+    assert_eq!(lines, &[String::from("a"), String::from("b")]);
+    assert_eq!(borrowme::borrow(&owned), word);
+}
@@ -0,0 +1,56 @@
+use std::any::Any;
+
+use borrowme::borrowme;
+
+#[borrowme(owned_static)]
+pub struct Wrap<'a, T: ?Sized> {
+    value: &'a T,
+    text: &'a str,
+}
+
+#[borrowme(owned_static)]
+pub struct Value<'a, T: Clone> {
+    value: T,
+    text: &'a str,
+}
+
+fn store<T>(owned: OwnedWrap<T>) -> Box<dyn Any>
+where
+    T: ?Sized + borrowme::ToOwned,
+{
+    Box::new(owned.value)
+}
+
+fn store_value<T>(owned: OwnedValue<T>) -> Box<dyn Any>
+where
+    T: Clone,
+{
+    Box::new(owned)
+}
+
+#[test]
+fn owned_static() {
+    let wrap = Wrap::<str> {
+        value: "hello",
+        text: "world",
+    };
+
+    let owned: OwnedWrap<str> = borrowme::to_owned(&wrap);
+    let stored = store(owned);
+    assert_eq!(
+        stored.downcast_ref::<String>().map(String::as_str),
+        Some("hello")
+    );
+
+    let value = Value {
+        value: 42u32,
+        text: "world",
+    };
+
+    let owned: OwnedValue<u32> = borrowme::to_owned(&value);
+    let stored = store_value(owned);
+    assert_eq!(
+        stored.downcast_ref::<OwnedValue<u32>>().map(|v| v.value),
+        Some(42)
+    );
+}
@@ -0,0 +1,41 @@
+mod word {
+    use borrowme::borrowme;
+
+    #[borrowme]
+    pub struct Word<'a> {
+        #[borrowme(owned_vis = pub(crate))]
+        pub text: &'a str,
+        pub lang: &'a str,
+    }
+
+    #[borrowme]
+    pub struct Unnamed<'a>(#[borrowme(owned_vis = pub(crate))] pub &'a str);
+
+    #[borrowme]
+    #[repr(C)]
+    pub union Payload {
+        #[copy]
+        #[borrowme(owned_vis = pub(crate))]
+        pub int: u32,
+    }
+}
+
+#[test]
+fn owned_vis() {
+    let word = word::Word {
+        text: "Hello",
+        lang: "en",
+    };
+
+    let owned: word::OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(owned.text, "Hello");
+    assert_eq!(owned.lang, "en");
+
+    let unnamed = word::Unnamed("World");
+    let owned: word::OwnedUnnamed = borrowme::to_owned(&unnamed);
+    assert_eq!(owned.0, "World");
+
+    let payload = word::Payload { int: 42 };
+    let owned: word::OwnedPayload = borrowme::to_owned(&payload);
+    assert_eq!(unsafe { owned.int }, 42);
+}
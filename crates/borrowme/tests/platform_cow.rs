@@ -0,0 +1,42 @@
+use std::borrow::Cow;
+use std::ffi::{CStr, CString, OsStr, OsString};
+
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Command<'a> {
+    program: Cow<'a, OsStr>,
+    name: Cow<'a, CStr>,
+}
+
+#[test]
+fn platform_cow() {
+    let name = CString::new("hello").unwrap();
+
+    let command = Command {
+        program: Cow::Borrowed(OsStr::new("ls")),
+        name: Cow::Borrowed(name.as_c_str()),
+    };
+
+    let owned: OwnedCommand = borrowme::to_owned(&command);
+    let program: &Cow<'static, OsStr> = &owned.program;
+    let name: &Cow<'static, CStr> = &owned.name;
+    assert!(matches!(program, Cow::Owned(program) if program == &OsString::from("ls")));
+    assert!(matches!(name, Cow::Owned(name) if name.as_bytes() == b"hello"));
+
+    let borrowed = borrowme::borrow(&owned);
+    assert!(matches!(borrowed.program, Cow::Borrowed(..)));
+    assert!(matches!(borrowed.name, Cow::Borrowed(..)));
+    assert_eq!(borrowed, command);
+
+    let owned = OwnedCommand {
+        program: Cow::Owned(OsString::from("cat")),
+        name: Cow::Owned(CString::new("world").unwrap()),
+    };
+
+    let command: Command<'_> = borrowme::borrow(&owned);
+    assert_eq!(command.program, OsStr::new("cat"));
+    assert_eq!(command.name.to_bytes(), b"world");
+    assert_eq!(borrowme::to_owned(&command), owned);
+}
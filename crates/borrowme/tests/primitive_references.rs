@@ -0,0 +1,36 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Counts<'a> {
+    count: &'a u32,
+    enabled: Option<&'a bool>,
+    letters: Vec<&'a char>,
+}
+
+#[borrowme]
+pub struct CountsMut<'a> {
+    count: &'a mut u32,
+}
+
+#[test]
+fn primitive_references() {
+    let (count, enabled, letter) = (1, true, 'a');
+
+    let counts = Counts {
+        count: &count,
+        enabled: Some(&enabled),
+        letters: vec![&letter],
+    };
+
+    let owned: OwnedCounts = borrowme::to_owned(&counts);
+    assert_eq!(owned.count, 1);
+    assert_eq!(owned.enabled, Some(true));
+    assert_eq!(owned.letters, ['a']);
+    assert_eq!(borrowme::borrow(&owned), counts);
+
+    let mut count = 1;
+    let mut owned: OwnedCountsMut = borrowme::to_owned(&CountsMut { count: &mut count });
+    *borrowme::borrow_mut(&mut owned).count += 1;
+    assert_eq!(owned.count, 2);
+}
@@ -0,0 +1,51 @@
+use borrowme::borrowme;
+
+pub struct Lines<'a> {
+    text: &'a str,
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (line, rest) = self.text.split_once('\n')?;
+        self.text = rest;
+        Some(line)
+    }
+}
+
+trait Parser<'a> {
+    type Output;
+}
+
+struct Text;
+
+impl<'a> Parser<'a> for Text {
+    type Output = &'a str;
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Word<'a> {
+    line: <Lines<'a> as Iterator>::Item,
+    text: <Text as Parser<'a>>::Output,
+}
+
+#[test]
+fn qself_lifetime() {
+    let mut lines = Lines {
+        text: "hello\nworld\n",
+    };
+
+    let word = Word {
+        line: lines.next().unwrap(),
+        text: "text",
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    let line: &String = &owned.line;
+    let text: &String = &owned.text;
+    assert_eq!(line, "hello");
+    assert_eq!(text, "text");
+    assert_eq!(borrowme::borrow(&owned), word);
+}
@@ -0,0 +1,59 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[repr(C)]
+struct Record<'a> {
+    #[copy]
+    id: u8,
+    name: &'a str,
+    #[copy]
+    flags: u16,
+    lang: Option<&'a str>,
+    #[copy]
+    weight: u64,
+}
+
+/// Offset of the given field in bytes.
+fn offset<T, F>(base: &T, field: &F) -> usize {
+    field as *const F as usize - base as *const T as usize
+}
+
+#[test]
+fn repr_c_field_order() {
+    let record = Record {
+        id: 1,
+        name: "hello",
+        flags: 2,
+        lang: Some("en"),
+        weight: 3,
+    };
+
+    let offsets = [
+        offset(&record, &record.id),
+        offset(&record, &record.name),
+        offset(&record, &record.flags),
+        offset(&record, &record.lang),
+        offset(&record, &record.weight),
+    ];
+
+    assert!(offsets.windows(2).all(|w| w[0] < w[1]));
+
+    let owned: OwnedRecord = borrowme::to_owned(&record);
+
+    let offsets = [
+        offset(&owned, &owned.id),
+        offset(&owned, &owned.name),
+        offset(&owned, &owned.flags),
+        offset(&owned, &owned.lang),
+        offset(&owned, &owned.weight),
+    ];
+
+    // Fields in the owned variant are declared in the same order, so with
+    // `#[repr(C)]` they are laid out in that order as well.
+    assert!(offsets.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(owned.id, 1);
+    assert_eq!(owned.name, "hello");
+    assert_eq!(owned.flags, 2);
+    assert_eq!(owned.lang.as_deref(), Some("en"));
+    assert_eq!(owned.weight, 3);
+}
@@ -0,0 +1,66 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use borrowme::borrowme;
+use serde::{Deserialize, Serialize};
+
+#[borrowme(serde)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Word<'a> {
+    text: &'a str,
+}
+
+#[borrowme(serde)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record<'a> {
+    name: &'a str,
+    word: Word<'a>,
+    lang: Cow<'a, str>,
+    tags: Vec<&'a str>,
+    count: u32,
+    #[borrowed_attr(serde(borrow))]
+    #[serde(flatten)]
+    extra: BTreeMap<&'a str, &'a str>,
+}
+
+#[borrowme(serde)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Entry<'a> {
+    Text(&'a str),
+    Record { name: &'a str, count: u32 },
+}
+
+#[test]
+fn serde_borrow() {
+    let input = r#"{"name":"hello","word":{"text":"world"},"lang":"en","tags":["a","b"],"count":2,"color":"red"}"#;
+
+    let record: Record<'_> = serde_json::from_str(input).unwrap();
+    assert_eq!(record.name, "hello");
+    assert_eq!(record.word.text, "world");
+    assert!(matches!(record.lang, Cow::Borrowed("en")));
+    assert_eq!(record.tags, ["a", "b"]);
+    assert_eq!(record.extra.get("color"), Some(&"red"));
+
+    let owned: OwnedRecord = borrowme::to_owned(&record);
+    assert_eq!(serde_json::to_string(&owned).unwrap(), input);
+    assert_eq!(serde_json::from_str::<OwnedRecord>(input).unwrap(), owned);
+    assert_eq!(borrowme::borrow(&owned), record);
+}
+
+#[test]
+fn serde_borrow_enum() {
+    let input = r#"{"Record":{"name":"hello","count":1}}"#;
+
+    let entry: Entry<'_> = serde_json::from_str(input).unwrap();
+    assert_eq!(
+        entry,
+        Entry::Record {
+            name: "hello",
+            count: 1
+        }
+    );
+
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert_eq!(serde_json::to_string(&owned).unwrap(), input);
+    assert_eq!(borrowme::borrow(&owned), entry);
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "serde")]
+
+use std::borrow::Cow;
+
+use borrowme::borrowme;
+use serde::{Deserialize, Serialize};
+
+#[borrowme]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record<'a> {
+    #[serde(borrow, with = "borrowme::serde::cow_str")]
+    name: Cow<'a, str>,
+    #[serde(borrow, with = "borrowme::serde::cow_bytes")]
+    data: Cow<'a, [u8]>,
+}
+
+#[test]
+fn cow_round_trip() {
+    let input = r#"{"name":"hello","data":[1,2,3]}"#;
+
+    let record: Record<'_> = serde_json::from_str(input).unwrap();
+    assert!(matches!(record.name, Cow::Borrowed("hello")));
+    assert_eq!(&*record.data, &[1, 2, 3]);
+
+    let owned: OwnedRecord = borrowme::to_owned(&record);
+    assert_eq!(serde_json::to_string(&owned).unwrap(), input);
+
+    let borrowed: Record<'_> = borrowme::borrow(&owned);
+    assert_eq!(borrowed, record);
+}
+
+#[test]
+fn cow_escaped_string() {
+    // Escaped strings can't be borrowed from the input.
+    let record: Record<'_> = serde_json::from_str(r#"{"name":"a\nb","data":[]}"#).unwrap();
+    assert!(matches!(record.name, Cow::Owned(..)));
+    assert_eq!(record.name, "a\nb");
+}
@@ -0,0 +1,53 @@
+use std::collections::BTreeMap;
+
+use borrowme::borrowme;
+use serde::{Deserialize, Serialize};
+
+#[borrowme]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record<'a> {
+    name: &'a str,
+    #[serde(flatten)]
+    #[borrowed_attr(serde(borrow))]
+    extra: BTreeMap<&'a str, &'a str>,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Split<'a> {
+    name: &'a str,
+    #[borrowed_attr(serde(flatten, borrow))]
+    #[owned_attr(serde(flatten))]
+    extra: BTreeMap<&'a str, &'a str>,
+}
+
+#[test]
+fn flatten_round_trip() {
+    let input = r#"{"name":"hello","color":"red","lang":"en"}"#;
+
+    let record: Record<'_> = serde_json::from_str(input).unwrap();
+    assert_eq!(record.name, "hello");
+    assert_eq!(record.extra.get("color"), Some(&"red"));
+    assert_eq!(record.extra.get("lang"), Some(&"en"));
+
+    let owned: OwnedRecord = borrowme::to_owned(&record);
+    assert_eq!(serde_json::to_string(&owned).unwrap(), input);
+    assert_eq!(serde_json::from_str::<OwnedRecord>(input).unwrap(), owned);
+
+    let borrowed: Record<'_> = borrowme::borrow(&owned);
+    assert_eq!(borrowed, record);
+}
+
+#[test]
+fn flatten_split_attributes() {
+    let input = r#"{"name":"hello","color":"red"}"#;
+
+    let split: Split<'_> = serde_json::from_str(input).unwrap();
+    assert_eq!(split.extra.get("color"), Some(&"red"));
+
+    let owned: OwnedSplit = borrowme::to_owned(&split);
+    assert_eq!(serde_json::to_string(&owned).unwrap(), input);
+
+    let borrowed: Split<'_> = borrowme::borrow(&owned);
+    assert_eq!(borrowed, split);
+}
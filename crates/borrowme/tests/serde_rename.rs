@@ -0,0 +1,34 @@
+use borrowme::borrowme;
+use serde::{Deserialize, Serialize};
+
+#[borrowme]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Word<'a> {
+    #[serde(rename = "t")]
+    text: &'a str,
+    #[serde(rename = "l", default, skip_serializing_if = "Option::is_none")]
+    lang: Option<&'a str>,
+}
+
+#[test]
+fn rename_is_forwarded() {
+    let input = r#"{"t":"hello","l":"en"}"#;
+
+    let word: Word<'_> = serde_json::from_str(input).unwrap();
+    assert_eq!(word.text, "hello");
+    assert_eq!(word.lang, Some("en"));
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(serde_json::to_string(&word).unwrap(), input);
+    assert_eq!(serde_json::to_string(&owned).unwrap(), input);
+    assert_eq!(serde_json::from_str::<OwnedWord>(input).unwrap(), owned);
+
+    let word = Word {
+        text: "hello",
+        lang: None,
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(serde_json::to_string(&owned).unwrap(), r#"{"t":"hello"}"#);
+    assert_eq!(borrowme::borrow(&owned), word);
+}
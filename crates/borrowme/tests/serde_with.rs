@@ -0,0 +1,77 @@
+use borrowme::borrowme;
+use serde::{Deserialize, Serialize};
+
+#[borrowme]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Word<'a> {
+    #[serde(rename = "t")]
+    #[borrowed_attr(serde(borrow, with = "borrowed"))]
+    #[owned_attr(serde(with = "owned"))]
+    text: &'a str,
+}
+
+/// Borrowed text is serialized with a `b:` prefix, which is stripped without
+/// allocating when deserializing.
+mod borrowed {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(text: &&str, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&format_args!("b:{text}"))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<&'de str, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let text = <&str>::deserialize(deserializer)?;
+        text.strip_prefix("b:")
+            .ok_or_else(|| D::Error::custom("missing borrowed prefix"))
+    }
+}
+
+/// Owned text is serialized with an `o:` prefix.
+mod owned {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(text: &str, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&format_args!("o:{text}"))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let text = String::deserialize(deserializer)?;
+
+        match text.strip_prefix("o:") {
+            Some(text) => Ok(text.to_owned()),
+            None => Err(D::Error::custom("missing owned prefix")),
+        }
+    }
+}
+
+#[test]
+fn with_per_variant() {
+    let word: Word<'_> = serde_json::from_str(r#"{"t":"b:hello"}"#).unwrap();
+    assert_eq!(word.text, "hello");
+    assert_eq!(serde_json::to_string(&word).unwrap(), r#"{"t":"b:hello"}"#);
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(serde_json::to_string(&owned).unwrap(), r#"{"t":"o:hello"}"#);
+    assert_eq!(
+        serde_json::from_str::<OwnedWord>(r#"{"t":"o:hello"}"#).unwrap(),
+        owned
+    );
+
+    assert!(serde_json::from_str::<OwnedWord>(r#"{"t":"b:hello"}"#).is_err());
+    assert!(serde_json::from_str::<Word<'_>>(r#"{"t":"o:hello"}"#).is_err());
+    assert_eq!(borrowme::borrow(&owned), word);
+}
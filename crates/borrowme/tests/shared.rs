@@ -0,0 +1,47 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Word<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Graph<'a> {
+    shared: Arc<Word<'a>>,
+    local: Option<Rc<Word<'a>>>,
+}
+
+#[test]
+fn arc_round_trip() {
+    let word = Arc::new(Word { text: "hello" });
+
+    let owned: Arc<OwnedWord> = borrowme::to_owned(&word);
+    assert_eq!(owned.text, "hello");
+
+    let borrowed: Arc<Word<'_>> = borrowme::borrow(&owned);
+    assert_eq!(borrowed, word);
+}
+
+#[test]
+fn shared_fields() {
+    let shared = Arc::new(Word { text: "hello" });
+
+    let graph = Graph {
+        shared: shared.clone(),
+        local: Some(Rc::new(Word { text: "world" })),
+    };
+
+    let owned: OwnedGraph = borrowme::to_owned(&graph);
+    assert_eq!(Arc::strong_count(&shared), 2);
+    assert_eq!(Arc::strong_count(&owned.shared), 1);
+    assert_eq!(
+        owned.local.as_ref().map(|word| word.text.as_str()),
+        Some("world")
+    );
+    assert_eq!(borrowme::borrow(&owned), graph);
+}
@@ -0,0 +1,35 @@
+use std::sync::{Arc, Mutex};
+
+use borrowme::borrowme;
+
+#[derive(Debug, Default)]
+pub struct State {
+    hits: u32,
+}
+
+#[borrowme]
+pub struct Request<'a> {
+    path: &'a str,
+    cache: Arc<Mutex<State>>,
+}
+
+#[test]
+fn shared_fields() {
+    let cache = Arc::new(Mutex::new(State::default()));
+
+    let request = Request {
+        path: "/index.html",
+        cache: cache.clone(),
+    };
+
+    let owned: OwnedRequest = borrowme::to_owned(&request);
+    let _: &Arc<Mutex<State>> = &owned.cache;
+    assert!(Arc::ptr_eq(&owned.cache, &cache));
+
+    let borrowed: Request<'_> = borrowme::borrow(&owned);
+    assert_eq!(borrowed.path, "/index.html");
+    assert!(Arc::ptr_eq(&borrowed.cache, &cache));
+
+    borrowed.cache.lock().unwrap().hits += 1;
+    assert_eq!(cache.lock().unwrap().hits, 1);
+}
@@ -0,0 +1,85 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Token<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+pub struct Parsed<'a> {
+    tokens: Vec<Token<'a>>,
+    #[borrowme(skip)]
+    cache: RefCell<HashMap<usize, String>>,
+}
+
+#[borrowme]
+pub struct Lookup<'a> {
+    name: &'a str,
+    #[borrowme(skip)]
+    last: Option<&'a str>,
+}
+
+#[borrowme]
+pub enum Entry<'a> {
+    Parsed {
+        text: &'a str,
+        #[borrowme(skip)]
+        cache: RefCell<Vec<String>>,
+    },
+    Tuple(&'a str, #[borrowme(skip)] Option<&'a str>),
+}
+
+#[test]
+fn skip() {
+    let parsed = Parsed {
+        tokens: vec![Token { text: "hello" }],
+        cache: RefCell::new(HashMap::new()),
+    };
+
+    parsed.cache.borrow_mut().insert(0, String::from("cached"));
+
+    let owned: OwnedParsed = borrowme::to_owned(&parsed);
+    assert_eq!(owned.tokens.len(), 1);
+    assert!(owned.cache.borrow().is_empty());
+
+    owned.cache.borrow_mut().insert(1, String::from("cached"));
+
+    let borrowed: Parsed<'_> = borrowme::borrow(&owned);
+    assert_eq!(borrowed.tokens, [Token { text: "hello" }]);
+    assert!(borrowed.cache.borrow().is_empty());
+
+    let lookup = Lookup {
+        name: "hello",
+        last: Some("world"),
+    };
+
+    let owned: OwnedLookup = borrowme::to_owned(&lookup);
+    assert_eq!(owned.name, "hello");
+    assert_eq!(owned.last, None);
+
+    let borrowed: Lookup<'_> = borrowme::borrow(&owned);
+    assert_eq!(borrowed.name, "hello");
+    assert_eq!(borrowed.last, None);
+
+    let entry = Entry::Tuple("hello", Some("world"));
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert!(matches!(owned, OwnedEntry::Tuple(ref text, None) if text == "hello"));
+
+    let entry = Entry::Parsed {
+        text: "hello",
+        cache: RefCell::new(vec![String::from("cached")]),
+    };
+
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+
+    let OwnedEntry::Parsed { text, cache } = &owned else {
+        panic!("expected parsed");
+    };
+
+    assert_eq!(text, "hello");
+    assert!(cache.borrow().is_empty());
+}
@@ -0,0 +1,40 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+pub struct Bytes<'a> {
+    #[borrowme(borrow_with = Vec::as_slice)]
+    bytes: &'a [u8],
+}
+
+#[test]
+fn slice_of_compound_types() {
+    let words = [Word { text: "hello" }, Word { text: "world" }];
+
+    let owned: Vec<OwnedWord> = borrowme::to_owned(&words[..]);
+
+    assert_eq!(
+        owned,
+        [
+            OwnedWord {
+                text: String::from("hello")
+            },
+            OwnedWord {
+                text: String::from("world")
+            },
+        ]
+    );
+}
+
+#[test]
+fn slice_of_primitives() {
+    let bytes = Bytes { bytes: &[1, 2, 3] };
+    let owned: OwnedBytes = borrowme::to_owned(&bytes);
+    assert_eq!(owned.bytes, [1, 2, 3]);
+    assert_eq!(borrowme::borrow(&owned).bytes, [1, 2, 3]);
+}
@@ -0,0 +1,44 @@
+use std::borrow::Cow;
+
+use borrowme::borrowme;
+
+#[borrowme(static_variant = StaticWord)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    text: &'a str,
+    lang: Cow<'a, str>,
+}
+
+#[borrowme(static_variant = StaticText)]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Text<'a, T, const N: usize> {
+    Words([Word<'a>; N]),
+    Value(T),
+}
+
+const HELLO: StaticWord = Word {
+    text: "hello",
+    lang: Cow::Borrowed("en"),
+};
+
+const TEXT: StaticText<u32, 1> = Text::Words([HELLO]);
+
+#[test]
+fn static_variant() {
+    let owned: OwnedWord = borrowme::to_owned(&HELLO);
+    assert_eq!(owned.text, "hello");
+    assert_eq!(owned.lang, "en");
+
+    let word: Word<'_> = borrowme::borrow(&owned);
+    assert_eq!(word, HELLO);
+
+    let owned: OwnedText<u32, 1> = borrowme::to_owned(&TEXT);
+    assert_eq!(borrowme::borrow(&owned), TEXT);
+}
+
+#[test]
+fn static_variant_from_owned() {
+    let owned: &'static OwnedWord = Box::leak(Box::new(borrowme::to_owned(&HELLO)));
+    let word: StaticWord = borrowme::borrow(owned);
+    assert_eq!(word, HELLO);
+}
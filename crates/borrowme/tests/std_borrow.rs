@@ -0,0 +1,41 @@
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use borrowme::borrowme;
+
+#[borrowme(std_borrow)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Word<'a>(&'a str);
+
+#[borrowme(std_borrow)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location<'a> {
+    path: &'a Path,
+}
+
+#[borrowme(std_borrow)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Bytes<'a> {
+    #[borrowme(owned = Box<[u8]>, to_owned_with = Box::from, deref_borrow)]
+    bytes: &'a [u8],
+}
+
+#[test]
+fn std_borrow() {
+    let mut counts = HashMap::<OwnedWord, u32>::new();
+    counts.insert(borrowme::to_owned(&Word("hello")), 1);
+    assert_eq!(counts.get("hello"), Some(&1));
+    assert_eq!(counts.get("world"), None);
+
+    let mut locations = BTreeSet::new();
+
+    locations.insert(OwnedLocation {
+        path: PathBuf::from("src/lib.rs"),
+    });
+
+    assert!(locations.contains(Path::new("src/lib.rs")));
+
+    let mut bytes = HashMap::new();
+    bytes.insert(borrowme::to_owned(&Bytes { bytes: b"hello" }), 2);
+    assert_eq!(bytes.get(&b"hello"[..]), Some(&2));
+}
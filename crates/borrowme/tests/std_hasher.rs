@@ -0,0 +1,82 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hasher};
+
+use borrowme::borrowme;
+
+/// A hasher which is seeded, so that the state is observable after converting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Seeded(u64);
+
+impl BuildHasher for Seeded {
+    type Hasher = DefaultHasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = DefaultHasher::new();
+        hasher.write_u64(self.0);
+        hasher
+    }
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Word<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Dictionary<'a> {
+    words: HashMap<&'a str, Word<'a>, Seeded>,
+    tags: HashSet<&'a str, Seeded>,
+}
+
+#[borrowme]
+struct DictionaryMut<'a> {
+    words: HashMap<&'a str, &'a mut String, Seeded>,
+}
+
+#[test]
+fn std_hasher() {
+    let mut dictionary = Dictionary {
+        words: HashMap::with_hasher(Seeded(1)),
+        tags: HashSet::with_hasher(Seeded(2)),
+    };
+
+    dictionary.words.insert("hello", Word { text: "world" });
+    dictionary.tags.insert("greeting");
+
+    let owned: OwnedDictionary = borrowme::to_owned(&dictionary);
+    let _: &HashMap<String, OwnedWord, Seeded> = &owned.words;
+    assert_eq!(owned.words.hasher(), &Seeded(1));
+    assert_eq!(owned.tags.hasher(), &Seeded(2));
+    assert_eq!(owned.words["hello"].text, "world");
+    assert!(owned.tags.contains("greeting"));
+
+    let borrowed: Dictionary<'_> = borrowme::borrow(&owned);
+    assert_eq!(borrowed.words.hasher(), &Seeded(1));
+    assert_eq!(borrowed.tags.hasher(), &Seeded(2));
+    assert_eq!(borrowed, dictionary);
+}
+
+#[test]
+fn std_hasher_borrow_mut() {
+    let mut owned = OwnedDictionaryMut {
+        words: HashMap::with_hasher(Seeded(3)),
+    };
+
+    owned
+        .words
+        .insert(String::from("hello"), String::from("world"));
+
+    {
+        let borrowed = borrowme::borrow_mut(&mut owned);
+        assert_eq!(borrowed.words.hasher(), &Seeded(3));
+
+        for value in borrowed.words.into_values() {
+            value.make_ascii_uppercase();
+        }
+    }
+
+    assert_eq!(owned.words["hello"], "WORLD");
+}
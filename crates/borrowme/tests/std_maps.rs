@@ -0,0 +1,228 @@
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Dictionary<'a> {
+    hash: HashMap<&'a str, &'a str>,
+    btree: BTreeMap<&'a str, &'a str>,
+}
+
+#[borrowme]
+struct DictionaryMut<'a> {
+    hash: HashMap<&'a str, &'a mut String>,
+    btree: BTreeMap<&'a str, &'a mut String>,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Word<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Config<'a> {
+    paths: HashMap<&'a Path, Word<'a>>,
+    vars: HashMap<&'a OsStr, Word<'a>>,
+    sorted: BTreeMap<&'a Path, &'a OsStr>,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct Key<'a> {
+    text: &'a str,
+    lang: &'a str,
+}
+
+#[borrowme]
+struct Data<'a> {
+    text: &'a mut String,
+}
+
+#[borrowme]
+struct Index<'a> {
+    #[borrowme(mut)]
+    words: HashMap<Key<'a>, Data<'a>>,
+}
+
+const ENTRIES: [(&str, &str); 4] = [
+    ("delta", "d"),
+    ("alpha", "a"),
+    ("charlie", "c"),
+    ("bravo", "b"),
+];
+
+#[test]
+fn map_round_trip() {
+    let dictionary = Dictionary {
+        hash: ENTRIES.iter().copied().collect(),
+        btree: ENTRIES.iter().copied().collect(),
+    };
+
+    let owned: OwnedDictionary = borrowme::to_owned(&dictionary);
+    assert_eq!(owned.hash.len(), ENTRIES.len());
+
+    for (key, value) in ENTRIES {
+        assert_eq!(owned.hash.get(key).map(String::as_str), Some(value));
+    }
+
+    let keys = owned.btree.keys().map(String::as_str).collect::<Vec<_>>();
+    assert_eq!(keys, ["alpha", "bravo", "charlie", "delta"]);
+
+    let borrowed = borrowme::borrow(&owned);
+    assert_eq!(borrowed, dictionary);
+
+    let keys = borrowed.btree.keys().copied().collect::<Vec<_>>();
+    assert_eq!(keys, ["alpha", "bravo", "charlie", "delta"]);
+}
+
+#[test]
+fn map_platform_keys() {
+    let config = Config {
+        paths: ENTRIES
+            .iter()
+            .map(|&(key, text)| (Path::new(key), Word { text }))
+            .collect(),
+        vars: ENTRIES
+            .iter()
+            .map(|&(key, text)| (OsStr::new(key), Word { text }))
+            .collect(),
+        sorted: ENTRIES
+            .iter()
+            .map(|&(key, value)| (Path::new(key), OsStr::new(value)))
+            .collect(),
+    };
+
+    let owned: OwnedConfig = borrowme::to_owned(&config);
+
+    for (key, value) in ENTRIES {
+        let word: &OwnedWord = &owned.paths[&PathBuf::from(key)];
+        assert_eq!(word.text, value);
+        let word: &OwnedWord = &owned.vars[&OsString::from(key)];
+        assert_eq!(word.text, value);
+        assert_eq!(owned.sorted[Path::new(key)], OsString::from(value));
+    }
+
+    let borrowed = borrowme::borrow(&owned);
+    assert_eq!(borrowed, config);
+    assert_eq!(borrowed.paths[Path::new("alpha")], Word { text: "a" });
+    assert_eq!(borrowed.vars[OsStr::new("bravo")], Word { text: "b" });
+}
+
+#[test]
+fn map_borrow_mut() {
+    let mut hash_values = ENTRIES.map(|(_, value)| String::from(value));
+    let mut btree_values = ENTRIES.map(|(_, value)| String::from(value));
+
+    let dictionary = DictionaryMut {
+        hash: ENTRIES
+            .iter()
+            .map(|(key, _)| *key)
+            .zip(hash_values.iter_mut())
+            .collect(),
+        btree: ENTRIES
+            .iter()
+            .map(|(key, _)| *key)
+            .zip(btree_values.iter_mut())
+            .collect(),
+    };
+
+    let mut owned: OwnedDictionaryMut = borrowme::to_owned(&dictionary);
+
+    let borrowed = borrowme::borrow_mut(&mut owned);
+
+    for value in borrowed.hash.into_values() {
+        value.make_ascii_uppercase();
+    }
+
+    let keys = borrowed.btree.keys().copied().collect::<Vec<_>>();
+    assert_eq!(keys, ["alpha", "bravo", "charlie", "delta"]);
+
+    for value in borrowed.btree.into_values() {
+        value.push('!');
+    }
+
+    for (key, value) in ENTRIES {
+        assert_eq!(owned.hash[key], value.to_ascii_uppercase());
+        assert_eq!(owned.btree[key], format!("{value}!"));
+    }
+}
+
+// Keys are borrowed immutably while their values are borrowed mutably, which
+// is disjoint since iterating over `&mut HashMap` only hands out shared
+// references to keys.
+#[test]
+fn map_borrow_mut_compound_keys() {
+    let mut owned = OwnedIndex {
+        words: ENTRIES
+            .iter()
+            .map(|&(key, value)| {
+                let key = OwnedKey {
+                    text: key.to_owned(),
+                    lang: String::from("en"),
+                };
+
+                let data = OwnedData {
+                    text: value.to_owned(),
+                };
+
+                (key, data)
+            })
+            .collect(),
+    };
+
+    let borrowed: Index<'_> = borrowme::borrow_mut(&mut owned);
+    assert_eq!(borrowed.words.len(), ENTRIES.len());
+
+    let mut words = borrowed.words;
+
+    for (key, value) in ENTRIES {
+        let data = words
+            .get_mut(&Key {
+                text: key,
+                lang: "en",
+            })
+            .expect("missing key");
+
+        assert_eq!(data.text, value);
+        data.text.make_ascii_uppercase();
+    }
+
+    for (key, value) in ENTRIES {
+        let key = OwnedKey {
+            text: key.to_owned(),
+            lang: String::from("en"),
+        };
+
+        assert_eq!(*owned.words[&key].text, value.to_ascii_uppercase());
+    }
+}
+
+#[test]
+fn map_capacity() {
+    const LEN: usize = 10_000;
+
+    let keys = (0..LEN).map(|n| n.to_string()).collect::<Vec<_>>();
+    let map = keys
+        .iter()
+        .map(|key| (key.as_str(), key.as_str()))
+        .collect::<HashMap<_, _>>();
+
+    // If the map had to grow while inserting, it would have a larger capacity
+    // than the one initially reserved.
+    let expected = HashMap::<String, String>::with_capacity(LEN).capacity();
+
+    let owned: HashMap<String, String> = borrowme::to_owned(&map);
+    assert_eq!(owned.len(), LEN);
+    assert_eq!(owned.capacity(), expected);
+
+    let expected = HashMap::<&str, &str>::with_capacity(LEN).capacity();
+
+    let borrowed: HashMap<&str, &str> = borrowme::borrow(&owned);
+    assert_eq!(borrowed.len(), LEN);
+    assert_eq!(borrowed.capacity(), expected);
+}
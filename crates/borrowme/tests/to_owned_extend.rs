@@ -0,0 +1,32 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    text: &'a str,
+}
+
+#[test]
+fn to_owned_extend() {
+    let mut out = Vec::with_capacity(4);
+    let capacity = out.capacity();
+
+    let words = [Word { text: "a" }, Word { text: "b" }, Word { text: "c" }];
+    borrowme::to_owned_extend(&words, &mut out);
+    assert_eq!(out.len(), 3);
+    assert_eq!(out.iter().map(borrowme::borrow).collect::<Vec<_>>(), words);
+
+    let words = [Word { text: "d" }];
+    borrowme::to_owned_extend(&words, &mut out);
+    assert_eq!(
+        out,
+        [OwnedWord {
+            text: String::from("d")
+        }]
+    );
+    assert_eq!(out.capacity(), capacity);
+
+    borrowme::to_owned_extend::<Word<'_>>(&[], &mut out);
+    assert!(out.is_empty());
+    assert_eq!(out.capacity(), capacity);
+}
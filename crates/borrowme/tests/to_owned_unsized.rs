@@ -0,0 +1,30 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    text: &'a str,
+}
+
+#[test]
+fn to_owned_unsized() {
+    let text: String = borrowme::to_owned("hello");
+    assert_eq!(text, "hello");
+
+    let string = String::from("hello");
+    let text: String = borrowme::to_owned(string.as_str());
+    assert_eq!(text, "hello");
+
+    // Additional references are peeled off by the blanket implementation.
+    let reference: &&str = &"hello";
+    let text: String = borrowme::to_owned(reference);
+    assert_eq!(text, "hello");
+
+    let values: Vec<u32> = borrowme::to_owned(&[1u32, 2, 3][..]);
+    assert_eq!(values, [1, 2, 3]);
+
+    let words = [Word { text: "hello" }, Word { text: "world" }];
+    let owned: Vec<OwnedWord> = borrowme::to_owned(&words[..]);
+    assert_eq!(owned[0].text, "hello");
+    assert_eq!(owned[1].text, "world");
+}
@@ -0,0 +1,41 @@
+use borrowme::borrowme;
+
+type Filter = Box<dyn Fn(&str) -> bool>;
+
+#[borrowme]
+struct Matcher<'a> {
+    pattern: &'a str,
+    #[borrowme(owned = Filter, to_owned_with = to_owned_filter, borrow_with = borrow_filter)]
+    filter: Box<dyn Fn(&'a str) -> bool + 'a>,
+}
+
+// A closure which borrows from `'a` can't be made owned, so the owned variant
+// gets a filter which accepts everything.
+fn to_owned_filter<'a>(_: &(dyn Fn(&'a str) -> bool + 'a)) -> Filter {
+    Box::new(|_| true)
+}
+
+fn borrow_filter(filter: &Filter) -> Box<dyn Fn(&str) -> bool + '_> {
+    Box::new(move |value| filter(value))
+}
+
+#[test]
+fn trait_object_fields() {
+    let pattern = String::from("hello");
+
+    let matcher = Matcher {
+        pattern: &pattern,
+        filter: Box::new(|value| value == pattern),
+    };
+
+    assert!((matcher.filter)("hello"));
+    assert!(!(matcher.filter)("world"));
+
+    let owned: OwnedMatcher = borrowme::to_owned(&matcher);
+    assert_eq!(owned.pattern, "hello");
+    assert!((owned.filter)("world"));
+
+    let borrowed: Matcher<'_> = borrowme::borrow(&owned);
+    assert_eq!(borrowed.pattern, "hello");
+    assert!((borrowed.filter)("world"));
+}
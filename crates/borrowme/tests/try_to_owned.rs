@@ -0,0 +1,115 @@
+use std::convert::{TryFrom, TryInto};
+
+use borrowme::borrowme;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Error(&'static str);
+
+fn non_empty(text: &str) -> Result<String, Error> {
+    if text.is_empty() {
+        return Err(Error("empty text"));
+    }
+
+    Ok(text.to_owned())
+}
+
+fn upper(text: &str) -> String {
+    text.to_uppercase()
+}
+
+#[borrowme(error = Error)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    #[borrowme(owned = String, to_owned_with = upper, try_to_owned_with = non_empty)]
+    text: &'a str,
+    lang: &'a str,
+    count: u32,
+}
+
+#[borrowme(error = Error)]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Entry<'a> {
+    Text(#[borrowme(try_to_owned_with = non_empty)] &'a str),
+    Other { lang: &'a str },
+}
+
+#[test]
+fn try_to_owned() {
+    let word = Word {
+        text: "hello",
+        lang: "en",
+        count: 1,
+    };
+
+    let owned: OwnedWord = borrowme::try_to_owned(&word).unwrap();
+    assert_eq!(owned.text, "hello");
+    assert_eq!(owned.lang, "en");
+    assert_eq!(owned.count, 1);
+    assert_eq!(borrowme::borrow(&owned), word);
+
+    // `ToOwned` keeps using `to_owned_with`.
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(owned.text, "HELLO");
+
+    let word = Word {
+        text: "",
+        lang: "en",
+        count: 1,
+    };
+
+    assert_eq!(
+        borrowme::try_to_owned(&word).unwrap_err(),
+        Error("empty text")
+    );
+}
+
+#[test]
+fn try_to_owned_enum() {
+    let owned: OwnedEntry = borrowme::try_to_owned(&Entry::Text("hello")).unwrap();
+    assert_eq!(owned, OwnedEntry::Text(String::from("hello")));
+
+    let owned: OwnedEntry = borrowme::try_to_owned(&Entry::Other { lang: "en" }).unwrap();
+    assert_eq!(
+        owned,
+        OwnedEntry::Other {
+            lang: String::from("en")
+        }
+    );
+
+    assert_eq!(
+        borrowme::try_to_owned(&Entry::Text("")).unwrap_err(),
+        Error("empty text")
+    );
+}
+
+#[borrowme(error = Error)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Generic<'a, T> {
+    #[borrowme(try_to_owned_with = non_empty)]
+    text: &'a str,
+    value: T,
+}
+
+#[test]
+fn try_from() {
+    let word = Word {
+        text: "hello",
+        lang: "en",
+        count: 1,
+    };
+
+    let owned = OwnedWord::try_from(word).unwrap();
+    assert_eq!(owned.text, "hello");
+
+    let result: Result<OwnedEntry, Error> = Entry::Text("").try_into();
+    assert_eq!(result.unwrap_err(), Error("empty text"));
+
+    let generic = Generic {
+        text: "hello",
+        value: 42u32,
+    };
+
+    let owned = OwnedGeneric::try_from(generic).unwrap();
+    assert_eq!(owned.text, "hello");
+    assert_eq!(owned.value, 42);
+}
@@ -0,0 +1,35 @@
+mod word {
+    use borrowme::borrowme;
+
+    #[borrowme]
+    pub struct Unnamed<'a>(
+        #[owned(String)] pub &'a str,
+        pub(crate) u32,
+        pub(super) &'a str,
+    );
+
+    #[borrowme]
+    pub enum Enum<'a> {
+        Word(#[owned(String)] &'a str),
+    }
+
+    #[borrowme]
+    pub struct Nested<'a>(pub Unnamed<'a>, pub Option<Enum<'a>>);
+}
+
+#[test]
+fn tuple_vis() {
+    let unnamed = word::Unnamed("hello", 42, "en");
+
+    // NB: Fields are accessed from outside of the module, so this only
+    // compiles if their visibility is preserved in the owned variant.
+    let owned: word::OwnedUnnamed = borrowme::to_owned(&unnamed);
+    assert_eq!(owned.0, "hello");
+    assert_eq!(owned.1, 42);
+    assert_eq!(owned.2, "en");
+
+    let nested = word::Nested(unnamed, Some(word::Enum::Word("world")));
+    let owned: word::OwnedNested = borrowme::to_owned(&nested);
+    assert_eq!(owned.0 .0, "hello");
+    assert!(matches!(owned.1, Some(word::OwnedEnum::Word(ref text)) if text == "world"));
+}
@@ -0,0 +1,38 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Word<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Entry<'a> {
+    pair: (&'a str, Word<'a>),
+    // Primitives are borrowed as references to them, the same as in other
+    // containers.
+    triple: (&'a str, &'a u32, Word<'a>),
+    copy: (u32, bool),
+    pairs: Vec<(&'a str, Option<Word<'a>>)>,
+}
+
+#[test]
+fn tuple_fields() {
+    let entry = Entry {
+        pair: ("hello", Word { text: "world" }),
+        triple: ("a", &42, Word { text: "b" }),
+        copy: (1, true),
+        pairs: vec![("c", None), ("d", Some(Word { text: "e" }))],
+    };
+
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    let _: &(String, OwnedWord) = &owned.pair;
+    let _: &(String, u32, OwnedWord) = &owned.triple;
+    let _: &(u32, bool) = &owned.copy;
+    assert_eq!(owned.pair.0, "hello");
+    assert_eq!(owned.pair.1.text, "world");
+    assert_eq!(owned.triple.1, 42);
+    assert_eq!(owned.pairs.len(), 2);
+    assert_eq!(borrowme::borrow(&owned), entry);
+}
@@ -0,0 +1,48 @@
+use borrowme::borrowme;
+
+type Str<'a> = &'a str;
+type Words<'a> = Vec<&'a str>;
+type Count<'a> = &'a u32;
+type Point = Position;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position(u32, u32);
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Aliased<'a> {
+    text: Str<'a>,
+    words: Words<'a>,
+    count: Count<'a>,
+    optional: Option<Str<'a>>,
+    #[copy]
+    point: Point,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Entry<'a> {
+    Text(Str<'a>),
+}
+
+#[test]
+fn type_alias() {
+    let aliased = Aliased {
+        text: "hello",
+        words: vec!["a", "b"],
+        count: &42,
+        optional: Some("world"),
+        point: Position(1, 2),
+    };
+
+    let owned: OwnedAliased = borrowme::to_owned(&aliased);
+    let _: &String = &owned.text;
+    let _: &Vec<String> = &owned.words;
+    let _: &u32 = &owned.count;
+    assert_eq!(owned.text, "hello");
+    assert_eq!(borrowme::borrow(&owned), aliased);
+
+    let entry = Entry::Text("hello");
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert_eq!(borrowme::borrow(&owned), entry);
+}
@@ -0,0 +1,16 @@
+use borrowme::borrowme;
+
+#[borrowme]
+pub struct NotReference<'a> {
+    #[borrowme(borrow_cow)]
+    text: String,
+    other: &'a str,
+}
+
+#[borrowme]
+pub struct Conflict<'a> {
+    #[borrowme(borrow_cow, cow)]
+    text: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use borrowme::borrowme;
+
+#[borrowme(bound = "T Clone")]
+pub struct Invalid<'a, T> {
+    text: &'a str,
+    value: T,
+}
+
+#[borrowme(bound = "T: Clone", bound = "T: Copy")]
+pub struct Duplicate<'a, T> {
+    text: &'a str,
+    value: T,
+}
+
+fn main() {}
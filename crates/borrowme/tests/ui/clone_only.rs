@@ -0,0 +1,15 @@
+use borrowme::borrowme;
+
+#[borrowme]
+pub struct Word<'a> {
+    #[borrowme(clone_only)]
+    text: &'a str,
+}
+
+#[borrowme]
+pub struct Conflict {
+    #[borrowme(clone_only, owned = String)]
+    text: String,
+}
+
+fn main() {}
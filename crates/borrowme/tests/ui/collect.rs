@@ -0,0 +1,14 @@
+use borrowme::borrowme;
+
+#[borrowme(error = core::convert::Infallible)]
+struct Word<'a> {
+    #[borrowme(collect, owned = Vec<String>)]
+    texts: Vec<&'a str>,
+    #[borrowme(collect, try_to_owned_with = self::try_to_owned)]
+    fallible: Vec<&'a str>,
+    #[borrowme(collect, cow)]
+    cow: Vec<&'a str>,
+}
+
+fn main() {
+}
@@ -0,0 +1,18 @@
+use borrowme::borrowme;
+
+#[borrowme]
+pub struct Word<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+pub struct Entry<'a> {
+    word: &'a Word<'a>,
+}
+
+#[borrowme]
+pub struct Nested<'a> {
+    text: &'a Option<&'a str>,
+}
+
+fn main() {}
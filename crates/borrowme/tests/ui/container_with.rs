@@ -0,0 +1,13 @@
+use borrowme::borrowme;
+
+#[borrowme(std, with = self::module)]
+pub struct WithStd<'a> {
+    text: &'a str,
+}
+
+#[borrowme(with = self::module, with = self::other)]
+pub struct Duplicate<'a> {
+    text: &'a str,
+}
+
+fn main() {}
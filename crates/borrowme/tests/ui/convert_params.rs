@@ -0,0 +1,35 @@
+use borrowme::borrowme;
+
+#[borrowme]
+pub struct Bounded<'a, #[borrowme(convert)] T: Clone> {
+    value: T,
+    text: &'a str,
+}
+
+#[borrowme]
+pub struct WhereBounded<'a, #[borrowme(convert)] T>
+where
+    T: Clone,
+{
+    value: T,
+    text: &'a str,
+}
+
+#[borrowme]
+pub struct Reference<'a, #[borrowme(convert)] T> {
+    value: &'a T,
+}
+
+#[borrowme]
+pub struct Mutable<'a, #[borrowme(convert)] T> {
+    value: T,
+    text: &'a mut String,
+}
+
+#[borrowme]
+pub struct Unsupported<'a, #[borrowme(clone)] T> {
+    value: T,
+    text: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use borrowme::borrowme;
+
+#[borrowme(error = core::convert::Infallible)]
+struct Word<'a> {
+    #[borrowme(cow)]
+    not_reference: Option<&'a str>,
+    #[borrowme(cow)]
+    mutable: &'a mut str,
+    #[borrowme(cow, owned = String)]
+    conflict: &'a str,
+    #[borrowme(cow, try_to_owned_with = self::try_to_owned)]
+    fallible: &'a str,
+    #[borrowme(cow, with = self::module)]
+    with_module: &'a str,
+}
+
+fn main() {
+}
@@ -0,0 +1,21 @@
+use borrowme::{Borrow, ToOwned};
+
+#[derive(ToOwned)]
+struct MissingTarget<'a> {
+    text: &'a str,
+}
+
+#[derive(Borrow)]
+#[borrow(target = Word<'a>)]
+enum NotStruct {
+    Text(String),
+}
+
+#[derive(ToOwned)]
+#[to_owned(target = String)]
+struct CopyWith<'a> {
+    #[to_owned(copy, to_owned_with = str::to_owned)]
+    text: &'a str,
+}
+
+fn main() {}
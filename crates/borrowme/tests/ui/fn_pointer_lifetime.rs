@@ -0,0 +1,14 @@
+use borrowme::borrowme;
+
+#[borrowme]
+pub struct Callback<'a> {
+    callback: fn(&'a u8) -> u8,
+}
+
+#[borrowme]
+pub struct Nested<'a> {
+    name: &'a str,
+    callback: Option<fn() -> &'a str>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use borrowme::borrowme;
+
+#[borrowme(from, error = String)]
+pub struct WithError<'a> {
+    text: &'a str,
+}
+
+#[borrowme(from, from)]
+pub struct Duplicate<'a> {
+    text: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+struct Word<'a> {
+    text: &'a str,
+}
+
+struct OwnedWord {
+    text: String,
+}
+
+borrowme::impl_borrow! {
+    Word<'a> => OwnedWord {
+        text: owned = String,
+    }
+}
+
+struct Count<'a> {
+    text: &'a str,
+    count: u32,
+}
+
+struct OwnedCount {
+    text: String,
+    count: u32,
+}
+
+borrowme::impl_borrow! {
+    Count<'a> => OwnedCount {
+        text: (with = self::text, to_owned_with = String::from),
+        count: (copy, borrow_with = Clone::clone),
+    }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[borrowme(inline = sometimes)]
+struct Word<'a> {
+    text: &'a str,
+}
+
+fn main() {
+}
@@ -0,0 +1,9 @@
+use borrowme::borrowme;
+
+#[borrowme(map_type(Option<&str> => Option<String>))]
+struct Word<'a> {
+    text: Option<&'a str>,
+}
+
+fn main() {
+}
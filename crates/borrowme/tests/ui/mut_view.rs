@@ -0,0 +1,16 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[borrowme(mut_view = MutWord)]
+struct Word<'a> {
+    text: &'a mut String,
+}
+
+#[borrowme]
+#[borrowme(mut_view = MutEntry)]
+enum Entry<'a> {
+    Text(&'a str),
+}
+
+fn main() {
+}
@@ -0,0 +1,12 @@
+use borrowme::borrowme;
+
+#[borrowme(no_heuristics)]
+struct Word<'a> {
+    text: &'a str,
+    count: u32,
+    #[no_copy]
+    lang: Option<&'a str>,
+}
+
+fn main() {
+}
@@ -0,0 +1,15 @@
+use borrowme::borrowme;
+
+#[borrowme]
+pub struct NotOption<'a> {
+    #[borrowme(or_default)]
+    text: &'a str,
+}
+
+#[borrowme]
+pub struct Conflict<'a> {
+    #[borrowme(or_default, cow)]
+    text: Option<&'a str>,
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+use borrowme::borrowme;
+
+#[borrowme]
+pub struct Word<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+pub struct SliceOfReferences<'a> {
+    #[borrowme(owned_collect = Vec<OwnedWord>)]
+    words: &'a [Word<'a>],
+}
+
+#[borrowme]
+pub struct Reference<'a> {
+    #[borrowme(owned_collect = Vec<u32>)]
+    counts: &'a Vec<u32>,
+}
+
+#[borrowme]
+pub struct Conflict<'a> {
+    #[borrowme(owned_collect = Vec<String>, collect)]
+    words: Vec<&'a str>,
+}
+
+fn main() {}
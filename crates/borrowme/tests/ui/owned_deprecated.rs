@@ -0,0 +1,14 @@
+#![deny(deprecated)]
+
+use borrowme::borrowme;
+
+#[borrowme]
+#[owned_attr(deprecated = "use OwnedWord instead")]
+pub struct LegacyWord<'a> {
+    text: &'a str,
+}
+
+fn main() {
+    let _ = LegacyWord { text: "hello" };
+    let _ = OwnedLegacyWord { text: String::new() };
+}
@@ -0,0 +1,17 @@
+use borrowme::borrowme;
+
+#[borrowme]
+struct Word<'a> {
+    text: &'a str,
+    #[borrowme(no_hash)]
+    cache: Vec<u32>,
+}
+
+#[borrowme(owned_hash)]
+struct Key<'a> {
+    #[borrowme(hash, no_hash)]
+    text: &'a str,
+}
+
+fn main() {
+}
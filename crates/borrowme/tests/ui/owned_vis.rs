@@ -0,0 +1,16 @@
+mod word {
+    use borrowme::borrowme;
+
+    #[borrowme]
+    pub struct Word<'a> {
+        #[borrowme(owned_vis = pub(self))]
+        pub text: &'a str,
+    }
+}
+
+fn main() {
+    let word = word::Word { text: "Hello" };
+    let _ = word.text;
+    let owned: word::OwnedWord = borrowme::to_owned(&word);
+    let _ = owned.text;
+}
@@ -0,0 +1,11 @@
+use borrowme::borrowme;
+
+#[borrowme]
+struct Word<'a> {
+    text: &'a str,
+    #[borrowme(skip, copy)]
+    conflict: u32,
+}
+
+fn main() {
+}
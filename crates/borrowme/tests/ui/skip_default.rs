@@ -0,0 +1,13 @@
+use borrowme::borrowme;
+
+struct NoDefault;
+
+#[borrowme]
+struct Word<'a> {
+    text: &'a str,
+    #[borrowme(skip)]
+    missing: NoDefault,
+}
+
+fn main() {
+}
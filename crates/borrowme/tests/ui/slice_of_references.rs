@@ -0,0 +1,10 @@
+use borrowme::borrowme;
+
+#[borrowme]
+struct Args<'a> {
+    program: &'a str,
+    args: &'a [&'a str],
+}
+
+fn main() {
+}
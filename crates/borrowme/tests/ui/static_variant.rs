@@ -0,0 +1,12 @@
+use borrowme::borrowme;
+
+#[borrowme(static_variant = StaticEmpty)]
+pub struct Empty;
+
+#[borrowme(static_variant = StaticValue)]
+#[repr(C)]
+pub union Value {
+    number: u32,
+}
+
+fn main() {}
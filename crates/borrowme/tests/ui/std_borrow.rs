@@ -0,0 +1,26 @@
+use borrowme::borrowme;
+
+#[borrowme(std_borrow)]
+pub struct Multiple<'a> {
+    text: &'a str,
+    lang: &'a str,
+}
+
+#[borrowme(std_borrow)]
+pub struct Mutable<'a>(&'a mut String);
+
+#[borrowme]
+pub struct Word<'a>(&'a str);
+
+#[borrowme(std_borrow)]
+pub struct Compound<'a>(
+    #[borrowme(owned = OwnedWord, to_owned_with = Clone::clone, borrow_with = Clone::clone)]
+    &'a Word<'a>,
+);
+
+#[borrowme(std_borrow)]
+pub enum Enum<'a> {
+    Word(&'a str),
+}
+
+fn main() {}
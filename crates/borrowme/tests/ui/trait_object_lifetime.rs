@@ -0,0 +1,10 @@
+use borrowme::borrowme;
+
+#[borrowme]
+struct Matcher<'a> {
+    pattern: &'a str,
+    filter: Box<dyn Fn(&'a str) -> bool + 'a>,
+}
+
+fn main() {
+}
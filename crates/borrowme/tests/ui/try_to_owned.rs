@@ -0,0 +1,20 @@
+use borrowme::borrowme;
+
+fn non_empty(text: &str) -> Result<String, String> {
+    Ok(text.to_owned())
+}
+
+#[borrowme]
+struct Word<'a> {
+    #[borrowme(try_to_owned_with = non_empty)]
+    text: &'a str,
+}
+
+#[borrowme(error = String)]
+struct Duplicate<'a> {
+    #[borrowme(try_to_owned_with = self::non_empty, try_to_owned_with = non_empty)]
+    text: &'a str,
+}
+
+fn main() {
+}
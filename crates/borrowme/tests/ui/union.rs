@@ -0,0 +1,67 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[repr(C)]
+pub union NotCopy {
+    #[copy]
+    int: u32,
+    text: std::mem::ManuallyDrop<String>,
+}
+
+#[borrowme]
+pub union NotRepr {
+    #[copy]
+    int: u32,
+}
+
+#[borrowme(mut_view = PayloadMut)]
+#[repr(C)]
+pub union Unsupported {
+    #[copy]
+    int: u32,
+}
+
+#[borrowme]
+#[repr(C)]
+#[owned_attr(repr(align(64)))]
+pub union OwnedRepr {
+    #[copy]
+    int: u32,
+}
+
+#[borrowme]
+#[repr(C)]
+#[borrowed_attr(repr(packed))]
+pub union BorrowedRepr {
+    #[copy]
+    int: u32,
+}
+
+#[borrowme(with = self::module, boxed_slices, serde)]
+#[borrowme(map_type(&str => Box<str>))]
+#[repr(C)]
+pub union Ignored {
+    #[copy]
+    int: u32,
+}
+
+#[borrowme]
+#[repr(C)]
+pub union Conversion {
+    #[copy]
+    #[borrowme(to_owned_with = Clone::clone)]
+    int: u32,
+    #[owned(u64)]
+    wide: u32,
+}
+
+#[borrowme]
+#[repr(C)]
+pub union Lifetime<'a> {
+    #[copy]
+    int: u32,
+    #[copy]
+    marker: std::marker::PhantomData<&'a ()>,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use std::mem::ManuallyDrop;
+
+use borrowme::borrowme;
+
+#[borrowme]
+#[repr(C)]
+pub union NotCopy {
+    #[copy]
+    int: u32,
+    #[copy]
+    text: ManuallyDrop<String>,
+}
+
+fn main() {}
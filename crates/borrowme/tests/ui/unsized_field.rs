@@ -0,0 +1,16 @@
+use borrowme::borrowme;
+
+#[borrowme]
+struct Words<'a> {
+    lang: &'a str,
+    words: [&'a str],
+}
+
+#[borrowme]
+struct Text<'a> {
+    lang: &'a str,
+    text: str,
+}
+
+fn main() {
+}
@@ -0,0 +1,26 @@
+use borrowme::borrowme;
+
+fn wrong_argument(_: &u32) -> String {
+    String::new()
+}
+
+fn wrong_return(_: &str) -> Box<str> {
+    Box::from("")
+}
+
+fn wrong_borrow(_: &u32) -> &str {
+    ""
+}
+
+#[borrowme]
+pub struct Word<'a> {
+    #[borrowme(owned = String, to_owned_with = wrong_argument)]
+    a: &'a str,
+    #[borrowme(owned = String, to_owned_with = wrong_return)]
+    b: &'a str,
+    #[borrowme(owned = String, borrow_with = wrong_borrow)]
+    c: &'a str,
+}
+
+fn main() {
+}
@@ -0,0 +1,48 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub union Payload {
+    #[copy]
+    int: u32,
+    float: f32,
+    bytes: [u8; 4],
+}
+
+#[borrowme]
+#[repr(C, align(8))]
+pub union Generic<T>
+where
+    T: Copy,
+{
+    #[copy]
+    value: T,
+    raw: u64,
+}
+
+#[test]
+fn union() {
+    let payload = Payload { int: 42 };
+
+    let owned: OwnedPayload = borrowme::to_owned(&payload);
+    assert_eq!(unsafe { owned.int }, 42);
+
+    let payload: Payload = borrowme::borrow(&owned);
+    assert_eq!(unsafe { payload.bytes }, 42u32.to_ne_bytes());
+
+    let payload = Payload { float: 1.5 };
+    let owned: OwnedPayload = borrowme::to_owned(&payload);
+    assert_eq!(unsafe { owned.float }, 1.5);
+}
+
+#[test]
+fn generic_union() {
+    let value = Generic::<u16> { raw: u64::MAX };
+
+    let owned: OwnedGeneric<u16> = borrowme::to_owned(&value);
+    assert_eq!(unsafe { owned.raw }, u64::MAX);
+
+    let value = borrowme::borrow(&owned);
+    assert_eq!(unsafe { value.value }, u16::MAX);
+}
@@ -0,0 +1,78 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    #[borrowme(owned = Vec<u8>, to_owned_with = borrowme::util::str_to_vec, borrow_with = borrowme::util::vec_to_str)]
+    text: &'a str,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Entry<'a> {
+    Text(
+        #[borrowme(owned = Vec<u8>, to_owned_with = borrowme::util::str_to_vec, borrow_with = borrowme::util::vec_to_str)]
+         &'a str,
+    ),
+}
+
+mod unchecked {
+    use borrowme::borrowme;
+
+    #[borrowme]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Word<'a> {
+        #[borrowme(owned = Vec<u8>, to_owned_with = borrowme::util::str_to_vec, borrow_with = vec_to_str_unchecked)]
+        text: &'a str,
+    }
+
+    impl<'a> Word<'a> {
+        pub fn new(text: &'a str) -> Self {
+            Self { text }
+        }
+
+        pub fn text(&self) -> &'a str {
+            self.text
+        }
+    }
+
+    impl OwnedWord {
+        pub fn bytes(&self) -> &[u8] {
+            &self.text
+        }
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn vec_to_str_unchecked(bytes: &Vec<u8>) -> &str {
+        // SAFETY: The field is private and only populated from a string.
+        unsafe { core::str::from_utf8_unchecked(bytes) }
+    }
+}
+
+#[test]
+fn utf8_bytes() {
+    let word = Word { text: "hello" };
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(owned.text, b"hello");
+    assert_eq!(borrowme::borrow(&owned), word);
+
+    let entry = Entry::Text("hello");
+    let owned: OwnedEntry = borrowme::to_owned(&entry);
+    assert!(matches!(&owned, OwnedEntry::Text(text) if text == b"hello"));
+    assert_eq!(borrowme::borrow(&owned), entry);
+
+    let word = unchecked::Word::new("hello");
+    let owned: unchecked::OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(owned.bytes(), b"hello");
+    assert_eq!(borrowme::borrow(&owned).text(), "hello");
+}
+
+#[test]
+#[should_panic = "owned value is not valid UTF-8"]
+fn utf8_bytes_invalid() {
+    let owned = OwnedWord {
+        text: vec![0xff, 0xfe],
+    };
+
+    let _ = borrowme::borrow(&owned);
+}
@@ -0,0 +1,61 @@
+use std::collections::VecDeque;
+
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Token<'a> {
+    text: &'a str,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Events<'a> {
+    program: &'a str,
+    queue: VecDeque<Token<'a>>,
+    args: VecDeque<&'a str>,
+}
+
+#[borrowme]
+struct EventsMut<'a> {
+    queue: VecDeque<&'a mut String>,
+}
+
+#[test]
+fn vec_deque_of_references() {
+    let events = Events {
+        program: "ls",
+        queue: VecDeque::from(vec![Token { text: "a" }, Token { text: "b" }]),
+        args: VecDeque::from(vec!["-l", "-a"]),
+    };
+
+    let owned: OwnedEvents = borrowme::to_owned(&events);
+    let _: &VecDeque<OwnedToken> = &owned.queue;
+    assert_eq!(owned.queue[1].text, "b");
+    assert_eq!(
+        owned.args,
+        VecDeque::from(vec![String::from("-l"), String::from("-a")])
+    );
+    assert_eq!(borrowme::borrow(&owned), events);
+}
+
+#[test]
+fn vec_deque_borrow_mut() {
+    let mut a = String::from("a");
+    let mut b = String::from("b");
+
+    let events = EventsMut {
+        queue: VecDeque::from(vec![&mut a, &mut b]),
+    };
+
+    let mut owned: OwnedEventsMut = borrowme::to_owned(&events);
+
+    for value in borrowme::borrow_mut(&mut owned).queue {
+        value.make_ascii_uppercase();
+    }
+
+    assert_eq!(
+        owned.queue,
+        VecDeque::from(vec![String::from("A"), String::from("B")])
+    );
+}
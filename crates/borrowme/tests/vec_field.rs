@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 use borrowme::borrowme;
 
 #[borrowme]
@@ -5,3 +7,22 @@ struct VecField<'a> {
     #[borrowme(borrow_with = Vec::as_slice)]
     strings: &'a [String],
 }
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Args<'a> {
+    program: &'a str,
+    args: Vec<&'a str>,
+}
+
+#[test]
+fn vec_of_references() {
+    let args = Args {
+        program: "ls",
+        args: vec!["-l", "-a"],
+    };
+
+    let owned: OwnedArgs = borrowme::to_owned(&args);
+    assert_eq!(owned.args, vec![String::from("-l"), String::from("-a")]);
+    assert_eq!(borrowme::borrow(&owned), args);
+}
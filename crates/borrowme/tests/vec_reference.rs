@@ -0,0 +1,37 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq)]
+pub struct Vector<'v> {
+    data: &'v Vec<f32>,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq)]
+pub struct Tagged<'t> {
+    name: &'t str,
+    vector: Vector<'t>,
+    words: &'t Vec<String>,
+}
+
+#[test]
+fn vec_reference() {
+    let data = vec![1.0, 2.0, 3.0];
+    let words = vec![String::from("hello"), String::from("world")];
+
+    let tagged = Tagged {
+        name: "tagged",
+        vector: Vector { data: &data },
+        words: &words,
+    };
+
+    let owned: OwnedTagged = borrowme::to_owned(&tagged);
+    let _: &Vec<f32> = &owned.vector.data;
+    let _: &Vec<String> = &owned.words;
+    assert_eq!(owned.name, "tagged");
+    assert_eq!(owned.vector.data, data);
+
+    let borrowed: Tagged<'_> = borrowme::borrow(&owned);
+    let _: &Vec<f32> = borrowed.vector.data;
+    assert_eq!(borrowed, tagged);
+}
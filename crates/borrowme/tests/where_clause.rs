@@ -0,0 +1,64 @@
+use std::collections::BTreeSet;
+
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+struct Tree<'a, T>
+where
+    T: Ord,
+{
+    name: &'a str,
+    items: BTreeSet<T>,
+}
+
+#[test]
+fn where_clause() {
+    let tree = Tree {
+        name: "tree",
+        items: BTreeSet::from([3, 1, 2]),
+    };
+
+    let owned: OwnedTree<u32> = borrowme::to_owned(&tree);
+    assert_eq!(owned.name, "tree");
+    assert_eq!(borrowme::borrow(&owned), tree);
+}
+
+fn to_strings<T>(items: &[T]) -> Vec<String>
+where
+    T: ToString,
+{
+    items.iter().map(ToString::to_string).collect()
+}
+
+fn parse<T>(items: &[String]) -> Vec<T>
+where
+    T: std::str::FromStr,
+{
+    items.iter().filter_map(|item| item.parse().ok()).collect()
+}
+
+#[borrowme(bound = "T: Clone + ToString + std::str::FromStr")]
+#[derive(Debug, PartialEq, Eq)]
+struct Labels<'a, T>
+where
+    T: Ord,
+{
+    name: &'a str,
+    #[borrowme(owned = Vec<String>, to_owned_with = to_strings, borrow_with = parse)]
+    items: Vec<T>,
+    sorted: BTreeSet<T>,
+}
+
+#[test]
+fn bound() {
+    let labels = Labels {
+        name: "labels",
+        items: vec![1, 2],
+        sorted: BTreeSet::from([3]),
+    };
+
+    let owned: OwnedLabels<u32> = borrowme::to_owned(&labels);
+    assert_eq!(owned.items, [String::from("1"), String::from("2")]);
+    assert_eq!(borrowme::borrow(&owned), labels);
+}
@@ -0,0 +1,47 @@
+use borrowme::borrowme;
+
+#[borrowme]
+struct Wrap<'a, T>
+where
+    T: 'a + ?Sized,
+{
+    name: &'a str,
+    value: &'a T,
+}
+
+#[borrowme]
+struct Bounded<'a, T: 'a + Clone> {
+    name: &'a str,
+    value: T,
+}
+
+#[test]
+fn where_lifetime() {
+    let wrap = Wrap::<str> {
+        name: "hello",
+        value: "world",
+    };
+
+    let owned: OwnedWrap<str> = borrowme::to_owned(&wrap);
+    assert_eq!(owned.name, "hello");
+    assert_eq!(owned.value, "world");
+
+    let wrap: Wrap<'_, str> = borrowme::borrow(&owned);
+    assert_eq!(wrap.name, "hello");
+    assert_eq!(wrap.value, "world");
+}
+
+#[test]
+fn inline_lifetime_bound() {
+    let bounded = Bounded {
+        name: "hello",
+        value: 42u32,
+    };
+
+    let owned: OwnedBounded<u32> = borrowme::to_owned(&bounded);
+    assert_eq!(owned.name, "hello");
+    assert_eq!(owned.value, 42);
+
+    let bounded: Bounded<'_, u32> = borrowme::borrow(&owned);
+    assert_eq!(bounded.value, 42);
+}
@@ -0,0 +1,84 @@
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    #[borrowme(owned = Option<String>, with = self::option)]
+    lang: Option<&'a str>,
+}
+
+#[borrowme]
+pub struct Mixed<'a> {
+    // The `option` module doesn't define `borrow_mut`, which is fine since this
+    // field is borrowed immutably even though the container isn't.
+    #[borrowme(owned = Option<String>, with = self::option)]
+    lang: Option<&'a str>,
+    text: &'a mut str,
+}
+
+#[borrowme]
+pub struct Mutable<'a> {
+    #[borrowme(owned = Option<String>, with = self::option_mut)]
+    lang: Option<&'a mut str>,
+}
+
+mod option_mut {
+    pub(crate) use super::option::to_owned;
+
+    pub(crate) fn borrow_mut(this: &mut Option<String>) -> Option<&mut str> {
+        this.as_deref_mut()
+    }
+}
+
+mod option {
+    use borrowme::{Borrow, ToOwned};
+
+    pub(crate) fn borrow<T>(this: &Option<T>) -> Option<T::Target<'_>>
+    where
+        T: Borrow,
+    {
+        this.as_ref().map(Borrow::borrow)
+    }
+
+    pub(crate) fn to_owned<T>(this: &Option<T>) -> Option<T::Owned>
+    where
+        T: ToOwned,
+    {
+        this.as_ref().map(ToOwned::to_owned)
+    }
+}
+
+#[test]
+fn with_module() {
+    let word = Word { lang: Some("en") };
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(borrowme::borrow(&owned), word);
+
+    let mut text = String::from("hello");
+
+    let mixed = Mixed {
+        lang: Some("en"),
+        text: &mut text,
+    };
+
+    let mut owned: OwnedMixed = borrowme::to_owned(&mixed);
+    let borrowed = borrowme::borrow_mut(&mut owned);
+    assert_eq!(borrowed.lang, Some("en"));
+    borrowed.text.make_ascii_uppercase();
+    assert_eq!(owned.text, "HELLO");
+
+    let mut lang = String::from("en");
+
+    let mutable = Mutable {
+        lang: Some(&mut lang),
+    };
+
+    let mut owned: OwnedMutable = borrowme::to_owned(&mutable);
+    let borrowed = borrowme::borrow_mut(&mut owned);
+
+    if let Some(lang) = borrowed.lang {
+        lang.make_ascii_uppercase();
+    }
+
+    assert_eq!(owned.lang.as_deref(), Some("EN"));
+}
@@ -0,0 +1,74 @@
+use std::marker::{PhantomData, PhantomPinned};
+
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug)]
+pub struct Pinned<'a> {
+    text: &'a str,
+    pinned: PhantomPinned,
+    unit: (),
+    marker: PhantomData<fn() -> u32>,
+}
+
+#[borrowme]
+pub enum Either<'a> {
+    Text(&'a str),
+    Pinned(PhantomPinned),
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Marked<'a> {
+    text: &'a str,
+    _marker: PhantomData<&'a str>,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub enum MarkedEither<'a> {
+    Text(&'a str),
+    Marker(PhantomData<&'a str>),
+}
+
+#[test]
+fn zst_markers() {
+    let pinned = Pinned {
+        text: "hello",
+        pinned: PhantomPinned,
+        unit: (),
+        marker: PhantomData,
+    };
+
+    let owned: OwnedPinned = borrowme::to_owned(&pinned);
+    let _: PhantomPinned = owned.pinned;
+    assert_eq!(owned.text, "hello");
+
+    let borrowed: Pinned<'_> = borrowme::borrow(&owned);
+    assert_eq!(borrowed.text, "hello");
+
+    let either = Either::Pinned(PhantomPinned);
+    let owned: OwnedEither = borrowme::to_owned(&either);
+    assert!(matches!(owned, OwnedEither::Pinned(PhantomPinned)));
+}
+
+#[test]
+fn phantom_data_reference() {
+    let marked = Marked {
+        text: "hello",
+        _marker: PhantomData,
+    };
+
+    let owned: OwnedMarked = borrowme::to_owned(&marked);
+    assert_eq!(owned.text, "hello");
+
+    let borrowed: Marked<'_> = borrowme::borrow(&owned);
+    assert_eq!(borrowed, marked);
+
+    let either = MarkedEither::Marker(PhantomData);
+    let owned: OwnedMarkedEither = borrowme::to_owned(&either);
+    assert!(matches!(owned, OwnedMarkedEither::Marker(PhantomData)));
+
+    let borrowed: MarkedEither<'_> = borrowme::borrow(&owned);
+    assert_eq!(borrowed, either);
+}
@@ -0,0 +1,2 @@
+//! Tests for code generated by `#[borrowme]` when compiled under the 2024
+//! edition, which changes how lifetimes are captured.
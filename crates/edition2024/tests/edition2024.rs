@@ -0,0 +1,79 @@
+//! The generated `borrow` functions return `Self::Target<'_>` rather than an
+//! `impl Trait`, so they aren't affected by the lifetime capture rules in the
+//! 2024 edition and don't need precise capturing through `use<..>`.
+
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    text: &'a str,
+    lang: Option<&'a str>,
+    count: u32,
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Entry<'a> {
+    Word(Word<'a>),
+    Text { text: &'a str },
+    Empty,
+}
+
+#[borrowme]
+pub struct Wrap<'a, T: ?Sized> {
+    value: &'a T,
+}
+
+#[borrowme(mut_view = TextMut)]
+pub struct Text<'a> {
+    text: &'a str,
+}
+
+#[borrowme(leak)]
+pub struct Mutable<'a> {
+    text: &'a mut String,
+}
+
+fn words<'a>(entries: &'a [OwnedEntry]) -> impl Iterator<Item = Entry<'a>> {
+    entries.iter().map(borrowme::borrow)
+}
+
+#[test]
+fn edition2024() {
+    let word = Word {
+        text: "hello",
+        lang: Some("en"),
+        count: 1,
+    };
+
+    let owned: OwnedWord = borrowme::to_owned(&word);
+    assert_eq!(borrowme::borrow(&owned), word);
+
+    let entries: Vec<OwnedEntry> = vec![
+        borrowme::to_owned(Entry::Word(word)),
+        borrowme::to_owned(Entry::Text { text: "world" }),
+        borrowme::to_owned(Entry::Empty),
+    ];
+
+    let borrowed = words(&entries).collect::<Vec<_>>();
+    assert_eq!(borrowed[1], Entry::Text { text: "world" });
+    assert_eq!(borrowed[2], Entry::Empty);
+
+    let wrap = Wrap::<str> { value: "hello" };
+    let owned: OwnedWrap<str> = borrowme::to_owned(&wrap);
+    assert_eq!(borrowme::borrow(&owned).value, "hello");
+
+    let mut owned: OwnedText = borrowme::to_owned(&Text { text: "hello" });
+    let view: TextMut<'_> = borrowme::borrow_mut(&mut owned);
+    view.text.make_ascii_uppercase();
+    assert_eq!(owned.text, "HELLO");
+
+    let mut text = String::from("hello");
+    let mut owned: OwnedMutable = borrowme::to_owned(&Mutable { text: &mut text });
+    borrowme::borrow_mut(&mut owned).text.push_str(" world");
+    assert_eq!(owned.text, "hello world");
+
+    let leaked: Mutable<'static> = owned.leak();
+    assert_eq!(leaked.text, "hello world");
+}
@@ -0,0 +1,13 @@
+//! A facade crate which re-exports `borrowme` under a different path, which
+//! ensures that the generated code doesn't depend on `::borrowme` being
+//! resolvable when `#[borrowme(crate = <path>)]` is used.
+
+pub use inner as reexport;
+
+use self::reexport::borrowme;
+
+#[borrowme(crate = crate::reexport)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    pub text: &'a str,
+}
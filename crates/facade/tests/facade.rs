@@ -0,0 +1,44 @@
+use facade::reexport::{self, borrowme};
+use facade::{OwnedWord, Word};
+
+#[borrowme(crate = facade::reexport, boxed_slices)]
+#[derive(Debug, PartialEq, Eq)]
+struct Dictionary<'a> {
+    words: Vec<Word<'a>>,
+    bytes: &'a [u8],
+    #[borrowme(mut)]
+    counter: &'a mut u32,
+}
+
+#[borrowme]
+#[borrowme(crate = facade::reexport)]
+#[derive(Debug, PartialEq, Eq)]
+enum Entry<'a> {
+    Word(Word<'a>),
+    Text(&'a str),
+}
+
+#[test]
+fn facade() {
+    let word = Word { text: "hello" };
+    let owned: OwnedWord = reexport::to_owned(&word);
+    assert_eq!(owned.text, "hello");
+    assert_eq!(reexport::borrow(&owned), word);
+
+    let mut counter = 1;
+
+    let dictionary = Dictionary {
+        words: vec![Word { text: "hello" }],
+        bytes: b"hello",
+        counter: &mut counter,
+    };
+
+    let mut owned: OwnedDictionary = reexport::to_owned(&dictionary);
+    let _: Box<[u8]> = owned.bytes.clone();
+    *reexport::borrow_mut(&mut owned).counter += 1;
+    assert_eq!(owned.counter, 2);
+
+    let entry = Entry::Text("world");
+    let owned: OwnedEntry = reexport::to_owned(&entry);
+    assert_eq!(reexport::borrow(&owned), entry);
+}
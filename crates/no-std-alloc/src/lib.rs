@@ -0,0 +1,35 @@
+//! Types using `#[borrowme]` when built with the `alloc` feature but without
+//! the `std` feature, which ensures that the generated code and the
+//! collections it uses only depend on `core` and `alloc`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+
+use borrowme::borrowme;
+
+#[borrowme(boxed_slices)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    pub text: &'a str,
+    pub bytes: &'a [u8],
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Dictionary<'a> {
+    pub words: Vec<Word<'a>>,
+    pub index: BTreeMap<&'a str, Word<'a>>,
+    pub tags: BTreeSet<&'a str>,
+    pub first: Option<Box<Word<'a>>>,
+}
+
+#[borrowme]
+pub struct DictionaryMut<'a> {
+    pub text: &'a mut alloc::string::String,
+    pub index: BTreeMap<&'a str, &'a mut alloc::string::String>,
+}
@@ -0,0 +1,43 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use no_std_alloc::{Dictionary, OwnedDictionary, OwnedDictionaryMut, Word};
+
+#[test]
+fn no_std_alloc() {
+    let word = || Word {
+        text: "hello",
+        bytes: b"hello",
+    };
+
+    let dictionary = Dictionary {
+        words: vec![word()],
+        index: BTreeMap::from([("hello", word())]),
+        tags: BTreeSet::from(["greeting"]),
+        first: Some(Box::new(word())),
+    };
+
+    let owned: OwnedDictionary = borrowme::to_owned(&dictionary);
+    let _: &String = &owned.words[0].text;
+    assert_eq!(owned.words[0].text, "hello");
+    assert_eq!(&owned.words[0].bytes[..], b"hello");
+    assert_eq!(owned.index["hello"].text, "hello");
+    assert!(owned.tags.contains("greeting"));
+    assert_eq!(borrowme::borrow(&owned), dictionary);
+
+    let mut owned = OwnedDictionaryMut {
+        text: String::from("hello"),
+        index: BTreeMap::from([(String::from("world"), String::from("moon"))]),
+    };
+
+    {
+        let borrowed = borrowme::borrow_mut(&mut owned);
+        borrowed.text.push_str(" world");
+
+        for value in borrowed.index.into_values() {
+            value.make_ascii_uppercase();
+        }
+    }
+
+    assert_eq!(owned.text, "hello world");
+    assert_eq!(owned.index["world"], "MOON");
+}
@@ -0,0 +1,24 @@
+//! Types using `#[borrowme]` when built without the `std` feature, which
+//! ensures that the generated code only depends on `core`.
+
+#![no_std]
+
+use borrowme::borrowme;
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Point<'a> {
+    #[copy]
+    pub x: u32,
+    pub y: &'a u32,
+    pub flags: Option<&'a bool>,
+    pub coords: &'a [u32; 2],
+}
+
+#[borrowme]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Shape<'a> {
+    Point(Point<'a>),
+    Size { width: &'a u32, height: u32 },
+    Empty,
+}
@@ -0,0 +1,31 @@
+use no_std::{OwnedPoint, OwnedShape, Point, Shape};
+
+#[test]
+fn no_std() {
+    let (y, flags, width) = (2, true, 3);
+    let coords = [4, 5];
+
+    let point = Point {
+        x: 1,
+        y: &y,
+        flags: Some(&flags),
+        coords: &coords,
+    };
+
+    let owned: OwnedPoint = borrowme::to_owned(&point);
+    assert_eq!(owned.y, 2);
+    assert_eq!(owned.flags, Some(true));
+    assert_eq!(owned.coords, [4, 5]);
+    assert_eq!(borrowme::borrow(&owned), point);
+
+    let shape = Shape::Size {
+        width: &width,
+        height: 4,
+    };
+
+    let owned: OwnedShape = borrowme::to_owned(&shape);
+    assert_eq!(borrowme::borrow(&owned), shape);
+
+    let owned: OwnedShape = borrowme::to_owned(&Shape::Point(point));
+    assert!(matches!(owned, OwnedShape::Point(OwnedPoint { x: 1, .. })));
+}